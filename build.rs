@@ -0,0 +1,103 @@
+// Generates the swizzle lists included by `src/math/swizzle.rs`.
+//
+// Every swizzle is derived from its components: the xyzw name, the rgba alias and the setter and
+// proxy names are all spelled from the same component list, so they can never drift apart.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const COMPONENTS: [char; 4] = ['x', 'y', 'z', 'w'];
+const ALIASES: [char; 4] = ['r', 'g', 'b', 'a'];
+
+fn main() {
+    let mut output = String::new();
+    for width in 2..=4 {
+        write_swizzles(&mut output, width);
+    }
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join("swizzles.rs");
+    fs::write(path, output).unwrap();
+    println!("cargo::rerun-if-changed=build.rs");
+}
+
+/// Writes the `swizzles{width}` macro implementing the swizzles of a vector with `width` components.
+fn write_swizzles(output: &mut String, width: usize) {
+    let components = &COMPONENTS[..width];
+    writeln!(
+        output,
+        "/// Implements the swizzles of a vector with {width} components."
+    )
+    .unwrap();
+    writeln!(output, "macro_rules! swizzles{width} {{").unwrap();
+    writeln!(
+        output,
+        "    ($T:ident: $S:ty => $T2:ident, $T3:ident, $T4:ident) => {{"
+    )
+    .unwrap();
+    writeln!(output, "        $crate::math::swizzle::components!($T: $S;").unwrap();
+    for (index, component) in components.iter().enumerate() {
+        let alias = ALIASES[index];
+        writeln!(
+            output,
+            "            ({alias} / set_{alias} / {alias}_mut: {component})"
+        )
+        .unwrap();
+    }
+    writeln!(output, "        );").unwrap();
+    writeln!(
+        output,
+        "        $crate::math::swizzle::swizzles!($T => $T2, $T3, $T4;"
+    )
+    .unwrap();
+    for length in 2..=4 {
+        for swizzle in swizzles(width, length) {
+            let name: String = swizzle.iter().map(|&index| COMPONENTS[index]).collect();
+            let alias: String = swizzle.iter().map(|&index| ALIASES[index]).collect();
+            let fields: Vec<String> = swizzle
+                .iter()
+                .map(|&index| COMPONENTS[index].to_string())
+                .collect();
+            let fields = fields.join(", ");
+            if is_writable(&swizzle) {
+                writeln!(
+                    output,
+                    "            ({name} {alias} / set_{name} set_{alias} / {name}_mut {alias}_mut: {fields})"
+                )
+                .unwrap();
+            } else {
+                writeln!(output, "            ({name} {alias}: {fields})").unwrap();
+            }
+        }
+    }
+    writeln!(output, "        );").unwrap();
+    writeln!(output, "    }};").unwrap();
+    writeln!(output, "}}").unwrap();
+    writeln!(output).unwrap();
+}
+
+/// Returns whether a swizzle can be assigned, which requires every component to appear at most once.
+fn is_writable(swizzle: &[usize]) -> bool {
+    swizzle
+        .iter()
+        .enumerate()
+        .all(|(i, index)| !swizzle[..i].contains(index))
+}
+
+/// Returns the component indices of every swizzle with `length` components of a vector with `width` components.
+fn swizzles(width: usize, length: usize) -> Vec<Vec<usize>> {
+    let mut swizzles = vec![Vec::new()];
+    for _ in 0..length {
+        swizzles = swizzles
+            .into_iter()
+            .flat_map(|swizzle| {
+                (0..width).map(move |index| {
+                    let mut swizzle = swizzle.clone();
+                    swizzle.push(index);
+                    swizzle
+                })
+            })
+            .collect();
+    }
+    swizzles
+}
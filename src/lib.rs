@@ -1,6 +1,64 @@
 mod math;
 mod tests;
 
+pub use math::Aabb;
+pub use math::Bool2;
+pub use math::Bool3;
+pub use math::Bool4;
+pub use math::Bvh;
+pub use math::BvhHit;
+pub use math::Compute;
+pub use math::DecomposeError;
+pub use math::Derivative;
+pub use math::Discard;
+pub use math::Double2;
+pub use math::Double3;
+pub use math::Double4;
+pub use math::EulerOrder;
 pub use math::Float2;
+pub use math::Float2x2;
+pub use math::Float2x3;
+pub use math::Float2x4;
 pub use math::Float3;
-pub use math::Float4;
\ No newline at end of file
+pub use math::Float3x2;
+pub use math::Float3x3;
+pub use math::Float3x4;
+pub use math::Float4;
+pub use math::Float4x2;
+pub use math::Float4x3;
+pub use math::Float4x4;
+pub use math::Frustum;
+pub use math::Half;
+pub use math::Half2;
+pub use math::Half3;
+pub use math::Half4;
+pub use math::Int2;
+pub use math::Int3;
+pub use math::Int4;
+pub use math::Multiply;
+pub use math::Obb;
+pub use math::Plane;
+pub use math::Quad;
+pub use math::Quaternion;
+pub use math::Ray;
+pub use math::Select;
+pub use math::SharedArray;
+pub use math::Sphere;
+pub use math::Swizzle;
+pub use math::Triangle;
+pub use math::UInt2;
+pub use math::UInt3;
+pub use math::UInt4;
+pub use math::Wave;
+pub use math::WaveNumeric;
+
+pub use math::asdouble;
+pub use math::asuint;
+pub use math::dot4add_i8packed;
+pub use math::dot4add_u8packed;
+pub use math::f16tof32;
+pub use math::f32tof16;
+pub use math::mul;
+pub use math::select;
+pub use math::unpack_s8s32;
+pub use math::unpack_u8u32;
@@ -1,7 +1,105 @@
+mod bool2;
+mod bool3;
+mod bool4;
+mod bvh;
+mod compute;
+mod construct;
+mod decompose;
+mod discard;
+mod double2;
+mod double3;
+mod double4;
 mod float2;
+mod float2x2;
+mod float2x3;
+mod float2x4;
 mod float3;
+mod float3x2;
+mod float3x3;
+mod float3x4;
 mod float4;
+mod float4x2;
+mod float4x3;
+mod float4x4;
+mod geometry;
+mod half;
+mod half2;
+mod half3;
+mod half4;
+mod int2;
+mod int3;
+mod int4;
+mod lockstep;
+mod mul;
+mod packed;
+mod quad;
+mod quaternion;
+mod reinterpret;
+mod select;
+mod swizzle;
+mod uint2;
+mod uint3;
+mod uint4;
+mod vector;
+mod wave;
 
+pub use bool2::Bool2;
+pub use bool3::Bool3;
+pub use bool4::Bool4;
+pub use bvh::Bvh;
+pub use bvh::BvhHit;
+pub use compute::Compute;
+pub use compute::SharedArray;
+pub use decompose::DecomposeError;
+pub use discard::Discard;
+pub use double2::Double2;
+pub use double3::Double3;
+pub use double4::Double4;
 pub use float2::Float2;
+pub use float2x2::Float2x2;
+pub use float2x3::Float2x3;
+pub use float2x4::Float2x4;
 pub use float3::Float3;
-pub use float4::Float4;
\ No newline at end of file
+pub use float3x2::Float3x2;
+pub use float3x3::Float3x3;
+pub use float3x4::Float3x4;
+pub use float4::Float4;
+pub use float4x2::Float4x2;
+pub use float4x3::Float4x3;
+pub use float4x4::Float4x4;
+pub use geometry::Aabb;
+pub use geometry::Frustum;
+pub use geometry::Obb;
+pub use geometry::Plane;
+pub use geometry::Ray;
+pub use geometry::Sphere;
+pub use geometry::Triangle;
+pub use half::Half;
+pub use half::f16tof32;
+pub use half::f32tof16;
+pub use half2::Half2;
+pub use half3::Half3;
+pub use half4::Half4;
+pub use int2::Int2;
+pub use int3::Int3;
+pub use int4::Int4;
+pub use mul::Multiply;
+pub use mul::mul;
+pub use packed::dot4add_i8packed;
+pub use packed::dot4add_u8packed;
+pub use packed::unpack_s8s32;
+pub use packed::unpack_u8u32;
+pub use quad::Derivative;
+pub use quad::Quad;
+pub use quaternion::EulerOrder;
+pub use quaternion::Quaternion;
+pub use reinterpret::asdouble;
+pub use reinterpret::asuint;
+pub use select::Select;
+pub use select::select;
+pub use swizzle::Swizzle;
+pub use uint2::UInt2;
+pub use uint3::UInt3;
+pub use uint4::UInt4;
+pub use wave::Wave;
+pub use wave::WaveNumeric;
@@ -0,0 +1,25 @@
+use super::Bool3;
+use super::Bool4;
+use super::swizzle::swizzles2;
+use super::vector::bool_arithmetic;
+use super::vector::vector_base;
+
+/// Vector containing 2 boolean values.
+#[derive(Copy, Clone, Debug)]
+pub struct Bool2 {
+    /// The x-component of the vector.
+    pub x: bool,
+    /// The y-component of the vector.
+    pub y: bool,
+}
+
+vector_base!(Bool2: bool { x: 0, y: 1 });
+bool_arithmetic!(Bool2 { x, y });
+swizzles2!(Bool2: bool => Bool2, Bool3, Bool4);
+
+impl Bool2 {
+    /// Creates a vector from 2 boolean values.
+    pub fn new(x: bool, y: bool) -> Self {
+        Self { x, y }
+    }
+}
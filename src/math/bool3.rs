@@ -0,0 +1,29 @@
+use super::Bool2;
+use super::Bool4;
+use super::swizzle::swizzles3;
+use super::vector::bool_arithmetic;
+use super::vector::compositions3;
+use super::vector::vector_base;
+
+/// Vector containing 3 boolean values.
+#[derive(Copy, Clone, Debug)]
+pub struct Bool3 {
+    /// The x-component of the vector.
+    pub x: bool,
+    /// The y-component of the vector.
+    pub y: bool,
+    /// The z-component of the vector.
+    pub z: bool,
+}
+
+vector_base!(Bool3: bool { x: 0, y: 1, z: 2 });
+compositions3!(Bool3: bool => Bool2);
+bool_arithmetic!(Bool3 { x, y, z });
+swizzles3!(Bool3: bool => Bool2, Bool3, Bool4);
+
+impl Bool3 {
+    /// Creates a vector from 3 boolean values.
+    pub fn new(x: bool, y: bool, z: bool) -> Self {
+        Self { x, y, z }
+    }
+}
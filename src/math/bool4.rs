@@ -0,0 +1,31 @@
+use super::Bool2;
+use super::Bool3;
+use super::swizzle::swizzles4;
+use super::vector::bool_arithmetic;
+use super::vector::compositions4;
+use super::vector::vector_base;
+
+/// Vector containing 4 boolean values.
+#[derive(Copy, Clone, Debug)]
+pub struct Bool4 {
+    /// The x-component of the vector.
+    pub x: bool,
+    /// The y-component of the vector.
+    pub y: bool,
+    /// The z-component of the vector.
+    pub z: bool,
+    /// The w-component of the vector.
+    pub w: bool,
+}
+
+vector_base!(Bool4: bool { x: 0, y: 1, z: 2, w: 3 });
+compositions4!(Bool4: bool => Bool2, Bool3);
+bool_arithmetic!(Bool4 { x, y, z, w });
+swizzles4!(Bool4: bool => Bool2, Bool3, Bool4);
+
+impl Bool4 {
+    /// Creates a vector from 4 boolean values.
+    pub fn new(x: bool, y: bool, z: bool, w: bool) -> Self {
+        Self { x, y, z, w }
+    }
+}
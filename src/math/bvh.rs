@@ -0,0 +1,252 @@
+// Bounding volume hierarchy over triangles for CPU ray queries such as lightmap baking.
+// Built top-down with the surface area heuristic evaluated over binned triangle centroids.
+
+use super::Aabb;
+use super::Float2;
+use super::Float3;
+use super::Ray;
+use super::Triangle;
+
+/// The number of bins the centroids are sorted into per axis when searching for a split.
+const BINS: usize = 16;
+
+/// The number of triangles below which a node is never split.
+const LEAF_SIZE: usize = 2;
+
+/// The closest or any intersection of a ray with the triangles of a `Bvh`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BvhHit {
+    /// The distance along the ray, in multiples of the length of its direction.
+    pub distance: f32,
+    /// The index of the triangle that was hit.
+    pub triangle: usize,
+    /// The barycentrics `(u, v)` of the hit, which lies at `a * (1 - u - v) + b * u + c * v`.
+    pub barycentrics: Float2,
+}
+
+/// Bounding volume hierarchy accelerating ray queries against a list of triangles.
+#[derive(Clone, Debug)]
+pub struct Bvh {
+    nodes: Vec<Node>,
+    indices: Vec<usize>,
+    triangles: Vec<Triangle>,
+}
+
+/// A leaf when `count` is non-zero, covering `indices[first..first + count]`;
+/// otherwise an interior node whose children are `nodes[first]` and `nodes[first + 1]`.
+#[derive(Copy, Clone, Debug)]
+struct Node {
+    bounds: Aabb,
+    first: usize,
+    count: usize,
+}
+
+impl Bvh {
+    /// Builds the hierarchy over a list of triangles.
+    pub fn new(triangles: &[Triangle]) -> Self {
+        let mut bvh = Self {
+            nodes: Vec::new(),
+            indices: (0..triangles.len()).collect(),
+            triangles: triangles.to_vec(),
+        };
+        if !triangles.is_empty() {
+            bvh.build();
+        }
+        bvh
+    }
+
+    /// Builds the hierarchy over an indexed triangle list, where every three indices into `positions` form a triangle.
+    pub fn from_indexed(positions: &[Float3], indices: &[u32]) -> Self {
+        let triangles: Vec<Triangle> = indices
+            .chunks_exact(3)
+            .map(|i| {
+                Triangle::new(
+                    positions[i[0] as usize],
+                    positions[i[1] as usize],
+                    positions[i[2] as usize],
+                )
+            })
+            .collect();
+        Self::new(&triangles)
+    }
+
+    /// Finds any intersection of the ray with a distance of at most `max_distance`; suited for visibility queries.
+    pub fn any_hit(&self, ray: &Ray, max_distance: f32) -> Option<BvhHit> {
+        self.traverse(ray, max_distance, true)
+    }
+
+    /// Returns the bounds of all triangles; an empty box when there are none.
+    pub fn bounds(&self) -> Aabb {
+        self.nodes.first().map_or(Aabb::empty(), |node| node.bounds)
+    }
+
+    /// Finds the closest intersection of the ray with a distance of at most `max_distance`.
+    pub fn closest_hit(&self, ray: &Ray, max_distance: f32) -> Option<BvhHit> {
+        self.traverse(ray, max_distance, false)
+    }
+
+    /// Returns the triangles in the order used for `BvhHit::triangle`.
+    pub fn triangles(&self) -> &[Triangle] {
+        &self.triangles
+    }
+
+    fn build(&mut self) {
+        let bounds: Vec<Aabb> = self.triangles.iter().map(Triangle::aabb).collect();
+        let centroids: Vec<Float3> = bounds.iter().map(Aabb::center).collect();
+        self.nodes.push(Node {
+            bounds: bounds.iter().fold(Aabb::empty(), |a, b| a.union(b)),
+            first: 0,
+            count: self.triangles.len(),
+        });
+        let mut stack = vec![0];
+        while let Some(index) = stack.pop() {
+            let node = self.nodes[index];
+            let Some(mid) = self.split(&node, &bounds, &centroids) else {
+                continue;
+            };
+            let range = [(node.first, mid), (mid, node.first + node.count)];
+            self.nodes[index] = Node {
+                bounds: node.bounds,
+                first: self.nodes.len(),
+                count: 0,
+            };
+            for (start, end) in range {
+                stack.push(self.nodes.len());
+                self.nodes.push(Node {
+                    bounds: self.indices[start..end]
+                        .iter()
+                        .fold(Aabb::empty(), |aabb, &i| aabb.union(&bounds[i])),
+                    first: start,
+                    count: end - start,
+                });
+            }
+        }
+    }
+
+    /// Partitions the triangles of a node along the split with the lowest surface area heuristic cost.
+    /// Returns the start of the second half, or `None` when keeping the node as a leaf is cheaper.
+    fn split(&mut self, node: &Node, bounds: &[Aabb], centroids: &[Float3]) -> Option<usize> {
+        if node.count <= LEAF_SIZE {
+            return None;
+        }
+        let range = node.first..node.first + node.count;
+        let extent = Aabb::from_points(
+            &self.indices[range.clone()]
+                .iter()
+                .map(|&i| centroids[i])
+                .collect::<Vec<_>>(),
+        );
+        let mut best: Option<(f32, usize, usize)> = None;
+        for axis in 0..3 {
+            let (min, max) = (component(&extent.min, axis), component(&extent.max, axis));
+            if max <= min {
+                continue;
+            }
+            let bin_of = |i: usize| bin(component(&centroids[i], axis), min, max);
+            let mut bins = [(Aabb::empty(), 0usize); BINS];
+            for &i in &self.indices[range.clone()] {
+                let (aabb, count) = &mut bins[bin_of(i)];
+                *aabb = aabb.union(&bounds[i]);
+                *count += 1;
+            }
+            let mut right = [0.0; BINS];
+            let (mut aabb, mut count) = (Aabb::empty(), 0);
+            for split in (1..BINS).rev() {
+                aabb = aabb.union(&bins[split].0);
+                count += bins[split].1;
+                right[split] = aabb.surface_area() * count as f32;
+            }
+            let (mut aabb, mut count) = (Aabb::empty(), 0);
+            for split in 1..BINS {
+                aabb = aabb.union(&bins[split - 1].0);
+                count += bins[split - 1].1;
+                if count == 0 || count == node.count {
+                    continue;
+                }
+                let cost = aabb.surface_area() * count as f32 + right[split];
+                if best.is_none_or(|(best, _, _)| cost < best) {
+                    best = Some((cost, axis, split));
+                }
+            }
+        }
+        let (cost, axis, split) = best?;
+        if cost >= node.bounds.surface_area() * node.count as f32 {
+            return None;
+        }
+        let (min, max) = (component(&extent.min, axis), component(&extent.max, axis));
+        let bin_of = |i: usize| bin(component(&centroids[i], axis), min, max);
+        let (mut mid, mut end) = (range.start, range.end);
+        while mid < end {
+            if bin_of(self.indices[mid]) < split {
+                mid += 1;
+            } else {
+                end -= 1;
+                self.indices.swap(mid, end);
+            }
+        }
+        (mid != range.start && mid != range.end).then_some(mid)
+    }
+
+    fn traverse(&self, ray: &Ray, max_distance: f32, any: bool) -> Option<BvhHit> {
+        let entry = |bounds: &Aabb, max: f32| {
+            let (enter, exit) = ray.intersect_aabb_interval(bounds)?;
+            (enter <= exit.min(max)).then_some(enter)
+        };
+        let mut closest: Option<BvhHit> = None;
+        let mut max = max_distance;
+        let mut stack = Vec::new();
+        if let Some(root) = self.nodes.first() {
+            stack.extend(entry(&root.bounds, max).map(|enter| (0, enter)));
+        }
+        while let Some((index, enter)) = stack.pop() {
+            if enter > max {
+                continue;
+            }
+            let node = &self.nodes[index];
+            if node.count > 0 {
+                for &triangle in &self.indices[node.first..node.first + node.count] {
+                    let Some((distance, barycentrics)) =
+                        ray.intersect_triangle(&self.triangles[triangle])
+                    else {
+                        continue;
+                    };
+                    if distance <= max {
+                        max = distance;
+                        closest = Some(BvhHit {
+                            distance,
+                            triangle,
+                            barycentrics,
+                        });
+                        if any {
+                            return closest;
+                        }
+                    }
+                }
+                continue;
+            }
+            let (left, right) = (node.first, node.first + 1);
+            let left = entry(&self.nodes[left].bounds, max).map(|enter| (left, enter));
+            let right = entry(&self.nodes[right].bounds, max).map(|enter| (right, enter));
+            // push the farther child first so that the nearer one is visited first.
+            match (left, right) {
+                (Some(left), Some(right)) if left.1 <= right.1 => stack.extend([right, left]),
+                (left, right) => stack.extend(left.into_iter().chain(right)),
+            }
+        }
+        closest
+    }
+}
+
+/// Returns the bin a centroid coordinate falls into, given the range of the centroids along the axis.
+fn bin(value: f32, min: f32, max: f32) -> usize {
+    (((value - min) / (max - min) * BINS as f32) as usize).min(BINS - 1)
+}
+
+/// Returns the component of a vector along an axis, where 0 is x, 1 is y and 2 is z.
+fn component(v: &Float3, axis: usize) -> f32 {
+    match axis {
+        0 => v.x,
+        1 => v.y,
+        _ => v.z,
+    }
+}
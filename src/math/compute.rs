@@ -0,0 +1,159 @@
+// Emulation of compute shader dispatches.
+//
+// The thread groups of a dispatch run one after another; the threads of a group run on their
+// own threads in lockstep so `group_memory_barrier_with_group_sync` behaves like on the GPU.
+
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+
+use super::UInt3;
+use super::lockstep::Departed;
+use super::lockstep::Lockstep;
+
+/// Array with interior mutability shared by the threads of a dispatch.
+/// Used for `groupshared` arrays as well as read-write buffers such as `RWStructuredBuffer`.
+#[derive(Debug)]
+pub struct SharedArray<T> {
+    values: Mutex<Vec<T>>,
+}
+
+impl<T: Copy> From<Vec<T>> for SharedArray<T> {
+    fn from(lhs: Vec<T>) -> Self {
+        Self {
+            values: Mutex::new(lhs),
+        }
+    }
+}
+
+impl<T: Copy> SharedArray<T> {
+    /// Creates an array of `len` elements set to `value`.
+    pub fn new(len: usize, value: T) -> Self {
+        Self::from(vec![value; len])
+    }
+
+    /// Returns the element at `index`.
+    pub fn get(&self, index: usize) -> T {
+        self.lock()[index]
+    }
+
+    /// Determines if the array has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Returns the number of elements in the array.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Assigns `value` to the element at `index`.
+    pub fn set(&self, index: usize, value: T) {
+        self.lock()[index] = value;
+    }
+
+    /// Returns a copy of all elements.
+    pub fn to_vec(&self) -> Vec<T> {
+        self.lock().clone()
+    }
+
+    /// Atomically replaces the element at `index` with `f(element)` and returns the original element.
+    /// Emulates the interlocked intrinsics such as `InterlockedAdd`.
+    pub fn update(&self, index: usize, f: impl FnOnce(T) -> T) -> T {
+        let mut values = self.lock();
+        let original = values[index];
+        values[index] = f(original);
+        original
+    }
+
+    /// Locks the elements; a thread that panicked while holding the lock does not poison it.
+    fn lock(&self) -> MutexGuard<'_, Vec<T>> {
+        self.values.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// A thread of a compute shader dispatch, giving access to the system values and group shared state.
+pub struct Compute<'a, S> {
+    group_id: UInt3,
+    group_thread_id: UInt3,
+    numthreads: UInt3,
+    shared: &'a S,
+    lockstep: &'a Lockstep,
+}
+
+impl<S: Sync> Compute<'_, S> {
+    /// Runs `kernel` for every thread of `groups` thread groups of `numthreads` threads each, like `Dispatch`.
+    /// Every group receives its own shared state created by `groupshared`, like `groupshared` variables in HLSL.
+    /// Panics if a component of `numthreads` is 0 or if a group has more than 1024 threads.
+    pub fn dispatch<G, F>(groups: UInt3, numthreads: UInt3, groupshared: G, kernel: F)
+    where
+        G: Fn() -> S,
+        F: Fn(&Compute<S>) + Sync,
+    {
+        let count = numthreads.x as u64 * numthreads.y as u64 * numthreads.z as u64;
+        assert!(
+            (1..=1024).contains(&count),
+            "a thread group must have 1 to 1024 threads"
+        );
+        for z in 0..groups.z {
+            for y in 0..groups.y {
+                for x in 0..groups.x {
+                    let shared = groupshared();
+                    let lockstep = Lockstep::new(count as usize);
+                    lockstep.run(|index| {
+                        let index = index as u32;
+                        let compute = Compute {
+                            group_id: UInt3::new(x, y, z),
+                            group_thread_id: UInt3::new(
+                                index % numthreads.x,
+                                index / numthreads.x % numthreads.y,
+                                index / (numthreads.x * numthreads.y),
+                            ),
+                            numthreads,
+                            shared: &shared,
+                            lockstep: &lockstep,
+                        };
+                        kernel(&compute)
+                    });
+                }
+            }
+        }
+    }
+
+    /// Returns the index of this thread within the dispatch, like `SV_DispatchThreadID`.
+    pub fn dispatch_thread_id(&self) -> UInt3 {
+        self.group_id * self.numthreads + self.group_thread_id
+    }
+
+    /// Blocks until all threads of the group have reached this call, like `GroupMemoryBarrierWithGroupSync`.
+    /// All threads of the group must call it the same number of times, like uniform control flow in HLSL;
+    /// panics if a thread returns while the others wait here, where the GPU would hang.
+    pub fn group_memory_barrier_with_group_sync(&self) {
+        self.lockstep.wait().unwrap_or_else(|Departed| {
+            panic!(
+                "a thread of the group returned before reaching a barrier the other threads wait at"
+            )
+        });
+    }
+
+    /// Returns the index of the thread group within the dispatch, like `SV_GroupID`.
+    pub fn group_id(&self) -> UInt3 {
+        self.group_id
+    }
+
+    /// Returns the flattened index of this thread within its group, like `SV_GroupIndex`.
+    pub fn group_index(&self) -> u32 {
+        let id = self.group_thread_id;
+        (id.z * self.numthreads.y + id.y) * self.numthreads.x + id.x
+    }
+
+    /// Returns the index of this thread within its group, like `SV_GroupThreadID`.
+    pub fn group_thread_id(&self) -> UInt3 {
+        self.group_thread_id
+    }
+
+    /// Returns the state shared by the threads of this group.
+    pub fn shared(&self) -> &S {
+        self.shared
+    }
+}
@@ -0,0 +1,202 @@
+// Constructor macros mirroring the HLSL vector constructors such as `float4(pos.xyz, 1.0)`.
+//
+// A single argument is converted with `From` (splatting scalars) and several arguments are
+// collected into a tuple, so every composition supported by the `From` impls is accepted.
+
+/// Creates a `Bool2` from any mix of boolean scalars and vectors totaling 2 components, like `bool2(...)` in HLSL.
+#[macro_export]
+macro_rules! bool2 {
+    ($e:expr $(,)?) => {
+        $crate::Bool2::from($e)
+    };
+    ($($e:expr),+ $(,)?) => {
+        $crate::Bool2::from(($($e,)+))
+    };
+}
+
+/// Creates a `Bool3` from any mix of boolean scalars and vectors totaling 3 components, like `bool3(...)` in HLSL.
+#[macro_export]
+macro_rules! bool3 {
+    ($e:expr $(,)?) => {
+        $crate::Bool3::from($e)
+    };
+    ($($e:expr),+ $(,)?) => {
+        $crate::Bool3::from(($($e,)+))
+    };
+}
+
+/// Creates a `Bool4` from any mix of boolean scalars and vectors totaling 4 components, like `bool4(...)` in HLSL.
+#[macro_export]
+macro_rules! bool4 {
+    ($e:expr $(,)?) => {
+        $crate::Bool4::from($e)
+    };
+    ($($e:expr),+ $(,)?) => {
+        $crate::Bool4::from(($($e,)+))
+    };
+}
+
+/// Creates a `Double2` from any mix of double-precision floating point scalars and vectors totaling 2 components, like `double2(...)` in HLSL.
+#[macro_export]
+macro_rules! double2 {
+    ($e:expr $(,)?) => {
+        $crate::Double2::from($e)
+    };
+    ($($e:expr),+ $(,)?) => {
+        $crate::Double2::from(($($e,)+))
+    };
+}
+
+/// Creates a `Double3` from any mix of double-precision floating point scalars and vectors totaling 3 components, like `double3(...)` in HLSL.
+#[macro_export]
+macro_rules! double3 {
+    ($e:expr $(,)?) => {
+        $crate::Double3::from($e)
+    };
+    ($($e:expr),+ $(,)?) => {
+        $crate::Double3::from(($($e,)+))
+    };
+}
+
+/// Creates a `Double4` from any mix of double-precision floating point scalars and vectors totaling 4 components, like `double4(...)` in HLSL.
+#[macro_export]
+macro_rules! double4 {
+    ($e:expr $(,)?) => {
+        $crate::Double4::from($e)
+    };
+    ($($e:expr),+ $(,)?) => {
+        $crate::Double4::from(($($e,)+))
+    };
+}
+
+/// Creates a `Float2` from any mix of floating point scalars and vectors totaling 2 components, like `float2(...)` in HLSL.
+#[macro_export]
+macro_rules! float2 {
+    ($e:expr $(,)?) => {
+        $crate::Float2::from($e)
+    };
+    ($($e:expr),+ $(,)?) => {
+        $crate::Float2::from(($($e,)+))
+    };
+}
+
+/// Creates a `Float3` from any mix of floating point scalars and vectors totaling 3 components, like `float3(...)` in HLSL.
+#[macro_export]
+macro_rules! float3 {
+    ($e:expr $(,)?) => {
+        $crate::Float3::from($e)
+    };
+    ($($e:expr),+ $(,)?) => {
+        $crate::Float3::from(($($e,)+))
+    };
+}
+
+/// Creates a `Float4` from any mix of floating point scalars and vectors totaling 4 components, like `float4(...)` in HLSL.
+#[macro_export]
+macro_rules! float4 {
+    ($e:expr $(,)?) => {
+        $crate::Float4::from($e)
+    };
+    ($($e:expr),+ $(,)?) => {
+        $crate::Float4::from(($($e,)+))
+    };
+}
+
+/// Creates a `Half2` from any mix of half-precision floating point scalars and vectors totaling 2 components, like `half2(...)` in HLSL.
+#[macro_export]
+macro_rules! half2 {
+    ($e:expr $(,)?) => {
+        $crate::Half2::from($e)
+    };
+    ($($e:expr),+ $(,)?) => {
+        $crate::Half2::from(($($e,)+))
+    };
+}
+
+/// Creates a `Half3` from any mix of half-precision floating point scalars and vectors totaling 3 components, like `half3(...)` in HLSL.
+#[macro_export]
+macro_rules! half3 {
+    ($e:expr $(,)?) => {
+        $crate::Half3::from($e)
+    };
+    ($($e:expr),+ $(,)?) => {
+        $crate::Half3::from(($($e,)+))
+    };
+}
+
+/// Creates a `Half4` from any mix of half-precision floating point scalars and vectors totaling 4 components, like `half4(...)` in HLSL.
+#[macro_export]
+macro_rules! half4 {
+    ($e:expr $(,)?) => {
+        $crate::Half4::from($e)
+    };
+    ($($e:expr),+ $(,)?) => {
+        $crate::Half4::from(($($e,)+))
+    };
+}
+
+/// Creates an `Int2` from any mix of signed integer scalars and vectors totaling 2 components, like `int2(...)` in HLSL.
+#[macro_export]
+macro_rules! int2 {
+    ($e:expr $(,)?) => {
+        $crate::Int2::from($e)
+    };
+    ($($e:expr),+ $(,)?) => {
+        $crate::Int2::from(($($e,)+))
+    };
+}
+
+/// Creates an `Int3` from any mix of signed integer scalars and vectors totaling 3 components, like `int3(...)` in HLSL.
+#[macro_export]
+macro_rules! int3 {
+    ($e:expr $(,)?) => {
+        $crate::Int3::from($e)
+    };
+    ($($e:expr),+ $(,)?) => {
+        $crate::Int3::from(($($e,)+))
+    };
+}
+
+/// Creates an `Int4` from any mix of signed integer scalars and vectors totaling 4 components, like `int4(...)` in HLSL.
+#[macro_export]
+macro_rules! int4 {
+    ($e:expr $(,)?) => {
+        $crate::Int4::from($e)
+    };
+    ($($e:expr),+ $(,)?) => {
+        $crate::Int4::from(($($e,)+))
+    };
+}
+
+/// Creates a `UInt2` from any mix of unsigned integer scalars and vectors totaling 2 components, like `uint2(...)` in HLSL.
+#[macro_export]
+macro_rules! uint2 {
+    ($e:expr $(,)?) => {
+        $crate::UInt2::from($e)
+    };
+    ($($e:expr),+ $(,)?) => {
+        $crate::UInt2::from(($($e,)+))
+    };
+}
+
+/// Creates a `UInt3` from any mix of unsigned integer scalars and vectors totaling 3 components, like `uint3(...)` in HLSL.
+#[macro_export]
+macro_rules! uint3 {
+    ($e:expr $(,)?) => {
+        $crate::UInt3::from($e)
+    };
+    ($($e:expr),+ $(,)?) => {
+        $crate::UInt3::from(($($e,)+))
+    };
+}
+
+/// Creates a `UInt4` from any mix of unsigned integer scalars and vectors totaling 4 components, like `uint4(...)` in HLSL.
+#[macro_export]
+macro_rules! uint4 {
+    ($e:expr $(,)?) => {
+        $crate::UInt4::from($e)
+    };
+    ($($e:expr),+ $(,)?) => {
+        $crate::UInt4::from(($($e,)+))
+    };
+}
@@ -0,0 +1,66 @@
+// Decomposition of affine transforms into translation, rotation and scale,
+// shared by the matrix types that can hold an affine transform.
+
+use core::error;
+use core::fmt;
+
+use super::Float3;
+use super::Float3x3;
+use super::Quaternion;
+
+/// Tolerance used to detect singular, sheared and non-affine matrices.
+const EPSILON: f32 = 1.0e-5;
+
+/// Signals why a matrix could not be decomposed into translation, rotation and scale.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DecomposeError {
+    /// The matrix does not have (0, 0, 0, 1) as its last column.
+    NotAffine,
+    /// The axes of the matrix are not perpendicular to each other.
+    Shear,
+    /// The matrix collapses at least one axis to zero length, or to nearly zero compared to the longest axis.
+    Singular,
+}
+
+impl fmt::Display for DecomposeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecomposeError::NotAffine => write!(f, "the matrix is not affine"),
+            DecomposeError::Shear => write!(f, "the matrix contains shear"),
+            DecomposeError::Singular => write!(f, "the matrix is singular"),
+        }
+    }
+}
+
+impl error::Error for DecomposeError {}
+
+/// Decomposes the linear rows and translation of a row vector transform.
+/// A reflection is returned as a negative x scale.
+pub(crate) fn decompose(
+    rows: Float3x3,
+    translation: Float3,
+) -> Result<(Float3, Quaternion, Float3), DecomposeError> {
+    let mut scale = Float3::new(rows.r0.length(), rows.r1.length(), rows.r2.length());
+    // relative to the longest axis, so a uniformly tiny scale is not mistaken for a collapsed axis.
+    let longest = scale.x.max(scale.y).max(scale.z);
+    if scale.x.min(scale.y).min(scale.z) <= longest * EPSILON {
+        return Err(DecomposeError::Singular);
+    }
+    let mut x = rows.r0 / scale.x;
+    let y = rows.r1 / scale.y;
+    let z = rows.r2 / scale.z;
+    if x.dot(&y).abs() > EPSILON || x.dot(&z).abs() > EPSILON || y.dot(&z).abs() > EPSILON {
+        return Err(DecomposeError::Shear);
+    }
+    if x.cross(&y).dot(&z) < 0.0 {
+        scale.x = -scale.x;
+        x = -x;
+    }
+    let rotation = Quaternion::from_float3x3(&Float3x3::new(x, y, z));
+    Ok((translation, rotation, scale))
+}
+
+/// Returns whether the last column of a 4x4 matrix is (0, 0, 0, 1).
+pub(crate) fn is_affine(column: Float3, w: f32) -> bool {
+    column.abs().lt(&Float3::from(EPSILON)).all() && (w - 1.0).abs() < EPSILON
+}
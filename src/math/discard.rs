@@ -0,0 +1,14 @@
+use core::error;
+use core::fmt;
+
+/// Signals that a pixel was discarded by `clip`, like the `discard` statement in HLSL.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Discard;
+
+impl fmt::Display for Discard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the pixel was discarded")
+    }
+}
+
+impl error::Error for Discard {}
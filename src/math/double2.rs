@@ -0,0 +1,54 @@
+use super::Bool2;
+use super::Double3;
+use super::Double4;
+use super::Float2;
+use super::UInt2;
+use super::reinterpret::reinterpret_double;
+use super::swizzle::swizzles2;
+use super::vector::float_arithmetic;
+use super::vector::float_methods;
+use super::vector::vector_base;
+
+/// Vector containing 2 double-precision floating point values.
+#[derive(Copy, Clone, Debug)]
+pub struct Double2 {
+    /// The x-component of the vector.
+    pub x: f64,
+    /// The y-component of the vector.
+    pub y: f64,
+}
+
+vector_base!(Double2: f64 { x: 0, y: 1 });
+float_arithmetic!(Double2: f64 { x, y });
+float_methods!(Double2: f64, Bool2 { x, y });
+reinterpret_double!(Double2 { x, y } => UInt2);
+swizzles2!(Double2: f64 => Double2, Double3, Double4);
+
+impl From<Float2> for Double2 {
+    fn from(lhs: Float2) -> Self {
+        Self {
+            x: lhs.x as f64,
+            y: lhs.y as f64,
+        }
+    }
+}
+
+impl Double2 {
+    /// Creates a vector from 2 double-precision floating point values.
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    /// Computes the four quadrant arctangent of y and x (in radians).
+    pub fn atan2(&self) -> f64 {
+        self.y.atan2(self.x)
+    }
+
+    /// Converts the vector to single-precision floating point values; losing precision.
+    pub fn to_float2(&self) -> Float2 {
+        Float2 {
+            x: self.x as f32,
+            y: self.y as f32,
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use super::Bool3;
+use super::Double2;
+use super::Double4;
+use super::Float3;
+use super::UInt3;
+use super::reinterpret::reinterpret_double;
+use super::swizzle::swizzles3;
+use super::vector::compositions3;
+use super::vector::float_arithmetic;
+use super::vector::float_methods;
+use super::vector::vector_base;
+
+/// Vector containing 3 double-precision floating point values.
+#[derive(Copy, Clone, Debug)]
+pub struct Double3 {
+    /// The x-component of the vector.
+    pub x: f64,
+    /// The y-component of the vector.
+    pub y: f64,
+    /// The z-component of the vector.
+    pub z: f64,
+}
+
+vector_base!(Double3: f64 { x: 0, y: 1, z: 2 });
+compositions3!(Double3: f64 => Double2);
+float_arithmetic!(Double3: f64 { x, y, z });
+float_methods!(Double3: f64, Bool3 { x, y, z });
+reinterpret_double!(Double3 { x, y, z } => UInt3);
+swizzles3!(Double3: f64 => Double2, Double3, Double4);
+
+impl From<Float3> for Double3 {
+    fn from(lhs: Float3) -> Self {
+        Self {
+            x: lhs.x as f64,
+            y: lhs.y as f64,
+            z: lhs.z as f64,
+        }
+    }
+}
+
+impl Double3 {
+    /// Creates a vector from 3 double-precision floating point values.
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Computes the cross product of two vectors.
+    pub fn cross(&self, rhs: &Self) -> Self {
+        Self {
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x,
+        }
+    }
+
+    /// Converts the vector to single-precision floating point values; losing precision.
+    pub fn to_float3(&self) -> Float3 {
+        Float3 {
+            x: self.x as f32,
+            y: self.y as f32,
+            z: self.z as f32,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use super::Bool4;
+use super::Double2;
+use super::Double3;
+use super::Float4;
+use super::UInt4;
+use super::reinterpret::reinterpret_double;
+use super::swizzle::swizzles4;
+use super::vector::compositions4;
+use super::vector::float_arithmetic;
+use super::vector::float_methods;
+use super::vector::vector_base;
+
+/// Vector containing 4 double-precision floating point values.
+#[derive(Copy, Clone, Debug)]
+pub struct Double4 {
+    /// The x-component of the vector.
+    pub x: f64,
+    /// The y-component of the vector.
+    pub y: f64,
+    /// The z-component of the vector.
+    pub z: f64,
+    /// The w-component of the vector.
+    pub w: f64,
+}
+
+vector_base!(Double4: f64 { x: 0, y: 1, z: 2, w: 3 });
+compositions4!(Double4: f64 => Double2, Double3);
+float_arithmetic!(Double4: f64 { x, y, z, w });
+float_methods!(Double4: f64, Bool4 { x, y, z, w });
+reinterpret_double!(Double4 { x, y, z, w } => UInt4);
+swizzles4!(Double4: f64 => Double2, Double3, Double4);
+
+impl From<Float4> for Double4 {
+    fn from(lhs: Float4) -> Self {
+        Self {
+            x: lhs.x as f64,
+            y: lhs.y as f64,
+            z: lhs.z as f64,
+            w: lhs.w as f64,
+        }
+    }
+}
+
+impl Double4 {
+    /// Creates a vector from 4 double-precision floating point values.
+    pub fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Converts the vector to single-precision floating point values; losing precision.
+    pub fn to_float4(&self) -> Float4 {
+        Float4 {
+            x: self.x as f32,
+            y: self.y as f32,
+            z: self.z as f32,
+            w: self.w as f32,
+        }
+    }
+}
@@ -0,0 +1,318 @@
+use core::fmt;
+use core::ops;
+
+use super::Float2;
+use super::Float2x3;
+use super::Float2x4;
+use super::Multiply;
+use super::mul;
+
+/// Matrix containing 2 rows and 2 columns of floating point values.
+#[derive(Copy, Clone, Debug)]
+pub struct Float2x2 {
+    /// The first row of the matrix.
+    pub r0: Float2,
+    /// The second row of the matrix.
+    pub r1: Float2,
+}
+
+impl fmt::Display for Float2x2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Float2x2 (({}, {}), ({}, {}))",
+            self.r0.x, self.r0.y, self.r1.x, self.r1.y
+        )
+    }
+}
+
+impl ops::Add<Self> for Float2x2 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            r0: self.r0 + rhs.r0,
+            r1: self.r1 + rhs.r1,
+        }
+    }
+}
+
+impl ops::Add<f32> for Float2x2 {
+    type Output = Self;
+
+    fn add(self, rhs: f32) -> Self {
+        Self {
+            r0: self.r0 + rhs,
+            r1: self.r1 + rhs,
+        }
+    }
+}
+
+impl ops::AddAssign<Self> for Float2x2 {
+    fn add_assign(&mut self, rhs: Self) {
+        self.r0 += rhs.r0;
+        self.r1 += rhs.r1;
+    }
+}
+
+impl ops::AddAssign<f32> for Float2x2 {
+    fn add_assign(&mut self, rhs: f32) {
+        self.r0 += rhs;
+        self.r1 += rhs;
+    }
+}
+
+impl ops::Sub<Self> for Float2x2 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            r0: self.r0 - rhs.r0,
+            r1: self.r1 - rhs.r1,
+        }
+    }
+}
+
+impl ops::Sub<f32> for Float2x2 {
+    type Output = Self;
+
+    fn sub(self, rhs: f32) -> Self {
+        Self {
+            r0: self.r0 - rhs,
+            r1: self.r1 - rhs,
+        }
+    }
+}
+
+impl ops::SubAssign<Self> for Float2x2 {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.r0 -= rhs.r0;
+        self.r1 -= rhs.r1;
+    }
+}
+
+impl ops::SubAssign<f32> for Float2x2 {
+    fn sub_assign(&mut self, rhs: f32) {
+        self.r0 -= rhs;
+        self.r1 -= rhs;
+    }
+}
+
+impl ops::Mul<Self> for Float2x2 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            r0: self.r0 * rhs.r0,
+            r1: self.r1 * rhs.r1,
+        }
+    }
+}
+
+impl ops::Mul<f32> for Float2x2 {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Self {
+            r0: self.r0 * rhs,
+            r1: self.r1 * rhs,
+        }
+    }
+}
+
+impl ops::MulAssign<Self> for Float2x2 {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.r0 *= rhs.r0;
+        self.r1 *= rhs.r1;
+    }
+}
+
+impl ops::MulAssign<f32> for Float2x2 {
+    fn mul_assign(&mut self, rhs: f32) {
+        self.r0 *= rhs;
+        self.r1 *= rhs;
+    }
+}
+
+impl ops::Div<Self> for Float2x2 {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Self {
+            r0: self.r0 / rhs.r0,
+            r1: self.r1 / rhs.r1,
+        }
+    }
+}
+
+impl ops::Div<f32> for Float2x2 {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self {
+        Self {
+            r0: self.r0 / rhs,
+            r1: self.r1 / rhs,
+        }
+    }
+}
+
+impl ops::DivAssign<Self> for Float2x2 {
+    fn div_assign(&mut self, rhs: Self) {
+        self.r0 /= rhs.r0;
+        self.r1 /= rhs.r1;
+    }
+}
+
+impl ops::DivAssign<f32> for Float2x2 {
+    fn div_assign(&mut self, rhs: f32) {
+        self.r0 /= rhs;
+        self.r1 /= rhs;
+    }
+}
+
+impl ops::Neg for Float2x2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            r0: -self.r0,
+            r1: -self.r1,
+        }
+    }
+}
+
+impl PartialEq for Float2x2 {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.r0 == rhs.r0 && self.r1 == rhs.r1
+    }
+}
+
+impl From<f32> for Float2x2 {
+    fn from(lhs: f32) -> Self {
+        Self {
+            r0: Float2::from(lhs),
+            r1: Float2::from(lhs),
+        }
+    }
+}
+
+impl From<(Float2, Float2)> for Float2x2 {
+    fn from(lhs: (Float2, Float2)) -> Self {
+        Self {
+            r0: lhs.0,
+            r1: lhs.1,
+        }
+    }
+}
+
+impl Multiply<Float2> for Float2x2 {
+    type Output = Float2;
+
+    fn mul(self, rhs: Float2) -> Float2 {
+        Float2 {
+            x: self.r0.dot(&rhs),
+            y: self.r1.dot(&rhs),
+        }
+    }
+}
+
+impl Multiply<Float2x2> for Float2 {
+    type Output = Float2;
+
+    fn mul(self, rhs: Float2x2) -> Float2 {
+        rhs.r0 * self.x + rhs.r1 * self.y
+    }
+}
+
+impl Multiply<Float2x2> for Float2x2 {
+    type Output = Float2x2;
+
+    fn mul(self, rhs: Float2x2) -> Float2x2 {
+        Float2x2 {
+            r0: mul(self.r0, rhs),
+            r1: mul(self.r1, rhs),
+        }
+    }
+}
+
+impl Multiply<Float2x3> for Float2x2 {
+    type Output = Float2x3;
+
+    fn mul(self, rhs: Float2x3) -> Float2x3 {
+        Float2x3 {
+            r0: mul(self.r0, rhs),
+            r1: mul(self.r1, rhs),
+        }
+    }
+}
+
+impl Multiply<Float2x4> for Float2x2 {
+    type Output = Float2x4;
+
+    fn mul(self, rhs: Float2x4) -> Float2x4 {
+        Float2x4 {
+            r0: mul(self.r0, rhs),
+            r1: mul(self.r1, rhs),
+        }
+    }
+}
+
+impl Float2x2 {
+    /// Creates a matrix from 2 row vectors.
+    pub fn new(r0: Float2, r1: Float2) -> Self {
+        Self { r0, r1 }
+    }
+
+    /// Creates an identity matrix.
+    pub fn identity() -> Self {
+        Self {
+            r0: Float2::new(1.0, 0.0),
+            r1: Float2::new(0.0, 1.0),
+        }
+    }
+
+    /// Returns the first column of the matrix.
+    pub fn c0(&self) -> Float2 {
+        Float2 {
+            x: self.r0.x,
+            y: self.r1.x,
+        }
+    }
+
+    /// Returns the second column of the matrix.
+    pub fn c1(&self) -> Float2 {
+        Float2 {
+            x: self.r0.y,
+            y: self.r1.y,
+        }
+    }
+
+    /// Computes the determinant of the matrix.
+    pub fn determinant(&self) -> f32 {
+        self.r0.x * self.r1.y - self.r0.y * self.r1.x
+    }
+
+    /// Computes the inverse of the matrix.
+    /// The result contains infinite or NaN values when the matrix is singular (the determinant is 0).
+    pub fn inverse(&self) -> Self {
+        let inv_det = 1.0 / self.determinant();
+        Self {
+            r0: Float2 {
+                x: self.r1.y * inv_det,
+                y: -self.r0.y * inv_det,
+            },
+            r1: Float2 {
+                x: -self.r1.x * inv_det,
+                y: self.r0.x * inv_det,
+            },
+        }
+    }
+
+    /// Computes the transpose of the matrix; swapping rows and columns.
+    pub fn transpose(&self) -> Float2x2 {
+        Float2x2 {
+            r0: self.c0(),
+            r1: self.c1(),
+        }
+    }
+}
@@ -0,0 +1,311 @@
+use core::fmt;
+use core::ops;
+
+use super::Float2;
+use super::Float2x2;
+use super::Float2x3;
+use super::Float4;
+use super::Float4x2;
+use super::Float4x3;
+use super::Float4x4;
+use super::Multiply;
+use super::mul;
+
+/// Matrix containing 2 rows and 4 columns of floating point values.
+#[derive(Copy, Clone, Debug)]
+pub struct Float2x4 {
+    /// The first row of the matrix.
+    pub r0: Float4,
+    /// The second row of the matrix.
+    pub r1: Float4,
+}
+
+impl fmt::Display for Float2x4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Float2x4 (({}, {}, {}, {}), ({}, {}, {}, {}))",
+            self.r0.x, self.r0.y, self.r0.z, self.r0.w, self.r1.x, self.r1.y, self.r1.z, self.r1.w
+        )
+    }
+}
+
+impl ops::Add<Self> for Float2x4 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            r0: self.r0 + rhs.r0,
+            r1: self.r1 + rhs.r1,
+        }
+    }
+}
+
+impl ops::Add<f32> for Float2x4 {
+    type Output = Self;
+
+    fn add(self, rhs: f32) -> Self {
+        Self {
+            r0: self.r0 + rhs,
+            r1: self.r1 + rhs,
+        }
+    }
+}
+
+impl ops::AddAssign<Self> for Float2x4 {
+    fn add_assign(&mut self, rhs: Self) {
+        self.r0 += rhs.r0;
+        self.r1 += rhs.r1;
+    }
+}
+
+impl ops::AddAssign<f32> for Float2x4 {
+    fn add_assign(&mut self, rhs: f32) {
+        self.r0 += rhs;
+        self.r1 += rhs;
+    }
+}
+
+impl ops::Sub<Self> for Float2x4 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            r0: self.r0 - rhs.r0,
+            r1: self.r1 - rhs.r1,
+        }
+    }
+}
+
+impl ops::Sub<f32> for Float2x4 {
+    type Output = Self;
+
+    fn sub(self, rhs: f32) -> Self {
+        Self {
+            r0: self.r0 - rhs,
+            r1: self.r1 - rhs,
+        }
+    }
+}
+
+impl ops::SubAssign<Self> for Float2x4 {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.r0 -= rhs.r0;
+        self.r1 -= rhs.r1;
+    }
+}
+
+impl ops::SubAssign<f32> for Float2x4 {
+    fn sub_assign(&mut self, rhs: f32) {
+        self.r0 -= rhs;
+        self.r1 -= rhs;
+    }
+}
+
+impl ops::Mul<Self> for Float2x4 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            r0: self.r0 * rhs.r0,
+            r1: self.r1 * rhs.r1,
+        }
+    }
+}
+
+impl ops::Mul<f32> for Float2x4 {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Self {
+            r0: self.r0 * rhs,
+            r1: self.r1 * rhs,
+        }
+    }
+}
+
+impl ops::MulAssign<Self> for Float2x4 {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.r0 *= rhs.r0;
+        self.r1 *= rhs.r1;
+    }
+}
+
+impl ops::MulAssign<f32> for Float2x4 {
+    fn mul_assign(&mut self, rhs: f32) {
+        self.r0 *= rhs;
+        self.r1 *= rhs;
+    }
+}
+
+impl ops::Div<Self> for Float2x4 {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Self {
+            r0: self.r0 / rhs.r0,
+            r1: self.r1 / rhs.r1,
+        }
+    }
+}
+
+impl ops::Div<f32> for Float2x4 {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self {
+        Self {
+            r0: self.r0 / rhs,
+            r1: self.r1 / rhs,
+        }
+    }
+}
+
+impl ops::DivAssign<Self> for Float2x4 {
+    fn div_assign(&mut self, rhs: Self) {
+        self.r0 /= rhs.r0;
+        self.r1 /= rhs.r1;
+    }
+}
+
+impl ops::DivAssign<f32> for Float2x4 {
+    fn div_assign(&mut self, rhs: f32) {
+        self.r0 /= rhs;
+        self.r1 /= rhs;
+    }
+}
+
+impl ops::Neg for Float2x4 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            r0: -self.r0,
+            r1: -self.r1,
+        }
+    }
+}
+
+impl PartialEq for Float2x4 {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.r0 == rhs.r0 && self.r1 == rhs.r1
+    }
+}
+
+impl From<f32> for Float2x4 {
+    fn from(lhs: f32) -> Self {
+        Self {
+            r0: Float4::from(lhs),
+            r1: Float4::from(lhs),
+        }
+    }
+}
+
+impl From<(Float4, Float4)> for Float2x4 {
+    fn from(lhs: (Float4, Float4)) -> Self {
+        Self {
+            r0: lhs.0,
+            r1: lhs.1,
+        }
+    }
+}
+
+impl Multiply<Float4> for Float2x4 {
+    type Output = Float2;
+
+    fn mul(self, rhs: Float4) -> Float2 {
+        Float2 {
+            x: self.r0.dot(&rhs),
+            y: self.r1.dot(&rhs),
+        }
+    }
+}
+
+impl Multiply<Float2x4> for Float2 {
+    type Output = Float4;
+
+    fn mul(self, rhs: Float2x4) -> Float4 {
+        rhs.r0 * self.x + rhs.r1 * self.y
+    }
+}
+
+impl Multiply<Float4x2> for Float2x4 {
+    type Output = Float2x2;
+
+    fn mul(self, rhs: Float4x2) -> Float2x2 {
+        Float2x2 {
+            r0: mul(self.r0, rhs),
+            r1: mul(self.r1, rhs),
+        }
+    }
+}
+
+impl Multiply<Float4x3> for Float2x4 {
+    type Output = Float2x3;
+
+    fn mul(self, rhs: Float4x3) -> Float2x3 {
+        Float2x3 {
+            r0: mul(self.r0, rhs),
+            r1: mul(self.r1, rhs),
+        }
+    }
+}
+
+impl Multiply<Float4x4> for Float2x4 {
+    type Output = Float2x4;
+
+    fn mul(self, rhs: Float4x4) -> Float2x4 {
+        Float2x4 {
+            r0: mul(self.r0, rhs),
+            r1: mul(self.r1, rhs),
+        }
+    }
+}
+
+impl Float2x4 {
+    /// Creates a matrix from 2 row vectors.
+    pub fn new(r0: Float4, r1: Float4) -> Self {
+        Self { r0, r1 }
+    }
+
+    /// Returns the first column of the matrix.
+    pub fn c0(&self) -> Float2 {
+        Float2 {
+            x: self.r0.x,
+            y: self.r1.x,
+        }
+    }
+
+    /// Returns the second column of the matrix.
+    pub fn c1(&self) -> Float2 {
+        Float2 {
+            x: self.r0.y,
+            y: self.r1.y,
+        }
+    }
+
+    /// Returns the third column of the matrix.
+    pub fn c2(&self) -> Float2 {
+        Float2 {
+            x: self.r0.z,
+            y: self.r1.z,
+        }
+    }
+
+    /// Returns the fourth column of the matrix.
+    pub fn c3(&self) -> Float2 {
+        Float2 {
+            x: self.r0.w,
+            y: self.r1.w,
+        }
+    }
+
+    /// Computes the transpose of the matrix; swapping rows and columns.
+    pub fn transpose(&self) -> Float4x2 {
+        Float4x2 {
+            r0: self.c0(),
+            r1: self.c1(),
+            r2: self.c2(),
+            r3: self.c3(),
+        }
+    }
+}
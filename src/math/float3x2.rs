@@ -0,0 +1,320 @@
+use core::fmt;
+use core::ops;
+
+use super::Float2;
+use super::Float2x2;
+use super::Float2x3;
+use super::Float2x4;
+use super::Float3;
+use super::Float3x3;
+use super::Float3x4;
+use super::Multiply;
+use super::mul;
+
+/// Matrix containing 3 rows and 2 columns of floating point values.
+#[derive(Copy, Clone, Debug)]
+pub struct Float3x2 {
+    /// The first row of the matrix.
+    pub r0: Float2,
+    /// The second row of the matrix.
+    pub r1: Float2,
+    /// The third row of the matrix.
+    pub r2: Float2,
+}
+
+impl fmt::Display for Float3x2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Float3x2 (({}, {}), ({}, {}), ({}, {}))",
+            self.r0.x, self.r0.y, self.r1.x, self.r1.y, self.r2.x, self.r2.y
+        )
+    }
+}
+
+impl ops::Add<Self> for Float3x2 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            r0: self.r0 + rhs.r0,
+            r1: self.r1 + rhs.r1,
+            r2: self.r2 + rhs.r2,
+        }
+    }
+}
+
+impl ops::Add<f32> for Float3x2 {
+    type Output = Self;
+
+    fn add(self, rhs: f32) -> Self {
+        Self {
+            r0: self.r0 + rhs,
+            r1: self.r1 + rhs,
+            r2: self.r2 + rhs,
+        }
+    }
+}
+
+impl ops::AddAssign<Self> for Float3x2 {
+    fn add_assign(&mut self, rhs: Self) {
+        self.r0 += rhs.r0;
+        self.r1 += rhs.r1;
+        self.r2 += rhs.r2;
+    }
+}
+
+impl ops::AddAssign<f32> for Float3x2 {
+    fn add_assign(&mut self, rhs: f32) {
+        self.r0 += rhs;
+        self.r1 += rhs;
+        self.r2 += rhs;
+    }
+}
+
+impl ops::Sub<Self> for Float3x2 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            r0: self.r0 - rhs.r0,
+            r1: self.r1 - rhs.r1,
+            r2: self.r2 - rhs.r2,
+        }
+    }
+}
+
+impl ops::Sub<f32> for Float3x2 {
+    type Output = Self;
+
+    fn sub(self, rhs: f32) -> Self {
+        Self {
+            r0: self.r0 - rhs,
+            r1: self.r1 - rhs,
+            r2: self.r2 - rhs,
+        }
+    }
+}
+
+impl ops::SubAssign<Self> for Float3x2 {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.r0 -= rhs.r0;
+        self.r1 -= rhs.r1;
+        self.r2 -= rhs.r2;
+    }
+}
+
+impl ops::SubAssign<f32> for Float3x2 {
+    fn sub_assign(&mut self, rhs: f32) {
+        self.r0 -= rhs;
+        self.r1 -= rhs;
+        self.r2 -= rhs;
+    }
+}
+
+impl ops::Mul<Self> for Float3x2 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            r0: self.r0 * rhs.r0,
+            r1: self.r1 * rhs.r1,
+            r2: self.r2 * rhs.r2,
+        }
+    }
+}
+
+impl ops::Mul<f32> for Float3x2 {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Self {
+            r0: self.r0 * rhs,
+            r1: self.r1 * rhs,
+            r2: self.r2 * rhs,
+        }
+    }
+}
+
+impl ops::MulAssign<Self> for Float3x2 {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.r0 *= rhs.r0;
+        self.r1 *= rhs.r1;
+        self.r2 *= rhs.r2;
+    }
+}
+
+impl ops::MulAssign<f32> for Float3x2 {
+    fn mul_assign(&mut self, rhs: f32) {
+        self.r0 *= rhs;
+        self.r1 *= rhs;
+        self.r2 *= rhs;
+    }
+}
+
+impl ops::Div<Self> for Float3x2 {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Self {
+            r0: self.r0 / rhs.r0,
+            r1: self.r1 / rhs.r1,
+            r2: self.r2 / rhs.r2,
+        }
+    }
+}
+
+impl ops::Div<f32> for Float3x2 {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self {
+        Self {
+            r0: self.r0 / rhs,
+            r1: self.r1 / rhs,
+            r2: self.r2 / rhs,
+        }
+    }
+}
+
+impl ops::DivAssign<Self> for Float3x2 {
+    fn div_assign(&mut self, rhs: Self) {
+        self.r0 /= rhs.r0;
+        self.r1 /= rhs.r1;
+        self.r2 /= rhs.r2;
+    }
+}
+
+impl ops::DivAssign<f32> for Float3x2 {
+    fn div_assign(&mut self, rhs: f32) {
+        self.r0 /= rhs;
+        self.r1 /= rhs;
+        self.r2 /= rhs;
+    }
+}
+
+impl ops::Neg for Float3x2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            r0: -self.r0,
+            r1: -self.r1,
+            r2: -self.r2,
+        }
+    }
+}
+
+impl PartialEq for Float3x2 {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.r0 == rhs.r0 && self.r1 == rhs.r1 && self.r2 == rhs.r2
+    }
+}
+
+impl From<f32> for Float3x2 {
+    fn from(lhs: f32) -> Self {
+        Self {
+            r0: Float2::from(lhs),
+            r1: Float2::from(lhs),
+            r2: Float2::from(lhs),
+        }
+    }
+}
+
+impl From<(Float2, Float2, Float2)> for Float3x2 {
+    fn from(lhs: (Float2, Float2, Float2)) -> Self {
+        Self {
+            r0: lhs.0,
+            r1: lhs.1,
+            r2: lhs.2,
+        }
+    }
+}
+
+impl Multiply<Float2> for Float3x2 {
+    type Output = Float3;
+
+    fn mul(self, rhs: Float2) -> Float3 {
+        Float3 {
+            x: self.r0.dot(&rhs),
+            y: self.r1.dot(&rhs),
+            z: self.r2.dot(&rhs),
+        }
+    }
+}
+
+impl Multiply<Float3x2> for Float3 {
+    type Output = Float2;
+
+    fn mul(self, rhs: Float3x2) -> Float2 {
+        rhs.r0 * self.x + rhs.r1 * self.y + rhs.r2 * self.z
+    }
+}
+
+impl Multiply<Float2x2> for Float3x2 {
+    type Output = Float3x2;
+
+    fn mul(self, rhs: Float2x2) -> Float3x2 {
+        Float3x2 {
+            r0: mul(self.r0, rhs),
+            r1: mul(self.r1, rhs),
+            r2: mul(self.r2, rhs),
+        }
+    }
+}
+
+impl Multiply<Float2x3> for Float3x2 {
+    type Output = Float3x3;
+
+    fn mul(self, rhs: Float2x3) -> Float3x3 {
+        Float3x3 {
+            r0: mul(self.r0, rhs),
+            r1: mul(self.r1, rhs),
+            r2: mul(self.r2, rhs),
+        }
+    }
+}
+
+impl Multiply<Float2x4> for Float3x2 {
+    type Output = Float3x4;
+
+    fn mul(self, rhs: Float2x4) -> Float3x4 {
+        Float3x4 {
+            r0: mul(self.r0, rhs),
+            r1: mul(self.r1, rhs),
+            r2: mul(self.r2, rhs),
+        }
+    }
+}
+
+impl Float3x2 {
+    /// Creates a matrix from 3 row vectors.
+    pub fn new(r0: Float2, r1: Float2, r2: Float2) -> Self {
+        Self { r0, r1, r2 }
+    }
+
+    /// Returns the first column of the matrix.
+    pub fn c0(&self) -> Float3 {
+        Float3 {
+            x: self.r0.x,
+            y: self.r1.x,
+            z: self.r2.x,
+        }
+    }
+
+    /// Returns the second column of the matrix.
+    pub fn c1(&self) -> Float3 {
+        Float3 {
+            x: self.r0.y,
+            y: self.r1.y,
+            z: self.r2.y,
+        }
+    }
+
+    /// Computes the transpose of the matrix; swapping rows and columns.
+    pub fn transpose(&self) -> Float2x3 {
+        Float2x3 {
+            r0: self.c0(),
+            r1: self.c1(),
+        }
+    }
+}
@@ -0,0 +1,618 @@
+use core::fmt;
+use core::ops;
+
+use super::DecomposeError;
+use super::EulerOrder;
+use super::Float3;
+use super::Float3x3;
+use super::Float4;
+use super::Float4x2;
+use super::Float4x3;
+use super::Multiply;
+use super::Quaternion;
+use super::decompose::decompose;
+use super::decompose::is_affine;
+use super::mul;
+
+/// Matrix containing 4 rows and 4 columns of floating point values.
+#[derive(Copy, Clone, Debug)]
+pub struct Float4x4 {
+    /// The first row of the matrix.
+    pub r0: Float4,
+    /// The second row of the matrix.
+    pub r1: Float4,
+    /// The third row of the matrix.
+    pub r2: Float4,
+    /// The fourth row of the matrix.
+    pub r3: Float4,
+}
+
+impl fmt::Display for Float4x4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Float4x4 (({}, {}, {}, {}), ({}, {}, {}, {}), ({}, {}, {}, {}), ({}, {}, {}, {}))",
+            self.r0.x,
+            self.r0.y,
+            self.r0.z,
+            self.r0.w,
+            self.r1.x,
+            self.r1.y,
+            self.r1.z,
+            self.r1.w,
+            self.r2.x,
+            self.r2.y,
+            self.r2.z,
+            self.r2.w,
+            self.r3.x,
+            self.r3.y,
+            self.r3.z,
+            self.r3.w
+        )
+    }
+}
+
+impl ops::Add<Self> for Float4x4 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            r0: self.r0 + rhs.r0,
+            r1: self.r1 + rhs.r1,
+            r2: self.r2 + rhs.r2,
+            r3: self.r3 + rhs.r3,
+        }
+    }
+}
+
+impl ops::Add<f32> for Float4x4 {
+    type Output = Self;
+
+    fn add(self, rhs: f32) -> Self {
+        Self {
+            r0: self.r0 + rhs,
+            r1: self.r1 + rhs,
+            r2: self.r2 + rhs,
+            r3: self.r3 + rhs,
+        }
+    }
+}
+
+impl ops::AddAssign<Self> for Float4x4 {
+    fn add_assign(&mut self, rhs: Self) {
+        self.r0 += rhs.r0;
+        self.r1 += rhs.r1;
+        self.r2 += rhs.r2;
+        self.r3 += rhs.r3;
+    }
+}
+
+impl ops::AddAssign<f32> for Float4x4 {
+    fn add_assign(&mut self, rhs: f32) {
+        self.r0 += rhs;
+        self.r1 += rhs;
+        self.r2 += rhs;
+        self.r3 += rhs;
+    }
+}
+
+impl ops::Sub<Self> for Float4x4 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            r0: self.r0 - rhs.r0,
+            r1: self.r1 - rhs.r1,
+            r2: self.r2 - rhs.r2,
+            r3: self.r3 - rhs.r3,
+        }
+    }
+}
+
+impl ops::Sub<f32> for Float4x4 {
+    type Output = Self;
+
+    fn sub(self, rhs: f32) -> Self {
+        Self {
+            r0: self.r0 - rhs,
+            r1: self.r1 - rhs,
+            r2: self.r2 - rhs,
+            r3: self.r3 - rhs,
+        }
+    }
+}
+
+impl ops::SubAssign<Self> for Float4x4 {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.r0 -= rhs.r0;
+        self.r1 -= rhs.r1;
+        self.r2 -= rhs.r2;
+        self.r3 -= rhs.r3;
+    }
+}
+
+impl ops::SubAssign<f32> for Float4x4 {
+    fn sub_assign(&mut self, rhs: f32) {
+        self.r0 -= rhs;
+        self.r1 -= rhs;
+        self.r2 -= rhs;
+        self.r3 -= rhs;
+    }
+}
+
+impl ops::Mul<Self> for Float4x4 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            r0: self.r0 * rhs.r0,
+            r1: self.r1 * rhs.r1,
+            r2: self.r2 * rhs.r2,
+            r3: self.r3 * rhs.r3,
+        }
+    }
+}
+
+impl ops::Mul<f32> for Float4x4 {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Self {
+            r0: self.r0 * rhs,
+            r1: self.r1 * rhs,
+            r2: self.r2 * rhs,
+            r3: self.r3 * rhs,
+        }
+    }
+}
+
+impl ops::MulAssign<Self> for Float4x4 {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.r0 *= rhs.r0;
+        self.r1 *= rhs.r1;
+        self.r2 *= rhs.r2;
+        self.r3 *= rhs.r3;
+    }
+}
+
+impl ops::MulAssign<f32> for Float4x4 {
+    fn mul_assign(&mut self, rhs: f32) {
+        self.r0 *= rhs;
+        self.r1 *= rhs;
+        self.r2 *= rhs;
+        self.r3 *= rhs;
+    }
+}
+
+impl ops::Div<Self> for Float4x4 {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Self {
+            r0: self.r0 / rhs.r0,
+            r1: self.r1 / rhs.r1,
+            r2: self.r2 / rhs.r2,
+            r3: self.r3 / rhs.r3,
+        }
+    }
+}
+
+impl ops::Div<f32> for Float4x4 {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self {
+        Self {
+            r0: self.r0 / rhs,
+            r1: self.r1 / rhs,
+            r2: self.r2 / rhs,
+            r3: self.r3 / rhs,
+        }
+    }
+}
+
+impl ops::DivAssign<Self> for Float4x4 {
+    fn div_assign(&mut self, rhs: Self) {
+        self.r0 /= rhs.r0;
+        self.r1 /= rhs.r1;
+        self.r2 /= rhs.r2;
+        self.r3 /= rhs.r3;
+    }
+}
+
+impl ops::DivAssign<f32> for Float4x4 {
+    fn div_assign(&mut self, rhs: f32) {
+        self.r0 /= rhs;
+        self.r1 /= rhs;
+        self.r2 /= rhs;
+        self.r3 /= rhs;
+    }
+}
+
+impl ops::Neg for Float4x4 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            r0: -self.r0,
+            r1: -self.r1,
+            r2: -self.r2,
+            r3: -self.r3,
+        }
+    }
+}
+
+impl PartialEq for Float4x4 {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.r0 == rhs.r0 && self.r1 == rhs.r1 && self.r2 == rhs.r2 && self.r3 == rhs.r3
+    }
+}
+
+impl From<f32> for Float4x4 {
+    fn from(lhs: f32) -> Self {
+        Self {
+            r0: Float4::from(lhs),
+            r1: Float4::from(lhs),
+            r2: Float4::from(lhs),
+            r3: Float4::from(lhs),
+        }
+    }
+}
+
+impl From<(Float4, Float4, Float4, Float4)> for Float4x4 {
+    fn from(lhs: (Float4, Float4, Float4, Float4)) -> Self {
+        Self {
+            r0: lhs.0,
+            r1: lhs.1,
+            r2: lhs.2,
+            r3: lhs.3,
+        }
+    }
+}
+
+impl Multiply<Float4> for Float4x4 {
+    type Output = Float4;
+
+    fn mul(self, rhs: Float4) -> Float4 {
+        Float4 {
+            x: self.r0.dot(&rhs),
+            y: self.r1.dot(&rhs),
+            z: self.r2.dot(&rhs),
+            w: self.r3.dot(&rhs),
+        }
+    }
+}
+
+impl Multiply<Float4x4> for Float4 {
+    type Output = Float4;
+
+    fn mul(self, rhs: Float4x4) -> Float4 {
+        rhs.r0 * self.x + rhs.r1 * self.y + rhs.r2 * self.z + rhs.r3 * self.w
+    }
+}
+
+impl Multiply<Float4x2> for Float4x4 {
+    type Output = Float4x2;
+
+    fn mul(self, rhs: Float4x2) -> Float4x2 {
+        Float4x2 {
+            r0: mul(self.r0, rhs),
+            r1: mul(self.r1, rhs),
+            r2: mul(self.r2, rhs),
+            r3: mul(self.r3, rhs),
+        }
+    }
+}
+
+impl Multiply<Float4x3> for Float4x4 {
+    type Output = Float4x3;
+
+    fn mul(self, rhs: Float4x3) -> Float4x3 {
+        Float4x3 {
+            r0: mul(self.r0, rhs),
+            r1: mul(self.r1, rhs),
+            r2: mul(self.r2, rhs),
+            r3: mul(self.r3, rhs),
+        }
+    }
+}
+
+impl Multiply<Float4x4> for Float4x4 {
+    type Output = Float4x4;
+
+    fn mul(self, rhs: Float4x4) -> Float4x4 {
+        Float4x4 {
+            r0: mul(self.r0, rhs),
+            r1: mul(self.r1, rhs),
+            r2: mul(self.r2, rhs),
+            r3: mul(self.r3, rhs),
+        }
+    }
+}
+
+impl Float4x4 {
+    /// Creates a matrix from 4 row vectors.
+    pub fn new(r0: Float4, r1: Float4, r2: Float4, r3: Float4) -> Self {
+        Self { r0, r1, r2, r3 }
+    }
+
+    /// Creates an identity matrix.
+    pub fn identity() -> Self {
+        Self {
+            r0: Float4::new(1.0, 0.0, 0.0, 0.0),
+            r1: Float4::new(0.0, 1.0, 0.0, 0.0),
+            r2: Float4::new(0.0, 0.0, 1.0, 0.0),
+            r3: Float4::new(0.0, 0.0, 0.0, 1.0),
+        }
+    }
+
+    /// Returns the first column of the matrix.
+    pub fn c0(&self) -> Float4 {
+        Float4 {
+            x: self.r0.x,
+            y: self.r1.x,
+            z: self.r2.x,
+            w: self.r3.x,
+        }
+    }
+
+    /// Returns the second column of the matrix.
+    pub fn c1(&self) -> Float4 {
+        Float4 {
+            x: self.r0.y,
+            y: self.r1.y,
+            z: self.r2.y,
+            w: self.r3.y,
+        }
+    }
+
+    /// Returns the third column of the matrix.
+    pub fn c2(&self) -> Float4 {
+        Float4 {
+            x: self.r0.z,
+            y: self.r1.z,
+            z: self.r2.z,
+            w: self.r3.z,
+        }
+    }
+
+    /// Returns the fourth column of the matrix.
+    pub fn c3(&self) -> Float4 {
+        Float4 {
+            x: self.r0.w,
+            y: self.r1.w,
+            z: self.r2.w,
+            w: self.r3.w,
+        }
+    }
+
+    /// Decomposes an affine transform into its translation, rotation and scale, such that
+    /// `mul(mul(Float4x4::scale(&s), r.to_float3x3()), Float4x4::translation(&t))` rebuilds the matrix.
+    /// A reflection is returned as a negative x scale.
+    pub fn decompose(&self) -> Result<(Float3, Quaternion, Float3), DecomposeError> {
+        if !is_affine(Float3::new(self.r0.w, self.r1.w, self.r2.w), self.r3.w) {
+            return Err(DecomposeError::NotAffine);
+        }
+        let rows = Float3x3::new(self.r0.xyz(), self.r1.xyz(), self.r2.xyz());
+        decompose(rows, self.r3.xyz())
+    }
+
+    /// Computes the determinant of the matrix.
+    pub fn determinant(&self) -> f32 {
+        let (a, b, c, d) = (self.r0, self.r1, self.r2, self.r3);
+        let s0 = a.x * b.y - a.y * b.x;
+        let s1 = a.x * b.z - a.z * b.x;
+        let s2 = a.x * b.w - a.w * b.x;
+        let s3 = a.y * b.z - a.z * b.y;
+        let s4 = a.y * b.w - a.w * b.y;
+        let s5 = a.z * b.w - a.w * b.z;
+        let c5 = c.z * d.w - c.w * d.z;
+        let c4 = c.y * d.w - c.w * d.y;
+        let c3 = c.y * d.z - c.z * d.y;
+        let c2 = c.x * d.w - c.w * d.x;
+        let c1 = c.x * d.z - c.z * d.x;
+        let c0 = c.x * d.y - c.y * d.x;
+        s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0
+    }
+
+    /// Computes the inverse of the matrix.
+    /// The result contains infinite or NaN values when the matrix is singular (the determinant is 0).
+    pub fn inverse(&self) -> Self {
+        let (a, b, c, d) = (self.r0, self.r1, self.r2, self.r3);
+        let s0 = a.x * b.y - a.y * b.x;
+        let s1 = a.x * b.z - a.z * b.x;
+        let s2 = a.x * b.w - a.w * b.x;
+        let s3 = a.y * b.z - a.z * b.y;
+        let s4 = a.y * b.w - a.w * b.y;
+        let s5 = a.z * b.w - a.w * b.z;
+        let c5 = c.z * d.w - c.w * d.z;
+        let c4 = c.y * d.w - c.w * d.y;
+        let c3 = c.y * d.z - c.z * d.y;
+        let c2 = c.x * d.w - c.w * d.x;
+        let c1 = c.x * d.z - c.z * d.x;
+        let c0 = c.x * d.y - c.y * d.x;
+        let inv_det = 1.0 / (s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0);
+        Self {
+            r0: Float4 {
+                x: (b.y * c5 - b.z * c4 + b.w * c3) * inv_det,
+                y: (-a.y * c5 + a.z * c4 - a.w * c3) * inv_det,
+                z: (d.y * s5 - d.z * s4 + d.w * s3) * inv_det,
+                w: (-c.y * s5 + c.z * s4 - c.w * s3) * inv_det,
+            },
+            r1: Float4 {
+                x: (-b.x * c5 + b.z * c2 - b.w * c1) * inv_det,
+                y: (a.x * c5 - a.z * c2 + a.w * c1) * inv_det,
+                z: (-d.x * s5 + d.z * s2 - d.w * s1) * inv_det,
+                w: (c.x * s5 - c.z * s2 + c.w * s1) * inv_det,
+            },
+            r2: Float4 {
+                x: (b.x * c4 - b.y * c2 + b.w * c0) * inv_det,
+                y: (-a.x * c4 + a.y * c2 - a.w * c0) * inv_det,
+                z: (d.x * s4 - d.y * s2 + d.w * s0) * inv_det,
+                w: (-c.x * s4 + c.y * s2 - c.w * s0) * inv_det,
+            },
+            r3: Float4 {
+                x: (-b.x * c3 + b.y * c1 - b.z * c0) * inv_det,
+                y: (a.x * c3 - a.y * c1 + a.z * c0) * inv_det,
+                z: (-d.x * s3 + d.y * s1 - d.z * s0) * inv_det,
+                w: (c.x * s3 - c.y * s1 + c.z * s0) * inv_det,
+            },
+        }
+    }
+
+    /// Creates a left-handed view matrix for a camera at `eye` looking at `target`.
+    pub fn look_at_lh(eye: &Float3, target: &Float3, up: &Float3) -> Self {
+        Self::look_to(eye, &(*target - *eye), up)
+    }
+
+    /// Creates a right-handed view matrix for a camera at `eye` looking at `target`.
+    pub fn look_at_rh(eye: &Float3, target: &Float3, up: &Float3) -> Self {
+        Self::look_to(eye, &(*eye - *target), up)
+    }
+
+    /// Creates a left-handed orthographic projection matrix mapping depth from `near` and `far` to 0 and 1.
+    pub fn orthographic_lh(width: f32, height: f32, near: f32, far: f32) -> Self {
+        let range = 1.0 / (far - near);
+        Self::projection(2.0 / width, 2.0 / height, range, -range * near, 0.0, 1.0)
+    }
+
+    /// Creates a right-handed orthographic projection matrix mapping depth from `near` and `far` to 0 and 1.
+    pub fn orthographic_rh(width: f32, height: f32, near: f32, far: f32) -> Self {
+        let range = 1.0 / (near - far);
+        Self::projection(2.0 / width, 2.0 / height, range, range * near, 0.0, 1.0)
+    }
+
+    /// Creates a left-handed perspective projection matrix without a far plane, mapping depth from `near` and infinity to 0 and 1.
+    pub fn perspective_fov_infinite_lh(fov_y: f32, aspect: f32, near: f32) -> Self {
+        let h = 1.0 / (fov_y * 0.5).tan();
+        Self::projection(h / aspect, h, 1.0, -near, 1.0, 0.0)
+    }
+
+    /// Creates a right-handed perspective projection matrix without a far plane, mapping depth from `near` and infinity to 0 and 1.
+    pub fn perspective_fov_infinite_rh(fov_y: f32, aspect: f32, near: f32) -> Self {
+        let h = 1.0 / (fov_y * 0.5).tan();
+        Self::projection(h / aspect, h, -1.0, -near, -1.0, 0.0)
+    }
+
+    /// Creates a left-handed perspective projection matrix without a far plane, mapping depth from `near` and infinity to 1 and 0 (reversed-Z).
+    pub fn perspective_fov_infinite_reversed_lh(fov_y: f32, aspect: f32, near: f32) -> Self {
+        let h = 1.0 / (fov_y * 0.5).tan();
+        Self::projection(h / aspect, h, 0.0, near, 1.0, 0.0)
+    }
+
+    /// Creates a right-handed perspective projection matrix without a far plane, mapping depth from `near` and infinity to 1 and 0 (reversed-Z).
+    pub fn perspective_fov_infinite_reversed_rh(fov_y: f32, aspect: f32, near: f32) -> Self {
+        let h = 1.0 / (fov_y * 0.5).tan();
+        Self::projection(h / aspect, h, 0.0, near, -1.0, 0.0)
+    }
+
+    /// Creates a left-handed perspective projection matrix mapping depth from `near` and `far` to 0 and 1.
+    /// The vertical field of view `fov_y` is in radians and `aspect` is the width divided by the height.
+    pub fn perspective_fov_lh(fov_y: f32, aspect: f32, near: f32, far: f32) -> Self {
+        let h = 1.0 / (fov_y * 0.5).tan();
+        let range = far / (far - near);
+        Self::projection(h / aspect, h, range, -range * near, 1.0, 0.0)
+    }
+
+    /// Creates a right-handed perspective projection matrix mapping depth from `near` and `far` to 0 and 1.
+    /// The vertical field of view `fov_y` is in radians and `aspect` is the width divided by the height.
+    pub fn perspective_fov_rh(fov_y: f32, aspect: f32, near: f32, far: f32) -> Self {
+        let h = 1.0 / (fov_y * 0.5).tan();
+        let range = far / (near - far);
+        Self::projection(h / aspect, h, range, range * near, -1.0, 0.0)
+    }
+
+    /// Creates a left-handed perspective projection matrix mapping depth from `near` and `far` to 1 and 0 (reversed-Z).
+    pub fn perspective_fov_reversed_lh(fov_y: f32, aspect: f32, near: f32, far: f32) -> Self {
+        Self::perspective_fov_lh(fov_y, aspect, far, near)
+    }
+
+    /// Creates a right-handed perspective projection matrix mapping depth from `near` and `far` to 1 and 0 (reversed-Z).
+    pub fn perspective_fov_reversed_rh(fov_y: f32, aspect: f32, near: f32, far: f32) -> Self {
+        Self::perspective_fov_rh(fov_y, aspect, far, near)
+    }
+
+    /// Creates a rotation matrix of `angle` radians around `axis`.
+    pub fn rotation_axis(axis: &Float3, angle: f32) -> Self {
+        Self::rotation(&Quaternion::from_axis_angle(axis, angle).to_float3x3())
+    }
+
+    /// Creates a rotation matrix from the angles (in radians) around the x, y and z axes, applied in the given order.
+    pub fn rotation_euler(angles: &Float3, order: EulerOrder) -> Self {
+        Self::rotation(&Quaternion::from_euler(angles, order).to_float3x3())
+    }
+
+    /// Creates a rotation matrix of `angle` radians around the x axis.
+    pub fn rotation_x(angle: f32) -> Self {
+        Self::rotation_axis(&Float3::new(1.0, 0.0, 0.0), angle)
+    }
+
+    /// Creates a rotation matrix of `angle` radians around the y axis.
+    pub fn rotation_y(angle: f32) -> Self {
+        Self::rotation_axis(&Float3::new(0.0, 1.0, 0.0), angle)
+    }
+
+    /// Creates a rotation matrix of `angle` radians around the z axis.
+    pub fn rotation_z(angle: f32) -> Self {
+        Self::rotation_axis(&Float3::new(0.0, 0.0, 1.0), angle)
+    }
+
+    /// Creates a matrix scaling each axis by the matching component of `scale`.
+    pub fn scale(scale: &Float3) -> Self {
+        Self {
+            r0: Float4::new(scale.x, 0.0, 0.0, 0.0),
+            r1: Float4::new(0.0, scale.y, 0.0, 0.0),
+            r2: Float4::new(0.0, 0.0, scale.z, 0.0),
+            r3: Float4::new(0.0, 0.0, 0.0, 1.0),
+        }
+    }
+
+    /// Creates a matrix translating points by `offset`.
+    pub fn translation(offset: &Float3) -> Self {
+        Self {
+            r0: Float4::new(1.0, 0.0, 0.0, 0.0),
+            r1: Float4::new(0.0, 1.0, 0.0, 0.0),
+            r2: Float4::new(0.0, 0.0, 1.0, 0.0),
+            r3: Float4::from((*offset, 1.0)),
+        }
+    }
+
+    /// Computes the transpose of the matrix; swapping rows and columns.
+    pub fn transpose(&self) -> Float4x4 {
+        Float4x4 {
+            r0: self.c0(),
+            r1: self.c1(),
+            r2: self.c2(),
+            r3: self.c3(),
+        }
+    }
+
+    fn look_to(eye: &Float3, direction: &Float3, up: &Float3) -> Self {
+        let z = direction.normalize();
+        let x = up.cross(&z).normalize();
+        let y = z.cross(&x);
+        Self {
+            r0: Float4::new(x.x, y.x, z.x, 0.0),
+            r1: Float4::new(x.y, y.y, z.y, 0.0),
+            r2: Float4::new(x.z, y.z, z.z, 0.0),
+            r3: Float4::new(-x.dot(eye), -y.dot(eye), -z.dot(eye), 1.0),
+        }
+    }
+
+    fn projection(x: f32, y: f32, z: f32, z_offset: f32, w: f32, w_offset: f32) -> Self {
+        Self {
+            r0: Float4::new(x, 0.0, 0.0, 0.0),
+            r1: Float4::new(0.0, y, 0.0, 0.0),
+            r2: Float4::new(0.0, 0.0, z, w),
+            r3: Float4::new(0.0, 0.0, z_offset, w_offset),
+        }
+    }
+
+    fn rotation(m: &Float3x3) -> Self {
+        Self {
+            r0: Float4::from((m.r0, 0.0)),
+            r1: Float4::from((m.r1, 0.0)),
+            r2: Float4::from((m.r2, 0.0)),
+            r3: Float4::new(0.0, 0.0, 0.0, 1.0),
+        }
+    }
+}
@@ -0,0 +1,515 @@
+// Geometric primitives for CPU-side picking and culling next to ported shader code.
+// Planes follow the Direct3D convention `dot(normal, p) + distance = 0` and rays are
+// parameterized as `origin + direction * t`, reporting hits as the smallest `t >= 0`.
+
+use super::Float2;
+use super::Float3;
+use super::Float4;
+use super::Float4x4;
+use super::Quaternion;
+
+/// Ray starting at an origin and extending along a direction.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ray {
+    /// The point where the ray starts.
+    pub origin: Float3,
+    /// The direction of the ray; distances along the ray are measured in multiples of its length.
+    pub direction: Float3,
+}
+
+/// Plane containing the points `p` for which `dot(normal, p) + distance` is 0.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Plane {
+    /// The normal of the plane, pointing towards the positive half-space.
+    pub normal: Float3,
+    /// The signed distance of the origin to the plane, when the normal has unit length.
+    pub distance: f32,
+}
+
+/// Sphere described by a center and a radius.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Sphere {
+    /// The center of the sphere.
+    pub center: Float3,
+    /// The radius of the sphere.
+    pub radius: f32,
+}
+
+/// Axis-aligned bounding box described by its minimum and maximum corners.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Aabb {
+    /// The corner with the smallest coordinates.
+    pub min: Float3,
+    /// The corner with the largest coordinates.
+    pub max: Float3,
+}
+
+/// Oriented bounding box described by a center, half extents and a rotation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Obb {
+    /// The center of the box.
+    pub center: Float3,
+    /// The half size of the box along each of its local axes.
+    pub extents: Float3,
+    /// The rotation from the local axes of the box to world space.
+    pub rotation: Quaternion,
+}
+
+/// Triangle described by three vertices.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Triangle {
+    /// The first vertex.
+    pub a: Float3,
+    /// The second vertex.
+    pub b: Float3,
+    /// The third vertex.
+    pub c: Float3,
+}
+
+/// Frustum described by six planes whose normals point inwards.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Frustum {
+    /// The left, right, bottom, top, near and far planes.
+    pub planes: [Plane; 6],
+}
+
+impl From<Float4> for Plane {
+    fn from(value: Float4) -> Self {
+        Self {
+            normal: value.xyz(),
+            distance: value.w,
+        }
+    }
+}
+
+impl From<Plane> for Float4 {
+    fn from(value: Plane) -> Self {
+        Float4::from((value.normal, value.distance))
+    }
+}
+
+impl Ray {
+    /// Creates a ray from an origin and a direction.
+    pub fn new(origin: Float3, direction: Float3) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Returns the point at distance `t` along the ray.
+    pub fn at(&self, t: f32) -> Float3 {
+        self.origin + self.direction * t
+    }
+
+    /// Computes the distance between a point and the closest point on the ray.
+    pub fn distance(&self, point: &Float3) -> f32 {
+        let t = (*point - self.origin).dot(&self.direction) / self.direction.dot(&self.direction);
+        self.at(t.max(0.0)).distance(point)
+    }
+
+    /// Intersects the ray with a box using the slab test; returns 0 when the ray starts inside the box.
+    pub fn intersect_aabb(&self, aabb: &Aabb) -> Option<f32> {
+        self.intersect_aabb_interval(aabb).map(|(enter, _)| enter)
+    }
+
+    /// Intersects the ray with a box using the slab test and returns the distances where the ray enters and exits it.
+    /// The entry distance is 0 when the ray starts inside the box.
+    pub fn intersect_aabb_interval(&self, aabb: &Aabb) -> Option<(f32, f32)> {
+        let (x0, x1) = slab(self.origin.x, self.direction.x, aabb.min.x, aabb.max.x)?;
+        let (y0, y1) = slab(self.origin.y, self.direction.y, aabb.min.y, aabb.max.y)?;
+        let (z0, z1) = slab(self.origin.z, self.direction.z, aabb.min.z, aabb.max.z)?;
+        let enter = x0.max(y0).max(z0).max(0.0);
+        let exit = x1.min(y1).min(z1);
+        (enter <= exit).then_some((enter, exit))
+    }
+
+    /// Intersects the ray with an oriented box; returns 0 when the ray starts inside the box.
+    pub fn intersect_obb(&self, obb: &Obb) -> Option<f32> {
+        let inverse = obb.rotation.conjugate();
+        let local = Ray {
+            origin: inverse.rotate(&(self.origin - obb.center)),
+            direction: inverse.rotate(&self.direction),
+        };
+        local.intersect_aabb(&Aabb::new(-obb.extents, obb.extents))
+    }
+
+    /// Intersects the ray with a plane; returns `None` when the ray is parallel to or points away from the plane.
+    pub fn intersect_plane(&self, plane: &Plane) -> Option<f32> {
+        let denominator = plane.normal.dot(&self.direction);
+        let t = -plane.signed_distance(&self.origin) / denominator;
+        (t >= 0.0 && t.is_finite()).then_some(t)
+    }
+
+    /// Intersects the ray with a sphere; returns 0 when the ray starts inside the sphere.
+    pub fn intersect_sphere(&self, sphere: &Sphere) -> Option<f32> {
+        let offset = self.origin - sphere.center;
+        let c = offset.dot(&offset) - sphere.radius * sphere.radius;
+        if c <= 0.0 {
+            return Some(0.0);
+        }
+        let a = self.direction.dot(&self.direction);
+        let b = offset.dot(&self.direction);
+        let discriminant = b * b - a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let t = (-b - discriminant.sqrt()) / a;
+        (t >= 0.0).then_some(t)
+    }
+
+    /// Intersects the ray with both sides of a triangle using the Möller–Trumbore algorithm.
+    /// Returns the distance and the barycentrics `(u, v)` of the hit, which lies at `a * (1 - u - v) + b * u + c * v`.
+    pub fn intersect_triangle(&self, triangle: &Triangle) -> Option<(f32, Float2)> {
+        let e1 = triangle.b - triangle.a;
+        let e2 = triangle.c - triangle.a;
+        let p = self.direction.cross(&e2);
+        let determinant = e1.dot(&p);
+        if determinant == 0.0 {
+            return None;
+        }
+        let inverse = 1.0 / determinant;
+        let s = self.origin - triangle.a;
+        let u = s.dot(&p) * inverse;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+        let q = s.cross(&e1);
+        let v = self.direction.dot(&q) * inverse;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+        let t = e2.dot(&q) * inverse;
+        (t >= 0.0).then_some((t, Float2::new(u, v)))
+    }
+}
+
+impl Plane {
+    /// Creates a plane from a normal and the signed distance of the origin to the plane.
+    pub fn new(normal: Float3, distance: f32) -> Self {
+        Self { normal, distance }
+    }
+
+    /// Creates a plane through a point with the given normal.
+    pub fn from_point_normal(point: &Float3, normal: &Float3) -> Self {
+        let normal = normal.normalize();
+        Self {
+            normal,
+            distance: -normal.dot(point),
+        }
+    }
+
+    /// Creates a plane through three points; the normal faces the side from which they appear counterclockwise in a right-handed coordinate system.
+    pub fn from_points(a: &Float3, b: &Float3, c: &Float3) -> Self {
+        Self::from_point_normal(a, &(*b - *a).cross(&(*c - *a)))
+    }
+
+    /// Returns the point on the plane closest to `point`.
+    pub fn closest_point(&self, point: &Float3) -> Float3 {
+        *point - self.normal * (self.signed_distance(point) / self.normal.dot(&self.normal))
+    }
+
+    /// Scales the plane equation such that the normal has unit length.
+    pub fn normalize(&self) -> Self {
+        let length = self.normal.length();
+        Self {
+            normal: self.normal / length,
+            distance: self.distance / length,
+        }
+    }
+
+    /// Computes the signed distance of a point to the plane; positive on the side the normal points to.
+    /// The result is scaled by the length of the normal when it is not normalized.
+    pub fn signed_distance(&self, point: &Float3) -> f32 {
+        self.normal.dot(point) + self.distance
+    }
+}
+
+impl Sphere {
+    /// Creates a sphere from a center and a radius.
+    pub fn new(center: Float3, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    /// Returns the point in the sphere closest to `point`.
+    pub fn closest_point(&self, point: &Float3) -> Float3 {
+        let offset = *point - self.center;
+        let length = offset.length();
+        if length <= self.radius {
+            *point
+        } else {
+            self.center + offset * (self.radius / length)
+        }
+    }
+
+    /// Returns whether the point lies inside or on the sphere.
+    pub fn contains(&self, point: &Float3) -> bool {
+        let offset = *point - self.center;
+        offset.dot(&offset) <= self.radius * self.radius
+    }
+
+    /// Computes the distance of a point to the sphere, which is 0 inside the sphere.
+    pub fn distance(&self, point: &Float3) -> f32 {
+        (point.distance(&self.center) - self.radius).max(0.0)
+    }
+
+    /// Returns whether the sphere overlaps a box.
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        self.contains(&aabb.closest_point(&self.center))
+    }
+
+    /// Returns whether two spheres overlap.
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        let offset = sphere.center - self.center;
+        let radius = self.radius + sphere.radius;
+        offset.dot(&offset) <= radius * radius
+    }
+}
+
+impl Aabb {
+    /// Creates a box from its minimum and maximum corners.
+    pub fn new(min: Float3, max: Float3) -> Self {
+        Self { min, max }
+    }
+
+    /// Creates an empty box that grows to exactly the first point or box it is combined with.
+    pub fn empty() -> Self {
+        Self {
+            min: Float3::from(f32::INFINITY),
+            max: Float3::from(f32::NEG_INFINITY),
+        }
+    }
+
+    /// Creates the smallest box containing all points; an empty box when there are none.
+    pub fn from_points(points: &[Float3]) -> Self {
+        points
+            .iter()
+            .fold(Self::empty(), |aabb, point| aabb.grow(point))
+    }
+
+    /// Returns the center of the box.
+    pub fn center(&self) -> Float3 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Returns the point in the box closest to `point`.
+    pub fn closest_point(&self, point: &Float3) -> Float3 {
+        point.max(&self.min).min(&self.max)
+    }
+
+    /// Returns whether the point lies inside or on the box.
+    pub fn contains(&self, point: &Float3) -> bool {
+        (point.ge(&self.min) & point.le(&self.max)).all()
+    }
+
+    /// Computes the distance of a point to the box, which is 0 inside the box.
+    pub fn distance(&self, point: &Float3) -> f32 {
+        self.closest_point(point).distance(point)
+    }
+
+    /// Returns the half size of the box along each axis.
+    pub fn extents(&self) -> Float3 {
+        (self.max - self.min) * 0.5
+    }
+
+    /// Returns the smallest box containing both the box and a point.
+    pub fn grow(&self, point: &Float3) -> Self {
+        Self {
+            min: self.min.min(point),
+            max: self.max.max(point),
+        }
+    }
+
+    /// Returns whether two boxes overlap.
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        (self.min.le(&aabb.max) & aabb.min.le(&self.max)).all()
+    }
+
+    /// Returns whether the box contains no points, which is the case for `Aabb::empty`.
+    pub fn is_empty(&self) -> bool {
+        self.min.gt(&self.max).any()
+    }
+
+    /// Computes the surface area of the box; 0 for an empty box.
+    pub fn surface_area(&self) -> f32 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        let size = self.max - self.min;
+        2.0 * (size.x * size.y + size.y * size.z + size.z * size.x)
+    }
+
+    /// Returns the smallest box containing both boxes.
+    pub fn union(&self, aabb: &Aabb) -> Self {
+        Self {
+            min: self.min.min(&aabb.min),
+            max: self.max.max(&aabb.max),
+        }
+    }
+}
+
+impl Obb {
+    /// Creates an oriented box from a center, half extents and a rotation.
+    pub fn new(center: Float3, extents: Float3, rotation: Quaternion) -> Self {
+        Self {
+            center,
+            extents,
+            rotation,
+        }
+    }
+
+    /// Returns the point in the box closest to `point`.
+    pub fn closest_point(&self, point: &Float3) -> Float3 {
+        let local = self.rotation.conjugate().rotate(&(*point - self.center));
+        let clamped = local.max(&-self.extents).min(&self.extents);
+        self.center + self.rotation.rotate(&clamped)
+    }
+
+    /// Returns whether the point lies inside or on the box.
+    pub fn contains(&self, point: &Float3) -> bool {
+        let local = self.rotation.conjugate().rotate(&(*point - self.center));
+        local.abs().le(&self.extents).all()
+    }
+
+    /// Computes the distance of a point to the box, which is 0 inside the box.
+    pub fn distance(&self, point: &Float3) -> f32 {
+        self.closest_point(point).distance(point)
+    }
+}
+
+impl Triangle {
+    /// Creates a triangle from three vertices.
+    pub fn new(a: Float3, b: Float3, c: Float3) -> Self {
+        Self { a, b, c }
+    }
+
+    /// Returns the smallest box containing the triangle.
+    pub fn aabb(&self) -> Aabb {
+        Aabb::new(
+            self.a.min(&self.b).min(&self.c),
+            self.a.max(&self.b).max(&self.c),
+        )
+    }
+
+    /// Returns the point on the triangle closest to `point`.
+    pub fn closest_point(&self, point: &Float3) -> Float3 {
+        let (a, b, c) = (self.a, self.b, self.c);
+        let ab = b - a;
+        let ac = c - a;
+        let ap = *point - a;
+        let d1 = ab.dot(&ap);
+        let d2 = ac.dot(&ap);
+        if d1 <= 0.0 && d2 <= 0.0 {
+            return a;
+        }
+        let bp = *point - b;
+        let d3 = ab.dot(&bp);
+        let d4 = ac.dot(&bp);
+        if d3 >= 0.0 && d4 <= d3 {
+            return b;
+        }
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+            return a + ab * (d1 / (d1 - d3));
+        }
+        let cp = *point - c;
+        let d5 = ab.dot(&cp);
+        let d6 = ac.dot(&cp);
+        if d6 >= 0.0 && d5 <= d6 {
+            return c;
+        }
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+            return a + ac * (d2 / (d2 - d6));
+        }
+        let va = d3 * d6 - d5 * d4;
+        if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+        let denominator = 1.0 / (va + vb + vc);
+        a + ab * (vb * denominator) + ac * (vc * denominator)
+    }
+
+    /// Computes the distance of a point to the triangle.
+    pub fn distance(&self, point: &Float3) -> f32 {
+        self.closest_point(point).distance(point)
+    }
+
+    /// Computes the unit normal of the triangle, facing the side from which the vertices appear counterclockwise in a right-handed coordinate system.
+    pub fn normal(&self) -> Float3 {
+        (self.b - self.a).cross(&(self.c - self.a)).normalize()
+    }
+}
+
+impl Frustum {
+    /// Extracts the frustum planes from a view-projection matrix that transforms row vectors into clip space with a depth range of 0 to 1.
+    /// Reversed-Z and infinite far plane projections are supported; an infinite far plane contains every point.
+    pub fn from_view_projection(m: &Float4x4) -> Self {
+        let (x, y, z, w) = (m.c0(), m.c1(), m.c2(), m.c3());
+        let plane = |value: Float4| {
+            let plane = Plane::from(value);
+            if plane.normal.dot(&plane.normal) > 0.0 {
+                plane.normalize()
+            } else {
+                plane
+            }
+        };
+        Self {
+            planes: [
+                plane(w + x),
+                plane(w - x),
+                plane(w + y),
+                plane(w - y),
+                plane(z),
+                plane(w - z),
+            ],
+        }
+    }
+
+    /// Returns whether the point lies inside or on the frustum.
+    pub fn contains(&self, point: &Float3) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(point) >= 0.0)
+    }
+
+    /// Returns whether a box may be visible; boxes that are entirely behind one of the planes are culled.
+    /// The test is conservative and can report boxes near the corners of the frustum as visible.
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        self.planes.iter().all(|plane| {
+            let corner = Float3::new(
+                if plane.normal.x >= 0.0 {
+                    aabb.max.x
+                } else {
+                    aabb.min.x
+                },
+                if plane.normal.y >= 0.0 {
+                    aabb.max.y
+                } else {
+                    aabb.min.y
+                },
+                if plane.normal.z >= 0.0 {
+                    aabb.max.z
+                } else {
+                    aabb.min.z
+                },
+            );
+            plane.signed_distance(&corner) >= 0.0
+        })
+    }
+
+    /// Returns whether a sphere may be visible; spheres that are entirely behind one of the planes are culled.
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(&sphere.center) >= -sphere.radius)
+    }
+}
+
+/// Returns the distances along one axis where a ray is between the planes at `min` and `max`.
+fn slab(origin: f32, direction: f32, min: f32, max: f32) -> Option<(f32, f32)> {
+    if direction == 0.0 {
+        // dividing would give 0 * inf = NaN for an origin on a plane, so check the origin instead.
+        return (min <= origin && origin <= max).then_some((f32::NEG_INFINITY, f32::INFINITY));
+    }
+    let t0 = (min - origin) / direction;
+    let t1 = (max - origin) / direction;
+    Some((t0.min(t1), t0.max(t1)))
+}
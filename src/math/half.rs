@@ -0,0 +1,426 @@
+use core::cmp;
+use core::fmt;
+use core::ops;
+
+use super::vector::Scalar;
+
+/// Half-precision (IEEE 754 binary16) floating point value.
+/// Stored as raw bits and converted to and from `f32` in software.
+#[derive(Copy, Clone, Debug)]
+pub struct Half {
+    bits: u16,
+}
+
+impl fmt::Display for Half {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_f32())
+    }
+}
+
+impl ops::Add<Self> for Half {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::from_f32(self.to_f32() + rhs.to_f32())
+    }
+}
+
+impl ops::AddAssign<Self> for Half {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl ops::Sub<Self> for Half {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_f32(self.to_f32() - rhs.to_f32())
+    }
+}
+
+impl ops::SubAssign<Self> for Half {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl ops::Mul<Self> for Half {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_f32(self.to_f32() * rhs.to_f32())
+    }
+}
+
+impl ops::MulAssign<Self> for Half {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl ops::Div<Self> for Half {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Self::from_f32(self.to_f32() / rhs.to_f32())
+    }
+}
+
+impl ops::DivAssign<Self> for Half {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl ops::Rem<Self> for Half {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        Self::from_f32(self.to_f32() % rhs.to_f32())
+    }
+}
+
+impl ops::RemAssign<Self> for Half {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
+
+impl ops::Neg for Half {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            bits: self.bits ^ 0x8000,
+        }
+    }
+}
+
+impl PartialEq for Half {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.to_f32() == rhs.to_f32()
+    }
+}
+
+impl PartialOrd for Half {
+    fn partial_cmp(&self, rhs: &Self) -> Option<cmp::Ordering> {
+        self.to_f32().partial_cmp(&rhs.to_f32())
+    }
+}
+
+impl From<f32> for Half {
+    fn from(lhs: f32) -> Self {
+        Self::from_f32(lhs)
+    }
+}
+
+impl From<Half> for f32 {
+    fn from(lhs: Half) -> Self {
+        lhs.to_f32()
+    }
+}
+
+impl Scalar for Half {
+    const ZERO: Self = Self { bits: 0x0000 };
+    const ONE: Self = Self { bits: 0x3c00 };
+    const TWO: Self = Self { bits: 0x4000 };
+    const THREE: Self = Self { bits: 0x4200 };
+    const INFINITY: Self = Self { bits: 0x7c00 };
+
+    fn frexp(self) -> (Self, Self) {
+        let (mantissa, exponent) = Scalar::frexp(self.to_f32());
+        (Self::from_f32(mantissa), Self::from_f32(exponent))
+    }
+
+    fn ldexp(self, exponent: Self) -> Self {
+        Self::from_f32(Scalar::ldexp(self.to_f32(), exponent.to_f32()))
+    }
+}
+
+impl Half {
+    /// Computes the absolute value.
+    /// Computed in single precision and rounded back to a half.
+    pub fn abs(self) -> Self {
+        Self::from_f32(self.to_f32().abs())
+    }
+
+    /// Computes the arccosine (in radians).
+    /// Computed in single precision and rounded back to a half.
+    pub fn acos(self) -> Self {
+        Self::from_f32(self.to_f32().acos())
+    }
+
+    /// Computes the arcsine (in radians).
+    /// Computed in single precision and rounded back to a half.
+    pub fn asin(self) -> Self {
+        Self::from_f32(self.to_f32().asin())
+    }
+
+    /// Computes the arctangent (in radians).
+    /// Computed in single precision and rounded back to a half.
+    pub fn atan(self) -> Self {
+        Self::from_f32(self.to_f32().atan())
+    }
+
+    /// Computes the four quadrant arctangent of `self` (y) and `other` (x) (in radians).
+    /// Computed in single precision and rounded back to a half.
+    pub fn atan2(self, other: Self) -> Self {
+        Self::from_f32(self.to_f32().atan2(other.to_f32()))
+    }
+
+    /// Computes the smallest integer greater than or equal to the value.
+    /// Computed in single precision and rounded back to a half.
+    pub fn ceil(self) -> Self {
+        Self::from_f32(self.to_f32().ceil())
+    }
+
+    /// Restricts the value to the range `min` to `max`.
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Self::from_f32(self.to_f32().clamp(min.to_f32(), max.to_f32()))
+    }
+
+    /// Computes the cosine (in radians).
+    /// Computed in single precision and rounded back to a half.
+    pub fn cos(self) -> Self {
+        Self::from_f32(self.to_f32().cos())
+    }
+
+    /// Computes the hyperbolic cosine.
+    /// Computed in single precision and rounded back to a half.
+    pub fn cosh(self) -> Self {
+        Self::from_f32(self.to_f32().cosh())
+    }
+
+    /// Computes e^(self), the exponential function.
+    /// Computed in single precision and rounded back to a half.
+    pub fn exp(self) -> Self {
+        Self::from_f32(self.to_f32().exp())
+    }
+
+    /// Computes 2^(self).
+    /// Computed in single precision and rounded back to a half.
+    pub fn exp2(self) -> Self {
+        Self::from_f32(self.to_f32().exp2())
+    }
+
+    /// Computes the largest integer less than or equal to the value.
+    /// Computed in single precision and rounded back to a half.
+    pub fn floor(self) -> Self {
+        Self::from_f32(self.to_f32().floor())
+    }
+
+    /// Computes the fractional part of the value.
+    /// Computed in single precision and rounded back to a half.
+    pub fn fract(self) -> Self {
+        Self::from_f32(self.to_f32().fract())
+    }
+
+    /// Creates a half from its raw IEEE 754 binary16 bits.
+    pub fn from_bits(bits: u16) -> Self {
+        Self { bits }
+    }
+
+    /// Converts a floating point value to the nearest half, rounding ties to even.
+    pub fn from_f32(value: f32) -> Self {
+        Self {
+            bits: f32tof16(value) as u16,
+        }
+    }
+
+    /// Determines if the value is neither infinite nor NaN.
+    pub fn is_finite(self) -> bool {
+        self.bits & 0x7c00 != 0x7c00
+    }
+
+    /// Determines if the value is positive or negative infinity.
+    pub fn is_infinite(self) -> bool {
+        self.bits & 0x7fff == 0x7c00
+    }
+
+    /// Determines if the value is NaN.
+    pub fn is_nan(self) -> bool {
+        self.bits & 0x7fff > 0x7c00
+    }
+
+    /// Computes the natural logarithm (base e).
+    /// Computed in single precision and rounded back to a half.
+    pub fn ln(self) -> Self {
+        Self::from_f32(self.to_f32().ln())
+    }
+
+    /// Computes the logarithm (base 10).
+    /// Computed in single precision and rounded back to a half.
+    pub fn log10(self) -> Self {
+        Self::from_f32(self.to_f32().log10())
+    }
+
+    /// Computes the logarithm (base 2).
+    /// Computed in single precision and rounded back to a half.
+    pub fn log2(self) -> Self {
+        Self::from_f32(self.to_f32().log2())
+    }
+
+    /// Computes the maximum of two numbers; ignoring NaN.
+    /// Computed in single precision and rounded back to a half.
+    pub fn max(self, other: Self) -> Self {
+        Self::from_f32(self.to_f32().max(other.to_f32()))
+    }
+
+    /// Computes the minimum of two numbers; ignoring NaN.
+    /// Computed in single precision and rounded back to a half.
+    pub fn min(self, other: Self) -> Self {
+        Self::from_f32(self.to_f32().min(other.to_f32()))
+    }
+
+    /// Raises the value to a floating point power.
+    /// Computed in single precision and rounded back to a half.
+    pub fn powf(self, exponent: Self) -> Self {
+        Self::from_f32(self.to_f32().powf(exponent.to_f32()))
+    }
+
+    /// Rounds to the nearest integer; rounding half-way cases away from zero.
+    /// Computed in single precision and rounded back to a half.
+    pub fn round(self) -> Self {
+        Self::from_f32(self.to_f32().round())
+    }
+
+    /// Computes the sign of the value; returning -1, 1 or NaN.
+    /// Computed in single precision and rounded back to a half.
+    pub fn signum(self) -> Self {
+        Self::from_f32(self.to_f32().signum())
+    }
+
+    /// Computes the sine (in radians).
+    /// Computed in single precision and rounded back to a half.
+    pub fn sin(self) -> Self {
+        Self::from_f32(self.to_f32().sin())
+    }
+
+    /// Computes the hyperbolic sine.
+    /// Computed in single precision and rounded back to a half.
+    pub fn sinh(self) -> Self {
+        Self::from_f32(self.to_f32().sinh())
+    }
+
+    /// Computes the square root.
+    /// Computed in single precision and rounded back to a half.
+    pub fn sqrt(self) -> Self {
+        Self::from_f32(self.to_f32().sqrt())
+    }
+
+    /// Computes the tangent (in radians).
+    /// Computed in single precision and rounded back to a half.
+    pub fn tan(self) -> Self {
+        Self::from_f32(self.to_f32().tan())
+    }
+
+    /// Computes the hyperbolic tangent.
+    /// Computed in single precision and rounded back to a half.
+    pub fn tanh(self) -> Self {
+        Self::from_f32(self.to_f32().tanh())
+    }
+
+    /// Returns the raw IEEE 754 binary16 bits of the half.
+    pub fn to_bits(&self) -> u16 {
+        self.bits
+    }
+
+    /// Converts radians to degrees.
+    /// Computed in single precision and rounded back to a half.
+    pub fn to_degrees(self) -> Self {
+        Self::from_f32(self.to_f32().to_degrees())
+    }
+
+    /// Converts the half to a floating point value; this conversion is exact.
+    pub fn to_f32(&self) -> f32 {
+        f16tof32(self.bits as u32)
+    }
+
+    /// Converts degrees to radians.
+    /// Computed in single precision and rounded back to a half.
+    pub fn to_radians(self) -> Self {
+        Self::from_f32(self.to_f32().to_radians())
+    }
+
+    /// Truncates the value to its integer portion.
+    /// Computed in single precision and rounded back to a half.
+    pub fn trunc(self) -> Self {
+        Self::from_f32(self.to_f32().trunc())
+    }
+}
+
+/// Converts a floating point value to half precision and returns the bits in the lower 16 bits.
+/// Rounds to nearest even, produces subnormals for tiny values and infinity on overflow.
+/// NaN values stay NaN with the quiet bit set, keeping the sign and the upper payload bits.
+pub fn f32tof16(value: f32) -> u32 {
+    let bits = value.to_bits();
+    let sign = (bits >> 16) & 0x8000;
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x7fffff;
+
+    // infinity and NaN.
+    if exponent == 0xff {
+        if mantissa != 0 {
+            return sign | 0x7e00 | (mantissa >> 13);
+        }
+        return sign | 0x7c00;
+    }
+
+    // re-bias the exponent from 127 to 15.
+    let exponent = exponent - 112;
+    if exponent >= 0x1f {
+        return sign | 0x7c00;
+    }
+
+    if exponent <= 0 {
+        // too small to round up to the smallest subnormal.
+        if exponent < -10 {
+            return sign;
+        }
+        let mantissa = mantissa | 0x800000;
+        let shift = (14 - exponent) as u32;
+        let half = mantissa >> shift;
+        let round = (mantissa >> (shift - 1)) & 1;
+        let sticky = mantissa & ((1 << (shift - 1)) - 1);
+        let half = if round != 0 && (sticky != 0 || half & 1 != 0) {
+            half + 1
+        } else {
+            half
+        };
+        return sign | half;
+    }
+
+    let half = ((exponent as u32) << 10) | (mantissa >> 13);
+    let round = mantissa & 0x1000;
+    let sticky = mantissa & 0xfff;
+    // a carry out of the mantissa correctly increments the exponent (up to infinity).
+    if round != 0 && (sticky != 0 || half & 1 != 0) {
+        sign | (half + 1)
+    } else {
+        sign | half
+    }
+}
+
+/// Converts the half precision bits in the lower 16 bits of `value` to a floating point value.
+/// The upper 16 bits are ignored and the conversion is exact.
+pub fn f16tof32(value: u32) -> f32 {
+    let sign = (value & 0x8000) << 16;
+    let exponent = (value >> 10) & 0x1f;
+    let mantissa = value & 0x3ff;
+
+    let bits = if exponent == 0x1f {
+        // infinity and NaN.
+        sign | 0x7f800000 | (mantissa << 13)
+    } else if exponent != 0 {
+        sign | ((exponent + 112) << 23) | (mantissa << 13)
+    } else if mantissa != 0 {
+        // subnormals are normalized as every subnormal half is a normal float.
+        let shift = mantissa.leading_zeros() - 21;
+        let mantissa = (mantissa << shift) & 0x3ff;
+        sign | ((113 - shift) << 23) | (mantissa << 13)
+    } else {
+        sign
+    };
+    f32::from_bits(bits)
+}
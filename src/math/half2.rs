@@ -0,0 +1,53 @@
+use super::Bool2;
+use super::Float2;
+use super::Half;
+use super::Half3;
+use super::Half4;
+use super::swizzle::swizzles2;
+use super::vector::float_arithmetic;
+use super::vector::float_methods;
+use super::vector::vector_base;
+
+/// Vector containing 2 half-precision floating point values.
+#[derive(Copy, Clone, Debug)]
+pub struct Half2 {
+    /// The x-component of the vector.
+    pub x: Half,
+    /// The y-component of the vector.
+    pub y: Half,
+}
+
+vector_base!(Half2: Half { x: 0, y: 1 });
+float_arithmetic!(Half2: Half { x, y });
+float_methods!(Half2: Half, Bool2 { x, y });
+swizzles2!(Half2: Half => Half2, Half3, Half4);
+
+impl From<Float2> for Half2 {
+    fn from(lhs: Float2) -> Self {
+        Self {
+            x: Half::from_f32(lhs.x),
+            y: Half::from_f32(lhs.y),
+        }
+    }
+}
+
+impl From<Half2> for Float2 {
+    fn from(lhs: Half2) -> Self {
+        Self {
+            x: lhs.x.to_f32(),
+            y: lhs.y.to_f32(),
+        }
+    }
+}
+
+impl Half2 {
+    /// Creates a vector from 2 half-precision floating point values.
+    pub fn new(x: Half, y: Half) -> Self {
+        Self { x, y }
+    }
+
+    /// Computes the four quadrant arctangent of y and x (in radians).
+    pub fn atan2(&self) -> Half {
+        self.y.atan2(self.x)
+    }
+}
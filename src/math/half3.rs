@@ -0,0 +1,63 @@
+use super::Bool3;
+use super::Float3;
+use super::Half;
+use super::Half2;
+use super::Half4;
+use super::swizzle::swizzles3;
+use super::vector::compositions3;
+use super::vector::float_arithmetic;
+use super::vector::float_methods;
+use super::vector::vector_base;
+
+/// Vector containing 3 half-precision floating point values.
+#[derive(Copy, Clone, Debug)]
+pub struct Half3 {
+    /// The x-component of the vector.
+    pub x: Half,
+    /// The y-component of the vector.
+    pub y: Half,
+    /// The z-component of the vector.
+    pub z: Half,
+}
+
+vector_base!(Half3: Half { x: 0, y: 1, z: 2 });
+compositions3!(Half3: Half => Half2);
+float_arithmetic!(Half3: Half { x, y, z });
+float_methods!(Half3: Half, Bool3 { x, y, z });
+swizzles3!(Half3: Half => Half2, Half3, Half4);
+
+impl From<Float3> for Half3 {
+    fn from(lhs: Float3) -> Self {
+        Self {
+            x: Half::from_f32(lhs.x),
+            y: Half::from_f32(lhs.y),
+            z: Half::from_f32(lhs.z),
+        }
+    }
+}
+
+impl From<Half3> for Float3 {
+    fn from(lhs: Half3) -> Self {
+        Self {
+            x: lhs.x.to_f32(),
+            y: lhs.y.to_f32(),
+            z: lhs.z.to_f32(),
+        }
+    }
+}
+
+impl Half3 {
+    /// Creates a vector from 3 half-precision floating point values.
+    pub fn new(x: Half, y: Half, z: Half) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Computes the cross product of two vectors.
+    pub fn cross(&self, rhs: &Self) -> Self {
+        Self {
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use super::Bool4;
+use super::Float4;
+use super::Half;
+use super::Half2;
+use super::Half3;
+use super::swizzle::swizzles4;
+use super::vector::compositions4;
+use super::vector::float_arithmetic;
+use super::vector::float_methods;
+use super::vector::vector_base;
+
+/// Vector containing 4 half-precision floating point values.
+#[derive(Copy, Clone, Debug)]
+pub struct Half4 {
+    /// The x-component of the vector.
+    pub x: Half,
+    /// The y-component of the vector.
+    pub y: Half,
+    /// The z-component of the vector.
+    pub z: Half,
+    /// The w-component of the vector.
+    pub w: Half,
+}
+
+vector_base!(Half4: Half { x: 0, y: 1, z: 2, w: 3 });
+compositions4!(Half4: Half => Half2, Half3);
+float_arithmetic!(Half4: Half { x, y, z, w });
+float_methods!(Half4: Half, Bool4 { x, y, z, w });
+swizzles4!(Half4: Half => Half2, Half3, Half4);
+
+impl From<Float4> for Half4 {
+    fn from(lhs: Float4) -> Self {
+        Self {
+            x: Half::from_f32(lhs.x),
+            y: Half::from_f32(lhs.y),
+            z: Half::from_f32(lhs.z),
+            w: Half::from_f32(lhs.w),
+        }
+    }
+}
+
+impl From<Half4> for Float4 {
+    fn from(lhs: Half4) -> Self {
+        Self {
+            x: lhs.x.to_f32(),
+            y: lhs.y.to_f32(),
+            z: lhs.z.to_f32(),
+            w: lhs.w.to_f32(),
+        }
+    }
+}
+
+impl Half4 {
+    /// Creates a vector from 4 half-precision floating point values.
+    pub fn new(x: Half, y: Half, z: Half, w: Half) -> Self {
+        Self { x, y, z, w }
+    }
+}
@@ -0,0 +1,34 @@
+use super::Float2;
+use super::Int3;
+use super::Int4;
+use super::UInt2;
+use super::reinterpret::reinterpret;
+use super::swizzle::swizzles2;
+use super::vector::integer_arithmetic;
+use super::vector::integer_methods;
+use super::vector::signed_methods;
+use super::vector::vector_base;
+
+/// Vector containing 2 signed integer values.
+/// Arithmetic wraps around on overflow like HLSL instead of panicking.
+#[derive(Copy, Clone, Debug)]
+pub struct Int2 {
+    /// The x-component of the vector.
+    pub x: i32,
+    /// The y-component of the vector.
+    pub y: i32,
+}
+
+vector_base!(Int2: i32 { x: 0, y: 1 });
+integer_arithmetic!(Int2: i32 { x, y });
+integer_methods!(Int2: i32 { x, y });
+signed_methods!(Int2 { x, y });
+reinterpret!(Int2 { x, y } => Float2, Int2, UInt2);
+swizzles2!(Int2: i32 => Int2, Int3, Int4);
+
+impl Int2 {
+    /// Creates a vector from 2 signed integer values.
+    pub fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+}
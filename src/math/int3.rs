@@ -0,0 +1,38 @@
+use super::Float3;
+use super::Int2;
+use super::Int4;
+use super::UInt3;
+use super::reinterpret::reinterpret;
+use super::swizzle::swizzles3;
+use super::vector::compositions3;
+use super::vector::integer_arithmetic;
+use super::vector::integer_methods;
+use super::vector::signed_methods;
+use super::vector::vector_base;
+
+/// Vector containing 3 signed integer values.
+/// Arithmetic wraps around on overflow like HLSL instead of panicking.
+#[derive(Copy, Clone, Debug)]
+pub struct Int3 {
+    /// The x-component of the vector.
+    pub x: i32,
+    /// The y-component of the vector.
+    pub y: i32,
+    /// The z-component of the vector.
+    pub z: i32,
+}
+
+vector_base!(Int3: i32 { x: 0, y: 1, z: 2 });
+compositions3!(Int3: i32 => Int2);
+integer_arithmetic!(Int3: i32 { x, y, z });
+integer_methods!(Int3: i32 { x, y, z });
+signed_methods!(Int3 { x, y, z });
+reinterpret!(Int3 { x, y, z } => Float3, Int3, UInt3);
+swizzles3!(Int3: i32 => Int2, Int3, Int4);
+
+impl Int3 {
+    /// Creates a vector from 3 signed integer values.
+    pub fn new(x: i32, y: i32, z: i32) -> Self {
+        Self { x, y, z }
+    }
+}
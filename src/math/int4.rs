@@ -0,0 +1,66 @@
+use super::Float4;
+use super::Int2;
+use super::Int3;
+use super::UInt4;
+use super::packed::pack_bytes;
+use super::reinterpret::reinterpret;
+use super::swizzle::swizzles4;
+use super::vector::compositions4;
+use super::vector::integer_arithmetic;
+use super::vector::integer_methods;
+use super::vector::signed_methods;
+use super::vector::vector_base;
+
+/// Vector containing 4 signed integer values.
+/// Arithmetic wraps around on overflow like HLSL instead of panicking.
+#[derive(Copy, Clone, Debug)]
+pub struct Int4 {
+    /// The x-component of the vector.
+    pub x: i32,
+    /// The y-component of the vector.
+    pub y: i32,
+    /// The z-component of the vector.
+    pub z: i32,
+    /// The w-component of the vector.
+    pub w: i32,
+}
+
+vector_base!(Int4: i32 { x: 0, y: 1, z: 2, w: 3 });
+compositions4!(Int4: i32 => Int2, Int3);
+integer_arithmetic!(Int4: i32 { x, y, z, w });
+integer_methods!(Int4: i32 { x, y, z, w });
+signed_methods!(Int4 { x, y, z, w });
+reinterpret!(Int4 { x, y, z, w } => Float4, Int4, UInt4);
+swizzles4!(Int4: i32 => Int2, Int3, Int4);
+
+impl Int4 {
+    /// Creates a vector from 4 signed integer values.
+    pub fn new(x: i32, y: i32, z: i32, w: i32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Packs each component clamped to the range -128 to 127 into the bytes of a 32-bit value; `x` in the lowest byte.
+    pub fn pack_clamp_s8(&self) -> u32 {
+        pack_bytes(
+            self.x.clamp(-128, 127) as u32,
+            self.y.clamp(-128, 127) as u32,
+            self.z.clamp(-128, 127) as u32,
+            self.w.clamp(-128, 127) as u32,
+        )
+    }
+
+    /// Packs each component clamped to the range 0 to 255 into the bytes of a 32-bit value; `x` in the lowest byte.
+    pub fn pack_clamp_u8(&self) -> u32 {
+        pack_bytes(
+            self.x.clamp(0, 255) as u32,
+            self.y.clamp(0, 255) as u32,
+            self.z.clamp(0, 255) as u32,
+            self.w.clamp(0, 255) as u32,
+        )
+    }
+
+    /// Packs the lower 8 bits of each component into a signed 8-bit packed value; `x` in the lowest byte.
+    pub fn pack_s8(&self) -> u32 {
+        pack_bytes(self.x as u32, self.y as u32, self.z as u32, self.w as u32)
+    }
+}
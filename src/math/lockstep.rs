@@ -0,0 +1,185 @@
+// Runs a group of threads in lockstep for the quad, wave and compute emulators.
+//
+// Unlike `std::sync::Barrier` the lockstep is poisoned when one of the threads panics, so the
+// remaining threads stop waiting and the original panic is propagated to the caller. Threads leave
+// the lockstep when they return, which a barrier reports as an error instead of waiting forever.
+// Emulators that need more than a barrier keep their own state inside the lockstep and wait on it
+// with `wait_until`.
+
+use std::panic;
+use std::sync::Condvar;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+use std::thread;
+
+/// Barrier shared by a fixed number of threads that is poisoned when one of them panics.
+/// Holds the state `S` shared by the threads, which is protected by the same lock as the barrier.
+pub(crate) struct Lockstep<S = ()> {
+    count: usize,
+    state: Mutex<LockstepState<S>>,
+    condvar: Condvar,
+}
+
+struct LockstepState<S> {
+    arrived: usize,
+    generation: u64,
+    poisoned: bool,
+    /// Whether each thread has returned.
+    returned: Vec<bool>,
+    shared: S,
+}
+
+/// Signals that a thread returned before reaching a barrier the other threads wait at.
+#[derive(Debug)]
+pub(crate) struct Departed;
+
+/// Payload used to unwind the threads that were waiting on a poisoned lockstep.
+struct Poisoned;
+
+/// Poisons the lockstep when the owning thread unwinds.
+struct PoisonOnPanic<'a, S>(&'a Lockstep<S>);
+
+impl<S> Drop for PoisonOnPanic<'_, S> {
+    fn drop(&mut self) {
+        if thread::panicking() {
+            self.0.poison();
+        }
+    }
+}
+
+impl Lockstep {
+    /// Creates a lockstep for `count` threads.
+    pub(crate) fn new(count: usize) -> Self {
+        Self::with_state(count, ())
+    }
+}
+
+impl<S> Lockstep<S> {
+    /// Creates a lockstep for `count` threads sharing `shared`.
+    pub(crate) fn with_state(count: usize, shared: S) -> Self {
+        Self {
+            count,
+            state: Mutex::new(LockstepState {
+                arrived: 0,
+                generation: 0,
+                poisoned: false,
+                returned: vec![false; count],
+                shared,
+            }),
+            condvar: Condvar::new(),
+        }
+    }
+
+    /// Runs `f` on one thread per index and returns the results in index order.
+    /// A panic on any of the threads is propagated once all threads have stopped.
+    pub(crate) fn run<R, F>(&self, f: F) -> Vec<R>
+    where
+        S: Send,
+        R: Send,
+        F: Fn(usize) -> R + Sync,
+    {
+        let f = &f;
+        thread::scope(|scope| {
+            let handles: Vec<_> = (0..self.count)
+                .map(|index| {
+                    scope.spawn(move || {
+                        let _guard = PoisonOnPanic(self);
+                        let result = f(index);
+                        self.leave(index);
+                        result
+                    })
+                })
+                .collect();
+            let mut results = Vec::with_capacity(self.count);
+            let mut original = None;
+            for handle in handles {
+                match handle.join() {
+                    Ok(result) => results.push(result),
+                    // the waiting threads only unwind because another thread panicked.
+                    Err(payload) if payload.is::<Poisoned>() => {}
+                    Err(payload) => {
+                        original.get_or_insert(payload);
+                    }
+                }
+            }
+            if let Some(payload) = original {
+                panic::resume_unwind(payload);
+            }
+            results
+        })
+    }
+
+    /// Changes the shared state and wakes the threads waiting in `wait_until`.
+    pub(crate) fn update<R>(&self, f: impl FnOnce(&mut S) -> R) -> R {
+        let result = f(&mut self.lock().shared);
+        self.condvar.notify_all();
+        result
+    }
+
+    /// Blocks until all threads have called `wait`.
+    /// Returns `Err(Departed)` when a thread has returned without calling `wait`, since the others would wait forever.
+    pub(crate) fn wait(&self) -> Result<(), Departed> {
+        let mut state = self.lock();
+        let generation = state.generation;
+        state.arrived += 1;
+        if state.arrived == self.count {
+            state.arrived = 0;
+            state.generation += 1;
+            self.condvar.notify_all();
+        } else {
+            while state.generation == generation && !state.poisoned {
+                if state.returned.contains(&true) {
+                    return Err(Departed);
+                }
+                state = self
+                    .condvar
+                    .wait(state)
+                    .unwrap_or_else(PoisonError::into_inner);
+            }
+        }
+        if state.poisoned {
+            drop(state);
+            panic::resume_unwind(Box::new(Poisoned));
+        }
+        Ok(())
+    }
+
+    /// Calls `poll` with the shared state and whether each thread has returned until it returns `Some`.
+    /// Blocks in between until another thread changes the shared state or returns.
+    pub(crate) fn wait_until<R>(&self, mut poll: impl FnMut(&mut S, &[bool]) -> Option<R>) -> R {
+        let mut state = self.lock();
+        loop {
+            if state.poisoned {
+                drop(state);
+                panic::resume_unwind(Box::new(Poisoned));
+            }
+            let LockstepState {
+                returned, shared, ..
+            } = &mut *state;
+            if let Some(result) = poll(shared, returned) {
+                return result;
+            }
+            state = self
+                .condvar
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// Marks a thread as returned, waking the threads that wait for it.
+    fn leave(&self, index: usize) {
+        self.lock().returned[index] = true;
+        self.condvar.notify_all();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, LockstepState<S>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Wakes all waiting threads and makes them unwind.
+    fn poison(&self) {
+        self.lock().poisoned = true;
+        self.condvar.notify_all();
+    }
+}
@@ -0,0 +1,43 @@
+use super::Float2;
+use super::Float3;
+use super::Float4;
+
+/// Multiplication of vectors and matrices following the HLSL `mul` intrinsic.
+pub trait Multiply<Rhs> {
+    /// The resulting type after multiplication.
+    type Output;
+
+    /// Multiplies `self` by `rhs`.
+    fn mul(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Multiplies `lhs` by `rhs` using HLSL `mul` semantics.
+/// A vector on the left-hand side is treated as a row vector and a vector on the right-hand side as a column vector.
+/// Multiplying two vectors computes their dot product.
+pub fn mul<L: Multiply<R>, R>(lhs: L, rhs: R) -> L::Output {
+    lhs.mul(rhs)
+}
+
+impl Multiply<Float2> for Float2 {
+    type Output = f32;
+
+    fn mul(self, rhs: Float2) -> f32 {
+        self.dot(&rhs)
+    }
+}
+
+impl Multiply<Float3> for Float3 {
+    type Output = f32;
+
+    fn mul(self, rhs: Float3) -> f32 {
+        self.dot(&rhs)
+    }
+}
+
+impl Multiply<Float4> for Float4 {
+    type Output = f32;
+
+    fn mul(self, rhs: Float4) -> f32 {
+        self.dot(&rhs)
+    }
+}
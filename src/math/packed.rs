@@ -0,0 +1,48 @@
+// Packed 8-bit integer intrinsics from Shader Model 6.6.
+//
+// Packed values are stored in a `u32` with the x-component in the lowest byte, matching the
+// `uint8_t4_packed` and `int8_t4_packed` types of HLSL.
+
+use super::Int4;
+use super::UInt4;
+
+/// Packs the lower 8 bits of the four values into a 32-bit value; `x` in the lowest byte.
+pub(crate) fn pack_bytes(x: u32, y: u32, z: u32, w: u32) -> u32 {
+    (x & 0xff) | ((y & 0xff) << 8) | ((z & 0xff) << 16) | ((w & 0xff) << 24)
+}
+
+/// Computes the dot product of the signed 8-bit packed values `a` and `b` and adds it to `acc`.
+/// Wraps around on overflow like HLSL.
+pub fn dot4add_i8packed(a: u32, b: u32, acc: i32) -> i32 {
+    let a = unpack_s8s32(a);
+    let b = unpack_s8s32(b);
+    acc.wrapping_add(a.dot(&b))
+}
+
+/// Computes the dot product of the unsigned 8-bit packed values `a` and `b` and adds it to `acc`.
+/// Wraps around on overflow like HLSL.
+pub fn dot4add_u8packed(a: u32, b: u32, acc: u32) -> u32 {
+    let a = unpack_u8u32(a);
+    let b = unpack_u8u32(b);
+    acc.wrapping_add(a.dot(&b))
+}
+
+/// Unpacks a signed 8-bit packed value into four sign-extended 32-bit integers.
+pub fn unpack_s8s32(packed: u32) -> Int4 {
+    Int4 {
+        x: packed as u8 as i8 as i32,
+        y: (packed >> 8) as u8 as i8 as i32,
+        z: (packed >> 16) as u8 as i8 as i32,
+        w: (packed >> 24) as u8 as i8 as i32,
+    }
+}
+
+/// Unpacks an unsigned 8-bit packed value into four zero-extended 32-bit integers.
+pub fn unpack_u8u32(packed: u32) -> UInt4 {
+    UInt4 {
+        x: packed & 0xff,
+        y: (packed >> 8) & 0xff,
+        z: (packed >> 16) & 0xff,
+        w: packed >> 24,
+    }
+}
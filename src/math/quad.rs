@@ -0,0 +1,166 @@
+// Emulation of a 2x2 pixel quad for the derivative intrinsics.
+//
+// The four pixels of the quad run on their own threads in lockstep: every derivative call
+// publishes the value of the calling pixel, waits for the other three and then computes the
+// differences following the D3D coarse and fine derivative rules.
+
+use core::any::Any;
+use core::ops;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+
+use super::Float2;
+use super::Float3;
+use super::Float4;
+use super::lockstep::Departed;
+use super::lockstep::Lockstep;
+
+/// Values that can be differentiated across the pixels of a quad.
+pub trait Derivative:
+    Copy + Send + 'static + ops::Add<Output = Self> + ops::Sub<Output = Self>
+{
+    /// Computes the absolute value of each component.
+    fn abs(self) -> Self;
+}
+
+impl Derivative for f32 {
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
+}
+
+impl Derivative for Float2 {
+    fn abs(self) -> Self {
+        Float2::abs(&self)
+    }
+}
+
+impl Derivative for Float3 {
+    fn abs(self) -> Self {
+        Float3::abs(&self)
+    }
+}
+
+impl Derivative for Float4 {
+    fn abs(self) -> Self {
+        Float4::abs(&self)
+    }
+}
+
+/// State shared by the four pixels of a quad.
+struct QuadShared {
+    lockstep: Lockstep,
+    values: Mutex<[Option<Box<dyn Any + Send>>; 4]>,
+}
+
+/// A pixel inside a 2x2 quad, giving access to the derivative intrinsics.
+/// Lanes are numbered 0 (top-left), 1 (top-right), 2 (bottom-left) and 3 (bottom-right).
+/// All four pixels must call the derivative intrinsics in the same order, like uniform control flow in HLSL;
+/// a pixel returning before a call the others make panics instead of leaving them waiting forever.
+pub struct Quad<'a> {
+    lane: usize,
+    position: Float2,
+    shared: &'a QuadShared,
+}
+
+impl Quad<'_> {
+    /// Runs `shader` for the four pixels of the quad whose top-left pixel is at `origin`.
+    /// Returns the results in lane order.
+    pub fn run<R, F>(origin: Float2, shader: F) -> [R; 4]
+    where
+        R: Send,
+        F: Fn(&Quad) -> R + Sync,
+    {
+        let shared = QuadShared {
+            lockstep: Lockstep::new(4),
+            values: Mutex::new([None, None, None, None]),
+        };
+        let results = shared.lockstep.run(|lane| {
+            let quad = Quad {
+                lane,
+                position: origin + Float2::new((lane & 1) as f32, (lane >> 1) as f32),
+                shared: &shared,
+            };
+            shader(&quad)
+        });
+        results
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("a quad has four pixels"))
+    }
+
+    /// Computes the coarse partial derivative of `value` with respect to the screen-space x-coordinate.
+    pub fn ddx<T: Derivative>(&self, value: T) -> T {
+        self.ddx_coarse(value)
+    }
+
+    /// Computes the partial derivative with respect to x using the top row of the quad for all four pixels.
+    pub fn ddx_coarse<T: Derivative>(&self, value: T) -> T {
+        let values = self.exchange(value);
+        values[1] - values[0]
+    }
+
+    /// Computes the partial derivative with respect to x using the row of the quad containing this pixel.
+    pub fn ddx_fine<T: Derivative>(&self, value: T) -> T {
+        let values = self.exchange(value);
+        let row = self.lane & 2;
+        values[row + 1] - values[row]
+    }
+
+    /// Computes the coarse partial derivative of `value` with respect to the screen-space y-coordinate.
+    pub fn ddy<T: Derivative>(&self, value: T) -> T {
+        self.ddy_coarse(value)
+    }
+
+    /// Computes the partial derivative with respect to y using the left column of the quad for all four pixels.
+    pub fn ddy_coarse<T: Derivative>(&self, value: T) -> T {
+        let values = self.exchange(value);
+        values[2] - values[0]
+    }
+
+    /// Computes the partial derivative with respect to y using the column of the quad containing this pixel.
+    pub fn ddy_fine<T: Derivative>(&self, value: T) -> T {
+        let values = self.exchange(value);
+        let column = self.lane & 1;
+        values[column + 2] - values[column]
+    }
+
+    /// Computes the sum of the absolute coarse derivatives: `abs(ddx(value)) + abs(ddy(value))`.
+    pub fn fwidth<T: Derivative>(&self, value: T) -> T {
+        let values = self.exchange(value);
+        (values[1] - values[0]).abs() + (values[2] - values[0]).abs()
+    }
+
+    /// Returns the index of this pixel within the quad.
+    pub fn lane(&self) -> usize {
+        self.lane
+    }
+
+    /// Returns the screen-space position of this pixel.
+    pub fn position(&self) -> Float2 {
+        self.position
+    }
+
+    /// Publishes the value of this pixel and returns the values of all four pixels in lane order.
+    fn exchange<T: Derivative>(&self, value: T) -> [T; 4] {
+        let lock = || {
+            self.shared
+                .values
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+        };
+        let wait = || {
+            self.shared.lockstep.wait().unwrap_or_else(|Departed| {
+                panic!("a pixel of the quad returned before the others computed a derivative")
+            })
+        };
+        lock()[self.lane] = Some(Box::new(value));
+        wait();
+        let values = {
+            let values = lock();
+            [0, 1, 2, 3].map(|lane| *values[lane].as_ref().unwrap().downcast_ref::<T>().unwrap())
+        };
+        // keep the values alive until every pixel has read them.
+        wait();
+        values
+    }
+}
@@ -0,0 +1,275 @@
+// Rotation quaternions following the Direct3D conventions used throughout this crate:
+// `mul(v, q.to_float3x3())` rotates the row vector `v` exactly like `q.rotate(&v)`.
+
+use core::fmt;
+use core::ops;
+
+use super::Float3;
+use super::Float3x3;
+use super::Float4;
+
+/// The order in which the rotations around the fixed x, y and z axes are applied by `Quaternion::from_euler`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EulerOrder {
+    /// Rotates around x first, then around y and finally around z.
+    Xyz,
+    /// Rotates around x first, then around z and finally around y.
+    Xzy,
+    /// Rotates around y first, then around x and finally around z.
+    Yxz,
+    /// Rotates around y first, then around z and finally around x.
+    Yzx,
+    /// Rotates around z first, then around x and finally around y.
+    Zxy,
+    /// Rotates around z first, then around y and finally around x.
+    Zyx,
+}
+
+/// Quaternion containing a vector part (x, y, z) and a scalar part (w), used to represent rotations.
+#[derive(Copy, Clone, Debug)]
+pub struct Quaternion {
+    /// The x-component of the vector part.
+    pub x: f32,
+    /// The y-component of the vector part.
+    pub y: f32,
+    /// The z-component of the vector part.
+    pub z: f32,
+    /// The scalar part.
+    pub w: f32,
+}
+
+impl fmt::Display for Quaternion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Quaternion ({}, {}, {}, {})",
+            self.x, self.y, self.z, self.w
+        )
+    }
+}
+
+impl PartialEq for Quaternion {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y && self.z == other.z && self.w == other.w
+    }
+}
+
+impl From<Float4> for Quaternion {
+    fn from(value: Float4) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+            w: value.w,
+        }
+    }
+}
+
+impl From<Quaternion> for Float4 {
+    fn from(value: Quaternion) -> Self {
+        Float4 {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+            w: value.w,
+        }
+    }
+}
+
+impl ops::Mul<Self> for Quaternion {
+    type Output = Self;
+
+    /// Composes two rotations; the result rotates by `rhs` first and then by `self`.
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        }
+    }
+}
+
+impl ops::MulAssign<Self> for Quaternion {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl ops::Neg for Quaternion {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: -self.w,
+        }
+    }
+}
+
+impl Quaternion {
+    /// Creates a quaternion from its vector part (x, y, z) and scalar part (w).
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Creates the quaternion that does not rotate.
+    pub fn identity() -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: 1.0,
+        }
+    }
+
+    /// Creates a rotation of `angle` radians around `axis`, counterclockwise when looking down the axis.
+    pub fn from_axis_angle(axis: &Float3, angle: f32) -> Self {
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        let axis = axis.normalize() * sin;
+        Self {
+            x: axis.x,
+            y: axis.y,
+            z: axis.z,
+            w: cos,
+        }
+    }
+
+    /// Creates a rotation from the angles (in radians) around the fixed x, y and z axes, applied in the given order.
+    pub fn from_euler(angles: &Float3, order: EulerOrder) -> Self {
+        let x = Self::from_axis_angle(&Float3::new(1.0, 0.0, 0.0), angles.x);
+        let y = Self::from_axis_angle(&Float3::new(0.0, 1.0, 0.0), angles.y);
+        let z = Self::from_axis_angle(&Float3::new(0.0, 0.0, 1.0), angles.z);
+        match order {
+            EulerOrder::Xyz => z * y * x,
+            EulerOrder::Xzy => y * z * x,
+            EulerOrder::Yxz => z * x * y,
+            EulerOrder::Yzx => x * z * y,
+            EulerOrder::Zxy => y * x * z,
+            EulerOrder::Zyx => x * y * z,
+        }
+    }
+
+    /// Creates a quaternion from a rotation matrix that transforms row vectors.
+    pub fn from_float3x3(m: &Float3x3) -> Self {
+        let trace = m.r0.x + m.r1.y + m.r2.z;
+        if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Self {
+                x: (m.r1.z - m.r2.y) / s,
+                y: (m.r2.x - m.r0.z) / s,
+                z: (m.r0.y - m.r1.x) / s,
+                w: 0.25 * s,
+            }
+        } else if m.r0.x > m.r1.y && m.r0.x > m.r2.z {
+            let s = (1.0 + m.r0.x - m.r1.y - m.r2.z).sqrt() * 2.0;
+            Self {
+                x: 0.25 * s,
+                y: (m.r0.y + m.r1.x) / s,
+                z: (m.r0.z + m.r2.x) / s,
+                w: (m.r1.z - m.r2.y) / s,
+            }
+        } else if m.r1.y > m.r2.z {
+            let s = (1.0 + m.r1.y - m.r0.x - m.r2.z).sqrt() * 2.0;
+            Self {
+                x: (m.r0.y + m.r1.x) / s,
+                y: 0.25 * s,
+                z: (m.r1.z + m.r2.y) / s,
+                w: (m.r2.x - m.r0.z) / s,
+            }
+        } else {
+            let s = (1.0 + m.r2.z - m.r0.x - m.r1.y).sqrt() * 2.0;
+            Self {
+                x: (m.r0.z + m.r2.x) / s,
+                y: (m.r1.z + m.r2.y) / s,
+                z: 0.25 * s,
+                w: (m.r0.y - m.r1.x) / s,
+            }
+        }
+    }
+
+    /// Returns the quaternion with a negated vector part; the inverse rotation for unit quaternions.
+    pub fn conjugate(&self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: self.w,
+        }
+    }
+
+    /// Computes the dot product of two quaternions.
+    pub fn dot(&self, rhs: &Self) -> f32 {
+        Float4::from(*self).dot(&Float4::from(*rhs))
+    }
+
+    /// Computes the inverse of the quaternion.
+    pub fn inverse(&self) -> Self {
+        Self::from(Float4::from(self.conjugate()) / self.dot(self))
+    }
+
+    /// Computes the length of the quaternion.
+    pub fn length(&self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// Linearly interpolates along the shortest path between two rotations and normalizes the result.
+    pub fn nlerp(&self, rhs: &Self, t: f32) -> Self {
+        let rhs = if self.dot(rhs) < 0.0 { -*rhs } else { *rhs };
+        Self::from(Float4::from(*self).lerp(&Float4::from(rhs), t).normalize())
+    }
+
+    /// Normalizes the quaternion to unit length.
+    pub fn normalize(&self) -> Self {
+        Self::from(Float4::from(*self).normalize())
+    }
+
+    /// Rotates a vector by the quaternion, which must have unit length.
+    pub fn rotate(&self, v: &Float3) -> Float3 {
+        let u = Float3::new(self.x, self.y, self.z);
+        let t = u.cross(v) * 2.0;
+        *v + t * self.w + u.cross(&t)
+    }
+
+    /// Spherically interpolates along the shortest path between two rotations at a constant angular velocity.
+    pub fn slerp(&self, rhs: &Self, t: f32) -> Self {
+        let mut cos = self.dot(rhs);
+        let mut rhs = *rhs;
+        if cos < 0.0 {
+            cos = -cos;
+            rhs = -rhs;
+        }
+        if cos > 0.9995 {
+            return self.nlerp(&rhs, t);
+        }
+        let angle = cos.acos();
+        let a = ((1.0 - t) * angle).sin();
+        let b = (t * angle).sin();
+        let result = (Float4::from(*self) * a + Float4::from(rhs) * b) / angle.sin();
+        Self::from(result)
+    }
+
+    /// Converts the quaternion, which must have unit length, to a rotation matrix that transforms row vectors.
+    pub fn to_float3x3(&self) -> Float3x3 {
+        let (x, y, z, w) = (self.x, self.y, self.z, self.w);
+        Float3x3 {
+            r0: Float3::new(
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y + w * z),
+                2.0 * (x * z - w * y),
+            ),
+            r1: Float3::new(
+                2.0 * (x * y - w * z),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z + w * x),
+            ),
+            r2: Float3::new(
+                2.0 * (x * z + w * y),
+                2.0 * (y * z - w * x),
+                1.0 - 2.0 * (x * x + y * y),
+            ),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+// Bit reinterpretation intrinsics shared by the 32-bit vector families and doubles.
+
+/// Scalars that are stored in 32 bits and can be reinterpreted as each other.
+pub(crate) trait Bits32: Copy {
+    /// Returns the raw bits of the value.
+    fn to_bits32(self) -> u32;
+}
+
+impl Bits32 for f32 {
+    fn to_bits32(self) -> u32 {
+        self.to_bits()
+    }
+}
+
+impl Bits32 for i32 {
+    fn to_bits32(self) -> u32 {
+        self as u32
+    }
+}
+
+impl Bits32 for u32 {
+    fn to_bits32(self) -> u32 {
+        self
+    }
+}
+
+/// Implements `asfloat`, `asint` and `asuint` reinterpreting the bits of each component.
+macro_rules! reinterpret {
+    ($T:ident { $($c:ident),+ } => $F:ident, $I:ident, $U:ident) => {
+        impl $T {
+            /// Reinterprets the bits of each component as a floating point value.
+            pub fn asfloat(&self) -> $F {
+                $F { $($c: f32::from_bits($crate::math::reinterpret::Bits32::to_bits32(self.$c))),+ }
+            }
+
+            /// Reinterprets the bits of each component as a signed integer.
+            pub fn asint(&self) -> $I {
+                $I { $($c: $crate::math::reinterpret::Bits32::to_bits32(self.$c) as i32),+ }
+            }
+
+            /// Reinterprets the bits of each component as an unsigned integer.
+            pub fn asuint(&self) -> $U {
+                $U { $($c: $crate::math::reinterpret::Bits32::to_bits32(self.$c)),+ }
+            }
+        }
+    };
+}
+
+/// Implements `asuint` splitting a double vector into its bits and `asdouble` joining them again.
+macro_rules! reinterpret_double {
+    ($D:ident { $($c:ident),+ } => $U:ident) => {
+        impl $D {
+            /// Splits the bits of each component into the low and high 32 bits.
+            /// Returns `(lowbits, highbits)` like `asuint(value, lowbits, highbits)` in HLSL.
+            pub fn asuint(&self) -> ($U, $U) {
+                $(let $c = $crate::math::reinterpret::asuint(self.$c);)+
+                ($U { $($c: $c.0),+ }, $U { $($c: $c.1),+ })
+            }
+        }
+
+        impl $U {
+            /// Joins the low 32 bits in `self` and the high 32 bits in `highbits` into double-precision values.
+            pub fn asdouble(&self, highbits: &Self) -> $D {
+                $D { $($c: $crate::math::reinterpret::asdouble(self.$c, highbits.$c)),+ }
+            }
+        }
+    };
+}
+
+pub(crate) use reinterpret;
+pub(crate) use reinterpret_double;
+
+/// Joins the low and high 32 bits into a double-precision floating point value, like `asdouble` in HLSL.
+pub fn asdouble(lowbits: u32, highbits: u32) -> f64 {
+    f64::from_bits(((highbits as u64) << 32) | lowbits as u64)
+}
+
+/// Splits a double-precision floating point value into its low and high 32 bits, like `asuint` in HLSL.
+/// Returns `(lowbits, highbits)`.
+pub fn asuint(value: f64) -> (u32, u32) {
+    let bits = value.to_bits();
+    (bits as u32, (bits >> 32) as u32)
+}
@@ -0,0 +1,22 @@
+/// Per-component selection between two values following the HLSL `select` intrinsic.
+pub trait Select<A, B> {
+    /// The resulting type after selection.
+    type Output;
+
+    /// Picks each component from `a` where `self` is true and from `b` where it is false.
+    fn select(self, a: A, b: B) -> Self::Output;
+}
+
+/// Picks each component from `a` where `cond` is true and from `b` where it is false.
+/// Scalars are broadcast to all components, equivalent to the HLSL `cond ? a : b` on vectors.
+pub fn select<C: Select<A, B>, A, B>(cond: C, a: A, b: B) -> C::Output {
+    cond.select(a, b)
+}
+
+impl<T> Select<T, T> for bool {
+    type Output = T;
+
+    fn select(self, a: T, b: T) -> T {
+        if self { a } else { b }
+    }
+}
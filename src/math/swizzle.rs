@@ -0,0 +1,191 @@
+// Swizzle accessors shared by every vector family.
+//
+// The lists of every swizzle per source width are generated by build.rs from the component lists;
+// the same lists drive all families so Float, Double, Half, Int, UInt and Bool vectors always offer
+// identical swizzles. Every entry names the xyzw swizzle next to its rgba alias and both names share
+// one implementation. Swizzles without repeating components are writable and additionally get a
+// setter and a proxy.
+
+use core::ops;
+
+/// Proxy to the swizzled components of a vector, like `v.zx` on the left-hand side in HLSL.
+/// Dereferences to a copy of the swizzled vector that is written back when the proxy is dropped.
+///
+/// ```
+/// let mut v = shadermath::Float3::new(1.0, 2.0, 3.0);
+/// v.set_zx(shadermath::Float2::new(4.0, 5.0));
+/// *v.xy_mut() *= 2.0;
+/// assert_eq!(v, shadermath::Float3::new(10.0, 4.0, 4.0));
+/// ```
+///
+/// Swizzles with repeating components are read-only, so writing `v.xx` is rejected at compile time:
+///
+/// ```compile_fail
+/// let mut v = shadermath::Float2::new(1.0, 2.0);
+/// v.set_xx(shadermath::Float2::new(3.0, 4.0));
+/// ```
+///
+/// ```compile_fail
+/// let mut v = shadermath::Float2::new(1.0, 2.0);
+/// *v.xx_mut() *= 2.0;
+/// ```
+#[derive(Debug)]
+pub struct Swizzle<'a, T, V: Copy> {
+    target: &'a mut T,
+    value: V,
+    set: fn(&mut T, V),
+}
+
+impl<'a, T, V: Copy> Swizzle<'a, T, V> {
+    /// Creates a proxy reading the swizzled components with `get` and writing them back with `set`.
+    pub(crate) fn new(target: &'a mut T, get: fn(&T) -> V, set: fn(&mut T, V)) -> Self {
+        let value = get(target);
+        Self { target, value, set }
+    }
+}
+
+impl<T, V: Copy> ops::Deref for Swizzle<'_, T, V> {
+    type Target = V;
+
+    fn deref(&self) -> &V {
+        &self.value
+    }
+}
+
+impl<T, V: Copy> ops::DerefMut for Swizzle<'_, T, V> {
+    fn deref_mut(&mut self) -> &mut V {
+        &mut self.value
+    }
+}
+
+impl<T, V: Copy> Drop for Swizzle<'_, T, V> {
+    fn drop(&mut self) {
+        (self.set)(self.target, self.value);
+    }
+}
+
+/// Implements a single swizzle returning a vector of the matching width.
+/// Writable swizzles list their setter and proxy names after the getter name.
+macro_rules! swizzle {
+    ($name:ident, $T2:ident, $T3:ident, $T4:ident: $a:ident, $b:ident) => {
+        /// Returns a swizzled vector.
+        pub fn $name(&self) -> $T2 {
+            $T2 {
+                x: self.$a,
+                y: self.$b,
+            }
+        }
+    };
+    ($name:ident, $T2:ident, $T3:ident, $T4:ident: $a:ident, $b:ident, $c:ident) => {
+        /// Returns a swizzled vector.
+        pub fn $name(&self) -> $T3 {
+            $T3 {
+                x: self.$a,
+                y: self.$b,
+                z: self.$c,
+            }
+        }
+    };
+    ($name:ident, $T2:ident, $T3:ident, $T4:ident: $a:ident, $b:ident, $c:ident, $d:ident) => {
+        /// Returns a swizzled vector.
+        pub fn $name(&self) -> $T4 {
+            $T4 {
+                x: self.$a,
+                y: self.$b,
+                z: self.$c,
+                w: self.$d,
+            }
+        }
+    };
+    ($name:ident / $set:ident / $proxy:ident, $T2:ident, $T3:ident, $T4:ident: $a:ident, $b:ident) => {
+        $crate::math::swizzle::swizzle!($name, $T2, $T3, $T4: $a, $b);
+
+        /// Assigns a vector to the swizzled components.
+        pub fn $set(&mut self, value: $T2) {
+            self.$a = value.x;
+            self.$b = value.y;
+        }
+
+        /// Returns a proxy to the swizzled components for compound assignments.
+        pub fn $proxy(&mut self) -> $crate::math::Swizzle<'_, Self, $T2> {
+            $crate::math::Swizzle::new(self, Self::$name, Self::$set)
+        }
+    };
+    ($name:ident / $set:ident / $proxy:ident, $T2:ident, $T3:ident, $T4:ident: $a:ident, $b:ident, $c:ident) => {
+        $crate::math::swizzle::swizzle!($name, $T2, $T3, $T4: $a, $b, $c);
+
+        /// Assigns a vector to the swizzled components.
+        pub fn $set(&mut self, value: $T3) {
+            self.$a = value.x;
+            self.$b = value.y;
+            self.$c = value.z;
+        }
+
+        /// Returns a proxy to the swizzled components for compound assignments.
+        pub fn $proxy(&mut self) -> $crate::math::Swizzle<'_, Self, $T3> {
+            $crate::math::Swizzle::new(self, Self::$name, Self::$set)
+        }
+    };
+    ($name:ident / $set:ident / $proxy:ident, $T2:ident, $T3:ident, $T4:ident: $a:ident, $b:ident, $c:ident, $d:ident) => {
+        $crate::math::swizzle::swizzle!($name, $T2, $T3, $T4: $a, $b, $c, $d);
+
+        /// Assigns a vector to the swizzled components.
+        pub fn $set(&mut self, value: $T4) {
+            self.$a = value.x;
+            self.$b = value.y;
+            self.$c = value.z;
+            self.$d = value.w;
+        }
+
+        /// Returns a proxy to the swizzled components for compound assignments.
+        pub fn $proxy(&mut self) -> $crate::math::Swizzle<'_, Self, $T4> {
+            $crate::math::Swizzle::new(self, Self::$name, Self::$set)
+        }
+    };
+}
+
+/// Implements every swizzle in the list for `$T`; each under its xyzw name and its rgba alias.
+macro_rules! swizzles {
+    ($T:ident => $T2:ident, $T3:ident, $T4:ident; $(($name:ident $alias:ident $(/ $set:ident $alias_set:ident / $proxy:ident $alias_proxy:ident)?: $($c:ident),+))+) => {
+        impl $T {
+            $(
+                $crate::math::swizzle::swizzle!($name $(/ $set / $proxy)?, $T2, $T3, $T4: $($c),+);
+                $crate::math::swizzle::swizzle!($alias $(/ $alias_set / $alias_proxy)?, $T2, $T3, $T4: $($c),+);
+            )+
+        }
+    };
+}
+
+/// Implements the rgba accessors of the components of `$T`.
+macro_rules! components {
+    ($T:ident: $S:ty; $(($name:ident / $set:ident / $proxy:ident: $c:ident))+) => {
+        impl $T {
+            $(
+                #[doc = concat!("Returns the ", stringify!($c), "-component of the vector.")]
+                pub fn $name(&self) -> $S {
+                    self.$c
+                }
+
+                #[doc = concat!("Assigns the ", stringify!($c), "-component of the vector.")]
+                pub fn $set(&mut self, value: $S) {
+                    self.$c = value;
+                }
+
+                #[doc = concat!("Returns a mutable reference to the ", stringify!($c), "-component of the vector.")]
+                pub fn $proxy(&mut self) -> &mut $S {
+                    &mut self.$c
+                }
+            )+
+        }
+    };
+}
+
+// the swizzles2, swizzles3 and swizzles4 macros listing every swizzle are generated by build.rs.
+include!(concat!(env!("OUT_DIR"), "/swizzles.rs"));
+
+pub(crate) use components;
+pub(crate) use swizzle;
+pub(crate) use swizzles;
+pub(crate) use swizzles2;
+pub(crate) use swizzles3;
+pub(crate) use swizzles4;
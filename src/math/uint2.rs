@@ -0,0 +1,43 @@
+use super::Float2;
+use super::Int2;
+use super::UInt3;
+use super::UInt4;
+use super::f16tof32;
+use super::reinterpret::reinterpret;
+use super::swizzle::swizzles2;
+use super::vector::integer_arithmetic;
+use super::vector::integer_methods;
+use super::vector::unsigned_methods;
+use super::vector::vector_base;
+
+/// Vector containing 2 unsigned integer values.
+/// Arithmetic wraps around on overflow like HLSL instead of panicking.
+#[derive(Copy, Clone, Debug)]
+pub struct UInt2 {
+    /// The x-component of the vector.
+    pub x: u32,
+    /// The y-component of the vector.
+    pub y: u32,
+}
+
+vector_base!(UInt2: u32 { x: 0, y: 1 });
+integer_arithmetic!(UInt2: u32 { x, y });
+integer_methods!(UInt2: u32 { x, y });
+unsigned_methods!(UInt2 { x, y });
+reinterpret!(UInt2 { x, y } => Float2, Int2, UInt2);
+swizzles2!(UInt2: u32 => UInt2, UInt3, UInt4);
+
+impl UInt2 {
+    /// Creates a vector from 2 unsigned integer values.
+    pub fn new(x: u32, y: u32) -> Self {
+        Self { x, y }
+    }
+
+    /// Converts the half precision bits in the lower 16 bits of each component to floating point values.
+    pub fn f16tof32(&self) -> Float2 {
+        Float2 {
+            x: f16tof32(self.x),
+            y: f16tof32(self.y),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use super::Float3;
+use super::Int3;
+use super::UInt2;
+use super::UInt4;
+use super::f16tof32;
+use super::reinterpret::reinterpret;
+use super::swizzle::swizzles3;
+use super::vector::compositions3;
+use super::vector::integer_arithmetic;
+use super::vector::integer_methods;
+use super::vector::unsigned_methods;
+use super::vector::vector_base;
+
+/// Vector containing 3 unsigned integer values.
+/// Arithmetic wraps around on overflow like HLSL instead of panicking.
+#[derive(Copy, Clone, Debug)]
+pub struct UInt3 {
+    /// The x-component of the vector.
+    pub x: u32,
+    /// The y-component of the vector.
+    pub y: u32,
+    /// The z-component of the vector.
+    pub z: u32,
+}
+
+vector_base!(UInt3: u32 { x: 0, y: 1, z: 2 });
+compositions3!(UInt3: u32 => UInt2);
+integer_arithmetic!(UInt3: u32 { x, y, z });
+integer_methods!(UInt3: u32 { x, y, z });
+unsigned_methods!(UInt3 { x, y, z });
+reinterpret!(UInt3 { x, y, z } => Float3, Int3, UInt3);
+swizzles3!(UInt3: u32 => UInt2, UInt3, UInt4);
+
+impl UInt3 {
+    /// Creates a vector from 3 unsigned integer values.
+    pub fn new(x: u32, y: u32, z: u32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Converts the half precision bits in the lower 16 bits of each component to floating point values.
+    pub fn f16tof32(&self) -> Float3 {
+        Float3 {
+            x: f16tof32(self.x),
+            y: f16tof32(self.y),
+            z: f16tof32(self.z),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use super::Float4;
+use super::Int4;
+use super::UInt2;
+use super::UInt3;
+use super::f16tof32;
+use super::packed::pack_bytes;
+use super::reinterpret::reinterpret;
+use super::swizzle::swizzles4;
+use super::vector::compositions4;
+use super::vector::integer_arithmetic;
+use super::vector::integer_methods;
+use super::vector::unsigned_methods;
+use super::vector::vector_base;
+
+/// Vector containing 4 unsigned integer values.
+/// Arithmetic wraps around on overflow like HLSL instead of panicking.
+#[derive(Copy, Clone, Debug)]
+pub struct UInt4 {
+    /// The x-component of the vector.
+    pub x: u32,
+    /// The y-component of the vector.
+    pub y: u32,
+    /// The z-component of the vector.
+    pub z: u32,
+    /// The w-component of the vector.
+    pub w: u32,
+}
+
+vector_base!(UInt4: u32 { x: 0, y: 1, z: 2, w: 3 });
+compositions4!(UInt4: u32 => UInt2, UInt3);
+integer_arithmetic!(UInt4: u32 { x, y, z, w });
+integer_methods!(UInt4: u32 { x, y, z, w });
+unsigned_methods!(UInt4 { x, y, z, w });
+reinterpret!(UInt4 { x, y, z, w } => Float4, Int4, UInt4);
+swizzles4!(UInt4: u32 => UInt2, UInt3, UInt4);
+
+impl UInt4 {
+    /// Creates a vector from 4 unsigned integer values.
+    pub fn new(x: u32, y: u32, z: u32, w: u32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Converts the half precision bits in the lower 16 bits of each component to floating point values.
+    pub fn f16tof32(&self) -> Float4 {
+        Float4 {
+            x: f16tof32(self.x),
+            y: f16tof32(self.y),
+            z: f16tof32(self.z),
+            w: f16tof32(self.w),
+        }
+    }
+
+    /// Packs the lower 8 bits of each component into an unsigned 8-bit packed value; `x` in the lowest byte.
+    pub fn pack_u8(&self) -> u32 {
+        pack_bytes(self.x, self.y, self.z, self.w)
+    }
+}
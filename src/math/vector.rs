@@ -0,0 +1,921 @@
+// Shared implementation of the vector families.
+//
+// Every vector type declares its own struct and constructor, then invokes the macros below to
+// receive the operators and intrinsics of its family. This keeps Float, Double, Half, Int, UInt
+// and Bool vectors in sync without writing every method once per type and width.
+
+/// Scalar constants and helpers needed by the floating point vector families.
+pub(crate) trait Scalar: Copy {
+    const ZERO: Self;
+    const ONE: Self;
+    const TWO: Self;
+    const THREE: Self;
+    const INFINITY: Self;
+
+    /// Splits the value into a mantissa with a magnitude in the range [0.5, 1) and an exponent.
+    /// Zero, infinity and NaN are returned unchanged with an exponent of 0.
+    fn frexp(self) -> (Self, Self);
+
+    /// Computes `self * 2^exponent` with the exponent truncated to an integer.
+    fn ldexp(self, exponent: Self) -> Self;
+}
+
+impl Scalar for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const TWO: Self = 2.0;
+    const THREE: Self = 3.0;
+    const INFINITY: Self = f32::INFINITY;
+
+    fn frexp(self) -> (Self, Self) {
+        if self == 0.0 || !self.is_finite() {
+            return (self, 0.0);
+        }
+        // scale subnormals into the normal range first.
+        let (value, bias) = if self.abs() < f32::MIN_POSITIVE {
+            (self * (2.0f32).powi(25), -25)
+        } else {
+            (self, 0)
+        };
+        let bits = value.to_bits();
+        let exponent = ((bits >> 23) & 0xff) as i32 - 126 + bias;
+        let mantissa = f32::from_bits((bits & !(0xff << 23)) | (126 << 23));
+        (mantissa, exponent as f32)
+    }
+
+    fn ldexp(self, exponent: Self) -> Self {
+        self * (2.0f32).powi(exponent as i32)
+    }
+}
+
+impl Scalar for f64 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const TWO: Self = 2.0;
+    const THREE: Self = 3.0;
+    const INFINITY: Self = f64::INFINITY;
+
+    fn frexp(self) -> (Self, Self) {
+        if self == 0.0 || !self.is_finite() {
+            return (self, 0.0);
+        }
+        // scale subnormals into the normal range first.
+        let (value, bias) = if self.abs() < f64::MIN_POSITIVE {
+            (self * (2.0f64).powi(54), -54)
+        } else {
+            (self, 0)
+        };
+        let bits = value.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as i64 - 1022 + bias;
+        let mantissa = f64::from_bits((bits & !(0x7ff << 52)) | (1022 << 52));
+        (mantissa, exponent as f64)
+    }
+
+    fn ldexp(self, exponent: Self) -> Self {
+        self * (2.0f64).powi(exponent as i32)
+    }
+}
+
+/// Integer division, remainder and shifts following HLSL instead of panicking.
+pub(crate) trait Integer: Copy {
+    /// Divides two numbers; wrapping around on overflow and returning all bits set when dividing by zero.
+    fn div(self, rhs: Self) -> Self;
+
+    /// Computes the remainder; wrapping around on overflow and returning all bits set when dividing by zero.
+    fn rem(self, rhs: Self) -> Self;
+
+    /// Shifts left by the lower 5 bits of `rhs`.
+    fn shl(self, rhs: Self) -> Self;
+
+    /// Shifts right by the lower 5 bits of `rhs`; arithmetic for signed and logical for unsigned numbers.
+    fn shr(self, rhs: Self) -> Self;
+}
+
+impl Integer for i32 {
+    fn div(self, rhs: Self) -> Self {
+        if rhs != 0 { self.wrapping_div(rhs) } else { -1 }
+    }
+
+    fn rem(self, rhs: Self) -> Self {
+        if rhs != 0 { self.wrapping_rem(rhs) } else { -1 }
+    }
+
+    fn shl(self, rhs: Self) -> Self {
+        self.wrapping_shl(rhs as u32)
+    }
+
+    fn shr(self, rhs: Self) -> Self {
+        self.wrapping_shr(rhs as u32)
+    }
+}
+
+impl Integer for u32 {
+    fn div(self, rhs: Self) -> Self {
+        if rhs != 0 {
+            self.wrapping_div(rhs)
+        } else {
+            u32::MAX
+        }
+    }
+
+    fn rem(self, rhs: Self) -> Self {
+        if rhs != 0 {
+            self.wrapping_rem(rhs)
+        } else {
+            u32::MAX
+        }
+    }
+
+    fn shl(self, rhs: Self) -> Self {
+        self.wrapping_shl(rhs)
+    }
+
+    fn shr(self, rhs: Self) -> Self {
+        self.wrapping_shr(rhs)
+    }
+}
+
+/// Implements formatting, equality and conversions from scalars and tuples.
+macro_rules! vector_base {
+    ($T:ident: $S:ty { $($c:ident: $i:tt),+ }) => {
+        impl core::fmt::Display for $T {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{} (", stringify!($T))?;
+                let components = [$(self.$c),+];
+                for (i, component) in components.iter().enumerate() {
+                    if i != 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", component)?;
+                }
+                write!(f, ")")
+            }
+        }
+
+        impl PartialEq for $T {
+            fn eq(&self, rhs: &Self) -> bool {
+                $(self.$c == rhs.$c)&&+
+            }
+        }
+
+        impl From<$S> for $T {
+            fn from(lhs: $S) -> Self {
+                Self { $($c: lhs),+ }
+            }
+        }
+
+        impl From<($($crate::math::vector::scalar!($c, $S)),+)> for $T {
+            fn from(lhs: ($($crate::math::vector::scalar!($c, $S)),+)) -> Self {
+                Self { $($c: lhs.$i),+ }
+            }
+        }
+    };
+}
+
+/// Implements the conversions composing a vector with 3 components from smaller vectors and scalars.
+macro_rules! compositions3 {
+    ($T:ident: $S:ty => $T2:ident) => {
+        impl From<($T2, $S)> for $T {
+            fn from(lhs: ($T2, $S)) -> Self {
+                Self {
+                    x: lhs.0.x,
+                    y: lhs.0.y,
+                    z: lhs.1,
+                }
+            }
+        }
+
+        impl From<($S, $T2)> for $T {
+            fn from(lhs: ($S, $T2)) -> Self {
+                Self {
+                    x: lhs.0,
+                    y: lhs.1.x,
+                    z: lhs.1.y,
+                }
+            }
+        }
+    };
+}
+
+/// Implements the conversions composing a vector with 4 components from smaller vectors and scalars.
+macro_rules! compositions4 {
+    ($T:ident: $S:ty => $T2:ident, $T3:ident) => {
+        impl From<($T3, $S)> for $T {
+            fn from(lhs: ($T3, $S)) -> Self {
+                Self {
+                    x: lhs.0.x,
+                    y: lhs.0.y,
+                    z: lhs.0.z,
+                    w: lhs.1,
+                }
+            }
+        }
+
+        impl From<($S, $T3)> for $T {
+            fn from(lhs: ($S, $T3)) -> Self {
+                Self {
+                    x: lhs.0,
+                    y: lhs.1.x,
+                    z: lhs.1.y,
+                    w: lhs.1.z,
+                }
+            }
+        }
+
+        impl From<($T2, $T2)> for $T {
+            fn from(lhs: ($T2, $T2)) -> Self {
+                Self {
+                    x: lhs.0.x,
+                    y: lhs.0.y,
+                    z: lhs.1.x,
+                    w: lhs.1.y,
+                }
+            }
+        }
+
+        impl From<($T2, $S, $S)> for $T {
+            fn from(lhs: ($T2, $S, $S)) -> Self {
+                Self {
+                    x: lhs.0.x,
+                    y: lhs.0.y,
+                    z: lhs.1,
+                    w: lhs.2,
+                }
+            }
+        }
+
+        impl From<($S, $T2, $S)> for $T {
+            fn from(lhs: ($S, $T2, $S)) -> Self {
+                Self {
+                    x: lhs.0,
+                    y: lhs.1.x,
+                    z: lhs.1.y,
+                    w: lhs.2,
+                }
+            }
+        }
+
+        impl From<($S, $S, $T2)> for $T {
+            fn from(lhs: ($S, $S, $T2)) -> Self {
+                Self {
+                    x: lhs.0,
+                    y: lhs.1,
+                    z: lhs.2.x,
+                    w: lhs.2.y,
+                }
+            }
+        }
+    };
+}
+
+/// Expands to the scalar type once for every component; used to spell out tuple types.
+macro_rules! scalar {
+    ($c:ident, $S:ty) => {
+        $S
+    };
+}
+
+/// Adds up the expressions from left to right.
+macro_rules! sum {
+    ($first:expr $(, $rest:expr)*) => {
+        ($first $(+ $rest)*)
+    };
+}
+
+/// Implements a binary operator and its assignment variant for vectors and scalars.
+macro_rules! vector_operator {
+    ($T:ident: $S:ty { $($c:ident),+ }, $Op:ident::$op:ident, $OpAssign:ident::$op_assign:ident, $f:expr) => {
+        impl core::ops::$Op<Self> for $T {
+            type Output = Self;
+
+            fn $op(self, rhs: Self) -> Self {
+                Self { $($c: $f(self.$c, rhs.$c)),+ }
+            }
+        }
+
+        impl core::ops::$Op<$S> for $T {
+            type Output = Self;
+
+            fn $op(self, rhs: $S) -> Self {
+                Self { $($c: $f(self.$c, rhs)),+ }
+            }
+        }
+
+        impl core::ops::$OpAssign<Self> for $T {
+            fn $op_assign(&mut self, rhs: Self) {
+                $(self.$c = $f(self.$c, rhs.$c);)+
+            }
+        }
+
+        impl core::ops::$OpAssign<$S> for $T {
+            fn $op_assign(&mut self, rhs: $S) {
+                $(self.$c = $f(self.$c, rhs);)+
+            }
+        }
+    };
+}
+
+/// Implements the arithmetic operators of the floating point families.
+macro_rules! float_arithmetic {
+    ($T:ident: $S:ty { $($c:ident),+ }) => {
+        $crate::math::vector::vector_operator!($T: $S { $($c),+ }, Add::add, AddAssign::add_assign, core::ops::Add::add);
+        $crate::math::vector::vector_operator!($T: $S { $($c),+ }, Sub::sub, SubAssign::sub_assign, core::ops::Sub::sub);
+        $crate::math::vector::vector_operator!($T: $S { $($c),+ }, Mul::mul, MulAssign::mul_assign, core::ops::Mul::mul);
+        $crate::math::vector::vector_operator!($T: $S { $($c),+ }, Div::div, DivAssign::div_assign, core::ops::Div::div);
+
+        impl core::ops::Neg for $T {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($c: -self.$c),+ }
+            }
+        }
+    };
+}
+
+/// Implements the arithmetic and bitwise operators of the integer families; wrapping around on overflow.
+macro_rules! integer_arithmetic {
+    ($T:ident: $S:ty { $($c:ident),+ }) => {
+        $crate::math::vector::vector_operator!($T: $S { $($c),+ }, Add::add, AddAssign::add_assign, <$S>::wrapping_add);
+        $crate::math::vector::vector_operator!($T: $S { $($c),+ }, Sub::sub, SubAssign::sub_assign, <$S>::wrapping_sub);
+        $crate::math::vector::vector_operator!($T: $S { $($c),+ }, Mul::mul, MulAssign::mul_assign, <$S>::wrapping_mul);
+        $crate::math::vector::vector_operator!($T: $S { $($c),+ }, Div::div, DivAssign::div_assign, <$S as $crate::math::vector::Integer>::div);
+        $crate::math::vector::vector_operator!($T: $S { $($c),+ }, Rem::rem, RemAssign::rem_assign, <$S as $crate::math::vector::Integer>::rem);
+        $crate::math::vector::vector_operator!($T: $S { $($c),+ }, BitAnd::bitand, BitAndAssign::bitand_assign, core::ops::BitAnd::bitand);
+        $crate::math::vector::vector_operator!($T: $S { $($c),+ }, BitOr::bitor, BitOrAssign::bitor_assign, core::ops::BitOr::bitor);
+        $crate::math::vector::vector_operator!($T: $S { $($c),+ }, BitXor::bitxor, BitXorAssign::bitxor_assign, core::ops::BitXor::bitxor);
+        $crate::math::vector::vector_operator!($T: $S { $($c),+ }, Shl::shl, ShlAssign::shl_assign, <$S as $crate::math::vector::Integer>::shl);
+        $crate::math::vector::vector_operator!($T: $S { $($c),+ }, Shr::shr, ShrAssign::shr_assign, <$S as $crate::math::vector::Integer>::shr);
+
+        impl core::ops::Neg for $T {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($c: self.$c.wrapping_neg()),+ }
+            }
+        }
+
+        impl core::ops::Not for $T {
+            type Output = Self;
+
+            fn not(self) -> Self {
+                Self { $($c: !self.$c),+ }
+            }
+        }
+
+        impl Eq for $T {}
+    };
+}
+
+/// Implements the logical operators of the boolean family.
+macro_rules! bool_arithmetic {
+    ($T:ident { $($c:ident),+ }) => {
+        $crate::math::vector::vector_operator!($T: bool { $($c),+ }, BitAnd::bitand, BitAndAssign::bitand_assign, core::ops::BitAnd::bitand);
+        $crate::math::vector::vector_operator!($T: bool { $($c),+ }, BitOr::bitor, BitOrAssign::bitor_assign, core::ops::BitOr::bitor);
+        $crate::math::vector::vector_operator!($T: bool { $($c),+ }, BitXor::bitxor, BitXorAssign::bitxor_assign, core::ops::BitXor::bitxor);
+
+        impl core::ops::Not for $T {
+            type Output = Self;
+
+            fn not(self) -> Self {
+                Self { $($c: !self.$c),+ }
+            }
+        }
+
+        impl Eq for $T {}
+
+        impl $T {
+            /// Determines if all components of the vector are true.
+            pub fn all(&self) -> bool {
+                $(self.$c)&&+
+            }
+
+            /// Determines if any components of the vector are true.
+            pub fn any(&self) -> bool {
+                $(self.$c)||+
+            }
+        }
+    };
+}
+
+/// Implements the HLSL intrinsics of the floating point families.
+macro_rules! float_methods {
+    ($T:ident: $S:ty, $B:ident { $($c:ident),+ }) => {
+        impl $T {
+            /// Computes the per-component absolute numbers.
+            pub fn abs(&self) -> Self {
+                Self { $($c: self.$c.abs()),+ }
+            }
+
+            /// Computes the per-component arccosine numbers (in radians).
+            /// Each component should be a value within the range of -1 to 1.
+            /// Return values are in the range 0, pi or NaN if the number is outside the range -1 to 1.
+            pub fn acos(&self) -> Self {
+                Self { $($c: self.$c.acos()),+ }
+            }
+
+            /// Determines if all components of the vector are non-zero.
+            pub fn all(&self) -> bool {
+                $(self.$c != <$S as $crate::math::vector::Scalar>::ZERO)&&+
+            }
+
+            /// Determines if any components of the vector are non-zero.
+            pub fn any(&self) -> bool {
+                $(self.$c != <$S as $crate::math::vector::Scalar>::ZERO)||+
+            }
+
+            /// Computes the per-component arcsine numbers (in radians).
+            /// Each component should be a value within the range of -1 to 1.
+            /// Return values are in the range -pi/2 to pi/2 or NaN if the number is outside the range -1 to 1.
+            pub fn asin(&self) -> Self {
+                Self { $($c: self.$c.asin()),+ }
+            }
+
+            /// Computes the per-component arctangent numbers (in radians).
+            /// Return values are in the range -pi/2 to pi/2.
+            pub fn atan(&self) -> Self {
+                Self { $($c: self.$c.atan()),+ }
+            }
+
+            /// Computes the per-component smallest integers greater than or equal to each component.
+            pub fn ceil(&self) -> Self {
+                Self { $($c: self.$c.ceil()),+ }
+            }
+
+            /// Computes the per-component clamped numbers between `min` and `max`.
+            pub fn clamp(&self, min: $S, max: $S) -> Self {
+                Self { $($c: self.$c.clamp(min, max)),+ }
+            }
+
+            /// Discards the current pixel if any component is less than zero, like `clip` in HLSL.
+            /// Returns `Err(Discard)` when the pixel should be discarded.
+            pub fn clip(&self) -> Result<(), $crate::math::Discard> {
+                if $(self.$c < <$S as $crate::math::vector::Scalar>::ZERO)||+ {
+                    Err($crate::math::Discard)
+                } else {
+                    Ok(())
+                }
+            }
+
+            /// Computes the per-component cosine numbers (in radians).
+            pub fn cos(&self) -> Self {
+                Self { $($c: self.$c.cos()),+ }
+            }
+
+            /// Computes the per-component hyperbolic cosine numbers.
+            pub fn cosh(&self) -> Self {
+                Self { $($c: self.$c.cosh()),+ }
+            }
+
+            /// Converts the per-component numbers from radians to degrees.
+            pub fn degrees(&self) -> Self {
+                Self { $($c: self.$c.to_degrees()),+ }
+            }
+
+            /// Computes the distance scalar between two vectors.
+            pub fn distance(&self, rhs: &Self) -> $S {
+                $crate::math::vector::sum!($((self.$c - rhs.$c) * (self.$c - rhs.$c)),+).sqrt()
+            }
+
+            /// Computes the dot product of two vectors.
+            pub fn dot(&self, rhs: &Self) -> $S {
+                $crate::math::vector::sum!($(self.$c * rhs.$c),+)
+            }
+
+            /// Compares each component for `self == rhs`; returning a mask of the results.
+            pub fn eq(&self, rhs: &Self) -> $B {
+                $B { $($c: self.$c == rhs.$c),+ }
+            }
+
+            /// Computes the per-component e^(self), the exponential function.
+            pub fn exp(&self) -> Self {
+                Self { $($c: self.$c.exp()),+ }
+            }
+
+            /// Computes the per-component 2^(self).
+            pub fn exp2(&self) -> Self {
+                Self { $($c: self.$c.exp2()),+ }
+            }
+
+            /// Flips the normal `self` to face against the incident vector `i`.
+            /// Returns `self` if `dot(i, ng)` is negative and `-self` otherwise, including when it is NaN like DXC's ordered compare.
+            pub fn faceforward(&self, i: &Self, ng: &Self) -> Self {
+                if i.dot(ng) < <$S as $crate::math::vector::Scalar>::ZERO {
+                    *self
+                } else {
+                    -*self
+                }
+            }
+
+            /// Computes the per-component largest integers less than or equal to each component.
+            pub fn floor(&self) -> Self {
+                Self { $($c: self.$c.floor()),+ }
+            }
+
+            /// Computes the floating-point remainder of division for each component.
+            pub fn fmod(&self, rhs: &Self) -> Self {
+                Self { $($c: self.$c % rhs.$c),+ }
+            }
+
+            /// Computes the fractional (or decimal) part of each component; which is greater than or equal to 0 and less than 1.
+            pub fn frac(&self) -> Self {
+                Self { $($c: self.$c.fract()),+ }
+            }
+
+            /// Splits each component into a mantissa with a magnitude in the range [0.5, 1) and an exponent.
+            /// Returns `(mantissa, exponent)` such that `self = mantissa * 2^exponent`, like `frexp` in HLSL.
+            pub fn frexp(&self) -> (Self, Self) {
+                $(let $c = $crate::math::vector::Scalar::frexp(self.$c);)+
+                (Self { $($c: $c.0),+ }, Self { $($c: $c.1),+ })
+            }
+
+            /// Compares each component for `self >= rhs`; returning a mask of the results.
+            pub fn ge(&self, rhs: &Self) -> $B {
+                $B { $($c: self.$c >= rhs.$c),+ }
+            }
+
+            /// Compares each component for `self > rhs`; returning a mask of the results.
+            pub fn gt(&self, rhs: &Self) -> $B {
+                $B { $($c: self.$c > rhs.$c),+ }
+            }
+
+            /// Determines for each component if it is neither infinite nor NaN.
+            pub fn isfinite(&self) -> $B {
+                $B { $($c: self.$c.is_finite()),+ }
+            }
+
+            /// Determines for each component if it is positive or negative infinity.
+            pub fn isinf(&self) -> $B {
+                $B { $($c: self.$c.is_infinite()),+ }
+            }
+
+            /// Determines for each component if it is NaN.
+            pub fn isnan(&self) -> $B {
+                $B { $($c: self.$c.is_nan()),+ }
+            }
+
+            /// Computes `value * 2^exponent` for each component of the vector.
+            pub fn ldexp(&self, exponent: &Self) -> Self {
+                Self { $($c: $crate::math::vector::Scalar::ldexp(self.$c, exponent.$c)),+ }
+            }
+
+            /// Compares each component for `self <= rhs`; returning a mask of the results.
+            pub fn le(&self, rhs: &Self) -> $B {
+                $B { $($c: self.$c <= rhs.$c),+ }
+            }
+
+            /// Computes the length scalar between two vectors.
+            pub fn length(&self) -> $S {
+                $crate::math::vector::sum!($(self.$c * self.$c),+).sqrt()
+            }
+
+            /// Computes a linear interpolation between two vectors.
+            pub fn lerp(&self, rhs: &Self, t: $S) -> Self {
+                Self { $($c: self.$c + t * (rhs.$c - self.$c)),+ }
+            }
+
+            /// Computes the natural logarithm (base e) of each component of the vector.
+            pub fn log(&self) -> Self {
+                Self { $($c: self.$c.ln()),+ }
+            }
+
+            /// Computes the natural logarithm (base 10) of each component of the vector.
+            pub fn log10(&self) -> Self {
+                Self { $($c: self.$c.log10()),+ }
+            }
+
+            /// Computes the natural logarithm (base 2) of each component of the vector.
+            pub fn log2(&self) -> Self {
+                Self { $($c: self.$c.log2()),+ }
+            }
+
+            /// Compares each component for `self < rhs`; returning a mask of the results.
+            pub fn lt(&self, rhs: &Self) -> $B {
+                $B { $($c: self.$c < rhs.$c),+ }
+            }
+
+            /// Computes the multiply-add operation: (self * b) + c.
+            pub fn mad(&self, b: &Self, c: &Self) -> Self {
+                Self { $($c: self.$c * b.$c + c.$c),+ }
+            }
+
+            /// Computes the component-wise maximum of two vectors.
+            pub fn max(&self, rhs: &Self) -> Self {
+                Self { $($c: self.$c.max(rhs.$c)),+ }
+            }
+
+            /// Computes the component-wise minimum of two vectors.
+            pub fn min(&self, rhs: &Self) -> Self {
+                Self { $($c: self.$c.min(rhs.$c)),+ }
+            }
+
+            /// Splits each component into its fractional and integer parts, both with the sign of the component.
+            /// Returns `(fractional, integer)` like `modf` in HLSL.
+            pub fn modf(&self) -> (Self, Self) {
+                (Self { $($c: self.$c.fract()),+ }, self.trunc())
+            }
+
+            /// Compares each component for `self != rhs`; returning a mask of the results.
+            pub fn ne(&self, rhs: &Self) -> $B {
+                $B { $($c: self.$c != rhs.$c),+ }
+            }
+
+            /// Computes the normalized vector (unit vector) of `self`.
+            pub fn normalize(&self) -> Self {
+                let length = self.length();
+                if length != <$S as $crate::math::vector::Scalar>::ZERO {
+                    Self { $($c: self.$c / length),+ }
+                } else {
+                    // return the zero vector if the input vector has zero length.
+                    Self::from(<$S as $crate::math::vector::Scalar>::ZERO)
+                }
+            }
+
+            /// Computes the component-wise power: `self^exponent`.
+            pub fn pow(&self, exponent: $S) -> Self {
+                Self { $($c: self.$c.powf(exponent)),+ }
+            }
+
+            /// Converts the per-component numbers from degrees to radians.
+            pub fn radians(&self) -> Self {
+                Self { $($c: self.$c.to_radians()),+ }
+            }
+
+            /// Computes the reciprocal of each component of the vector.
+            /// Equivalent to `1 / self`.
+            pub fn rcp(&self) -> Self {
+                Self { $($c: <$S as $crate::math::vector::Scalar>::ONE / self.$c),+ }
+            }
+
+            /// Computes the reciprocal of each component of the vector.
+            /// Equivalent to `1 / self` and returning 0 when self is 0.
+            pub fn rcp_safe(&self) -> Self {
+                Self {
+                    $($c: if self.$c != <$S as $crate::math::vector::Scalar>::ZERO {
+                        <$S as $crate::math::vector::Scalar>::ONE / self.$c
+                    } else {
+                        <$S as $crate::math::vector::Scalar>::ZERO
+                    }),+
+                }
+            }
+
+            /// Computes the reflection of an incident vector `self` about a normal vector `normal`.
+            pub fn reflect(&self, normal: &Self) -> Self {
+                let dot = self.dot(normal);
+                Self { $($c: self.$c - <$S as $crate::math::vector::Scalar>::TWO * dot * normal.$c),+ }
+            }
+
+            /// Computes the refraction vector for the given incident vector, normal, and refraction index.
+            pub fn refract(&self, normal: &Self, eta: $S) -> Self {
+                let one = <$S as $crate::math::vector::Scalar>::ONE;
+                let dot_n_i = self.dot(normal);
+                let k = one - eta * eta * (one - dot_n_i * dot_n_i);
+                if k < <$S as $crate::math::vector::Scalar>::ZERO {
+                    Self::from(<$S as $crate::math::vector::Scalar>::ZERO)
+                } else {
+                    let scale_i = eta;
+                    let scale_n = eta * dot_n_i + k.sqrt();
+                    Self { $($c: scale_i * self.$c - scale_n * normal.$c),+ }
+                }
+            }
+
+            /// Rounds each component of the vector to the nearest integer.
+            pub fn round(&self) -> Self {
+                Self { $($c: self.$c.round()),+ }
+            }
+
+            /// Computes the reciprocal square root of each component of the vector.
+            pub fn rsqrt(&self) -> Self {
+                Self {
+                    $($c: if self.$c != <$S as $crate::math::vector::Scalar>::ZERO {
+                        <$S as $crate::math::vector::Scalar>::ONE / self.$c.sqrt()
+                    } else {
+                        <$S as $crate::math::vector::Scalar>::INFINITY
+                    }),+
+                }
+            }
+
+            /// Clamps each component of the vector to the range [0, 1].
+            pub fn saturate(&self) -> Self {
+                Self {
+                    $($c: self.$c.clamp(
+                        <$S as $crate::math::vector::Scalar>::ZERO,
+                        <$S as $crate::math::vector::Scalar>::ONE,
+                    )),+
+                }
+            }
+
+            /// Computes the sign of each component of the vector.
+            pub fn sign(&self) -> Self {
+                Self { $($c: self.$c.signum()),+ }
+            }
+
+            /// Computes the per-component sine numbers (in radians).
+            pub fn sin(&self) -> Self {
+                Self { $($c: self.$c.sin()),+ }
+            }
+
+            /// Computes the per-component sine and cosine numbers (in radians).
+            /// Returns `(sin, cos)` like `sincos` in HLSL.
+            pub fn sincos(&self) -> (Self, Self) {
+                (self.sin(), self.cos())
+            }
+
+            /// Computes the per-component hyperbolic sine numbers.
+            pub fn sinh(&self) -> Self {
+                Self { $($c: self.$c.sinh()),+ }
+            }
+
+            /// Performs smoothstep interpolation on each component of the vector.
+            pub fn smoothstep(&self, min: &Self, max: &Self) -> Self {
+                fn smoothstep_component(min: $S, max: $S, value: $S) -> $S {
+                    if value <= min {
+                        <$S as $crate::math::vector::Scalar>::ZERO
+                    } else if value >= max {
+                        <$S as $crate::math::vector::Scalar>::ONE
+                    } else {
+                        let t = (value - min) / (max - min);
+                        t * t * (<$S as $crate::math::vector::Scalar>::THREE - <$S as $crate::math::vector::Scalar>::TWO * t)
+                    }
+                }
+
+                Self { $($c: smoothstep_component(min.$c, max.$c, self.$c)),+ }
+            }
+
+            /// Computes the square root of each component of the vector.
+            pub fn sqrt(&self) -> Self {
+                Self { $($c: self.$c.sqrt()),+ }
+            }
+
+            /// Computes the component-wise step function.
+            /// For each component: returns 0.0 if `self < edge`, else returns 1.0.
+            pub fn step(&self, edge: &Self) -> Self {
+                Self {
+                    $($c: if self.$c < edge.$c {
+                        <$S as $crate::math::vector::Scalar>::ZERO
+                    } else {
+                        <$S as $crate::math::vector::Scalar>::ONE
+                    }),+
+                }
+            }
+
+            /// Computes the per-component tangent numbers (in radians).
+            pub fn tan(&self) -> Self {
+                Self { $($c: self.$c.tan()),+ }
+            }
+
+            /// Computes the per-component hyperbolic tangent numbers.
+            pub fn tanh(&self) -> Self {
+                Self { $($c: self.$c.tanh()),+ }
+            }
+
+            /// Truncates each component of the vector to its integer portion.
+            pub fn trunc(&self) -> Self {
+                Self { $($c: self.$c.trunc()),+ }
+            }
+        }
+    };
+}
+
+/// Implements `Select` for a boolean mask choosing between vectors and broadcast scalars.
+macro_rules! vector_select {
+    ($T:ident: $S:ty, $B:ident { $($c:ident),+ }) => {
+        impl $crate::math::Select<$T, $T> for $B {
+            type Output = $T;
+
+            fn select(self, a: $T, b: $T) -> $T {
+                $T { $($c: if self.$c { a.$c } else { b.$c }),+ }
+            }
+        }
+
+        impl $crate::math::Select<$T, $S> for $B {
+            type Output = $T;
+
+            fn select(self, a: $T, b: $S) -> $T {
+                $T { $($c: if self.$c { a.$c } else { b }),+ }
+            }
+        }
+
+        impl $crate::math::Select<$S, $T> for $B {
+            type Output = $T;
+
+            fn select(self, a: $S, b: $T) -> $T {
+                $T { $($c: if self.$c { a } else { b.$c }),+ }
+            }
+        }
+
+        impl $crate::math::Select<$S, $S> for $B {
+            type Output = $T;
+
+            fn select(self, a: $S, b: $S) -> $T {
+                $T { $($c: if self.$c { a } else { b }),+ }
+            }
+        }
+    };
+}
+
+/// Implements the HLSL intrinsics shared by the signed and unsigned integer families.
+macro_rules! integer_methods {
+    ($T:ident: $S:ty { $($c:ident),+ }) => {
+        impl $T {
+            /// Determines if all components of the vector are non-zero.
+            pub fn all(&self) -> bool {
+                $(self.$c != 0)&&+
+            }
+
+            /// Determines if any components of the vector are non-zero.
+            pub fn any(&self) -> bool {
+                $(self.$c != 0)||+
+            }
+
+            /// Computes the per-component clamped numbers between `min` and `max`.
+            pub fn clamp(&self, min: $S, max: $S) -> Self {
+                Self { $($c: self.$c.clamp(min, max)),+ }
+            }
+
+            /// Computes the dot product of two vectors.
+            pub fn dot(&self, rhs: &Self) -> $S {
+                let mut dot: $S = 0;
+                $(dot = dot.wrapping_add(self.$c.wrapping_mul(rhs.$c));)+
+                dot
+            }
+
+            /// Computes the multiply-add operation: (self * b) + c.
+            pub fn mad(&self, b: &Self, c: &Self) -> Self {
+                Self { $($c: self.$c.wrapping_mul(b.$c).wrapping_add(c.$c)),+ }
+            }
+
+            /// Computes the component-wise maximum of two vectors.
+            pub fn max(&self, rhs: &Self) -> Self {
+                Self { $($c: self.$c.max(rhs.$c)),+ }
+            }
+
+            /// Computes the component-wise minimum of two vectors.
+            pub fn min(&self, rhs: &Self) -> Self {
+                Self { $($c: self.$c.min(rhs.$c)),+ }
+            }
+        }
+    };
+}
+
+/// Implements the HLSL intrinsics of the signed integer family.
+macro_rules! signed_methods {
+    ($T:ident { $($c:ident),+ }) => {
+        impl $T {
+            /// Computes the per-component absolute numbers.
+            /// The absolute value of `i32::MIN` wraps around to `i32::MIN` like HLSL.
+            pub fn abs(&self) -> Self {
+                Self { $($c: self.$c.wrapping_abs()),+ }
+            }
+
+            /// Computes the sign of each component of the vector; returning -1, 0 or 1.
+            pub fn sign(&self) -> Self {
+                Self { $($c: self.$c.signum()),+ }
+            }
+        }
+    };
+}
+
+/// Implements the HLSL bit intrinsics of the unsigned integer family.
+macro_rules! unsigned_methods {
+    ($T:ident { $($c:ident),+ }) => {
+        impl $T {
+            /// Counts the number of bits set in each component.
+            pub fn countbits(&self) -> Self {
+                Self { $($c: self.$c.count_ones()),+ }
+            }
+
+            /// Finds the index of the highest set bit in each component, counting from the least significant bit.
+            /// Returns `u32::MAX` for components that are 0.
+            pub fn firstbithigh(&self) -> Self {
+                Self { $($c: if self.$c != 0 { 31 - self.$c.leading_zeros() } else { u32::MAX }),+ }
+            }
+
+            /// Finds the index of the lowest set bit in each component, counting from the least significant bit.
+            /// Returns `u32::MAX` for components that are 0.
+            pub fn firstbitlow(&self) -> Self {
+                Self { $($c: if self.$c != 0 { self.$c.trailing_zeros() } else { u32::MAX }),+ }
+            }
+
+            /// Reverses the order of the bits in each component.
+            pub fn reversebits(&self) -> Self {
+                Self { $($c: self.$c.reverse_bits()),+ }
+            }
+        }
+    };
+}
+
+pub(crate) use bool_arithmetic;
+pub(crate) use compositions3;
+pub(crate) use compositions4;
+pub(crate) use float_arithmetic;
+pub(crate) use float_methods;
+pub(crate) use integer_arithmetic;
+pub(crate) use integer_methods;
+pub(crate) use scalar;
+pub(crate) use signed_methods;
+pub(crate) use sum;
+pub(crate) use unsigned_methods;
+pub(crate) use vector_base;
+pub(crate) use vector_operator;
+pub(crate) use vector_select;
@@ -0,0 +1,519 @@
+// Emulation of the wave intrinsics for compute shader ports.
+//
+// Every active lane of the wave runs on its own thread: each wave intrinsic publishes the value of
+// the calling lane, waits for the other active lanes and then reduces the values in lane order.
+// Divergent control flow is scoped explicitly with `Wave::branch`: the lanes of a branch are the
+// active lanes of every wave intrinsic called inside it, and the lanes reconverge when it returns.
+// Calls are matched by their number within the innermost branch, so lanes reaching different
+// wave intrinsics without a branch are reported instead of silently combined.
+
+use core::any::Any;
+use core::cell::Cell;
+use core::ops;
+use core::panic::Location;
+use std::collections::HashMap;
+
+use super::Float2;
+use super::Float3;
+use super::Float4;
+use super::Int2;
+use super::Int3;
+use super::Int4;
+use super::UInt2;
+use super::UInt3;
+use super::UInt4;
+use super::lockstep::Lockstep;
+
+/// Values supported by the arithmetic wave intrinsics such as `active_sum` and `prefix_product`.
+/// Integer arithmetic wraps around on overflow like HLSL.
+pub trait WaveNumeric: Copy + Send + PartialEq + 'static {
+    /// Returns the identity of `add`.
+    fn zero() -> Self;
+
+    /// Returns the identity of `mul`.
+    fn one() -> Self;
+
+    /// Adds two values.
+    fn add(self, rhs: Self) -> Self;
+
+    /// Multiplies two values.
+    fn mul(self, rhs: Self) -> Self;
+
+    /// Computes the per-component minimum of two values.
+    fn min(self, rhs: Self) -> Self;
+
+    /// Computes the per-component maximum of two values.
+    fn max(self, rhs: Self) -> Self;
+}
+
+impl WaveNumeric for f32 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+
+    fn mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+
+    fn min(self, rhs: Self) -> Self {
+        f32::min(self, rhs)
+    }
+
+    fn max(self, rhs: Self) -> Self {
+        f32::max(self, rhs)
+    }
+}
+
+impl WaveNumeric for i32 {
+    fn zero() -> Self {
+        0
+    }
+
+    fn one() -> Self {
+        1
+    }
+
+    fn add(self, rhs: Self) -> Self {
+        self.wrapping_add(rhs)
+    }
+
+    fn mul(self, rhs: Self) -> Self {
+        self.wrapping_mul(rhs)
+    }
+
+    fn min(self, rhs: Self) -> Self {
+        Ord::min(self, rhs)
+    }
+
+    fn max(self, rhs: Self) -> Self {
+        Ord::max(self, rhs)
+    }
+}
+
+impl WaveNumeric for u32 {
+    fn zero() -> Self {
+        0
+    }
+
+    fn one() -> Self {
+        1
+    }
+
+    fn add(self, rhs: Self) -> Self {
+        self.wrapping_add(rhs)
+    }
+
+    fn mul(self, rhs: Self) -> Self {
+        self.wrapping_mul(rhs)
+    }
+
+    fn min(self, rhs: Self) -> Self {
+        Ord::min(self, rhs)
+    }
+
+    fn max(self, rhs: Self) -> Self {
+        Ord::max(self, rhs)
+    }
+}
+
+/// Implements `WaveNumeric` for vectors using their operators and intrinsics.
+macro_rules! wave_numeric {
+    ($($T:ident: $zero:expr, $one:expr;)+) => {
+        $(
+            impl WaveNumeric for $T {
+                fn zero() -> Self {
+                    Self::from($zero)
+                }
+
+                fn one() -> Self {
+                    Self::from($one)
+                }
+
+                fn add(self, rhs: Self) -> Self {
+                    self + rhs
+                }
+
+                fn mul(self, rhs: Self) -> Self {
+                    self * rhs
+                }
+
+                fn min(self, rhs: Self) -> Self {
+                    $T::min(&self, &rhs)
+                }
+
+                fn max(self, rhs: Self) -> Self {
+                    $T::max(&self, &rhs)
+                }
+            }
+        )+
+    };
+}
+
+wave_numeric! {
+    Float2: 0.0, 1.0;
+    Float3: 0.0, 1.0;
+    Float4: 0.0, 1.0;
+    Int2: 0, 1;
+    Int3: 0, 1;
+    Int4: 0, 1;
+    UInt2: 0, 1;
+    UInt3: 0, 1;
+    UInt4: 0, 1;
+}
+
+/// State shared by the active lanes of a wave.
+struct WaveShared {
+    lockstep: Lockstep<WaveState>,
+    lanes: Vec<u32>,
+}
+
+/// State of the wave intrinsic calls, protected by the lockstep of the wave.
+struct WaveState {
+    /// The calls in progress, by branch scope and number of the call within the scope.
+    calls: HashMap<(Vec<u32>, u32), WaveCall>,
+    /// The lanes of each branch scope and the lanes that have left it, as masks of thread indices.
+    scopes: HashMap<Vec<u32>, (u64, u64)>,
+}
+
+/// The values published by the lanes for a wave intrinsic call, indexed by thread.
+struct WaveCall {
+    site: &'static Location<'static>,
+    values: Vec<Option<Box<dyn Any + Send>>>,
+    /// The number of lanes that have read the values.
+    read: usize,
+}
+
+/// A lane inside a wave, giving access to the wave intrinsics.
+/// All active lanes must call the wave intrinsics in the same order, like uniform control flow in HLSL.
+/// Divergent control flow around wave intrinsics goes through `branch`; lanes that returned are inactive.
+/// Lanes calling wave intrinsics under a plain `if` panic when they reach different call sites, but a call
+/// from the same site, such as inside a shared helper function, would combine the lanes of both sides.
+pub struct Wave<'a> {
+    lane: u32,
+    index: usize,
+    size: u32,
+    /// The numbers of the `branch` calls this lane is inside, from the outermost.
+    scope: Vec<u32>,
+    /// The number of wave intrinsics this lane has called in its innermost branch.
+    calls: Cell<u32>,
+    shared: &'a WaveShared,
+}
+
+impl Wave<'_> {
+    /// Runs `kernel` on every active lane of a wave with `size` lanes; bit `i` of `active_mask` activates lane `i`.
+    /// Returns the results in lane order with `None` for the inactive lanes.
+    /// Panics if `size` is not 32 or 64.
+    pub fn run<R, F>(size: u32, active_mask: u64, kernel: F) -> Vec<Option<R>>
+    where
+        R: Send,
+        F: Fn(&Wave) -> R + Sync,
+    {
+        assert!(size == 32 || size == 64, "the wave size must be 32 or 64");
+        let active_mask = if size == 32 {
+            active_mask & 0xffffffff
+        } else {
+            active_mask
+        };
+        let lanes: Vec<u32> = (0..size)
+            .filter(|lane| active_mask & (1 << lane) != 0)
+            .collect();
+        let members = (0..lanes.len()).fold(0, |mask, index| mask | (1 << index));
+        let state = WaveState {
+            calls: HashMap::new(),
+            scopes: HashMap::from([(Vec::new(), (members, 0))]),
+        };
+        let shared = WaveShared {
+            lockstep: Lockstep::with_state(lanes.len(), state),
+            lanes,
+        };
+        let results = shared.lockstep.run(|index| {
+            let wave = Wave {
+                lane: shared.lanes[index],
+                index,
+                size,
+                scope: Vec::new(),
+                calls: Cell::new(0),
+                shared: &shared,
+            };
+            kernel(&wave)
+        });
+        let mut output: Vec<Option<R>> = (0..size).map(|_| None).collect();
+        for (lane, result) in shared.lanes.iter().zip(results) {
+            output[*lane as usize] = Some(result);
+        }
+        output
+    }
+
+    /// Determines if all active lanes have the same `value`, like `WaveActiveAllEqual`.
+    #[track_caller]
+    pub fn active_all_equal<T: Copy + Send + PartialEq + 'static>(&self, value: T) -> bool {
+        let values = self.exchange(value);
+        let first = self.read_first(&values);
+        values.iter().flatten().all(|value| *value == first)
+    }
+
+    /// Determines if `condition` is true on all active lanes, like `WaveActiveAllTrue`.
+    #[track_caller]
+    pub fn active_all_true(&self, condition: bool) -> bool {
+        self.exchange(condition)
+            .iter()
+            .flatten()
+            .all(|condition| *condition)
+    }
+
+    /// Determines if `condition` is true on any active lane, like `WaveActiveAnyTrue`.
+    #[track_caller]
+    pub fn active_any_true(&self, condition: bool) -> bool {
+        self.exchange(condition)
+            .iter()
+            .flatten()
+            .any(|condition| *condition)
+    }
+
+    /// Returns a mask of the active lanes where `condition` is true, like `WaveActiveBallot`.
+    /// Lanes 0 to 31 are stored in `x` and lanes 32 to 63 in `y`.
+    #[track_caller]
+    pub fn active_ballot(&self, condition: bool) -> UInt4 {
+        let mask = self.ballot(condition);
+        UInt4::new(mask as u32, (mask >> 32) as u32, 0, 0)
+    }
+
+    /// Computes the bitwise and of `value` over all active lanes, like `WaveActiveBitAnd`.
+    #[track_caller]
+    pub fn active_bit_and<T: WaveNumeric + ops::BitAnd<Output = T>>(&self, value: T) -> T {
+        self.reduce(value, |a, b| a & b)
+    }
+
+    /// Computes the bitwise or of `value` over all active lanes, like `WaveActiveBitOr`.
+    #[track_caller]
+    pub fn active_bit_or<T: WaveNumeric + ops::BitOr<Output = T>>(&self, value: T) -> T {
+        self.reduce(value, |a, b| a | b)
+    }
+
+    /// Computes the bitwise exclusive or of `value` over all active lanes, like `WaveActiveBitXor`.
+    #[track_caller]
+    pub fn active_bit_xor<T: WaveNumeric + ops::BitXor<Output = T>>(&self, value: T) -> T {
+        self.reduce(value, |a, b| a ^ b)
+    }
+
+    /// Counts the active lanes where `condition` is true, like `WaveActiveCountBits`.
+    #[track_caller]
+    pub fn active_count_bits(&self, condition: bool) -> u32 {
+        self.ballot(condition).count_ones()
+    }
+
+    /// Computes the maximum of `value` over all active lanes, like `WaveActiveMax`.
+    #[track_caller]
+    pub fn active_max<T: WaveNumeric>(&self, value: T) -> T {
+        self.reduce(value, T::max)
+    }
+
+    /// Computes the minimum of `value` over all active lanes, like `WaveActiveMin`.
+    #[track_caller]
+    pub fn active_min<T: WaveNumeric>(&self, value: T) -> T {
+        self.reduce(value, T::min)
+    }
+
+    /// Computes the product of `value` over all active lanes, like `WaveActiveProduct`.
+    #[track_caller]
+    pub fn active_product<T: WaveNumeric>(&self, value: T) -> T {
+        self.reduce(value, T::mul)
+    }
+
+    /// Computes the sum of `value` over all active lanes, like `WaveActiveSum`.
+    #[track_caller]
+    pub fn active_sum<T: WaveNumeric>(&self, value: T) -> T {
+        self.reduce(value, T::add)
+    }
+
+    /// Runs `f` on the active lanes where `condition` is true, like an `if` with a divergent condition in HLSL.
+    /// Only those lanes are active in the wave intrinsics called by `f`; the other lanes skip it and return `None`.
+    /// All active lanes must call `branch` like any other wave intrinsic, and reconverge once `f` returns.
+    #[track_caller]
+    pub fn branch<R>(&self, condition: bool, f: impl FnOnce(&Wave) -> R) -> Option<R> {
+        let number = self.calls.get();
+        let values = self.exchange_threads(condition);
+        if !condition {
+            return None;
+        }
+        let members = (0..values.len())
+            .filter(|index| values[*index] == Some(true))
+            .fold(0, |mask, index| mask | (1 << index));
+        let mut scope = self.scope.clone();
+        scope.push(number);
+        self.shared.lockstep.update(|state| {
+            state.scopes.entry(scope.clone()).or_insert((members, 0));
+        });
+        let wave = Wave {
+            lane: self.lane,
+            index: self.index,
+            size: self.size,
+            scope,
+            calls: Cell::new(0),
+            shared: self.shared,
+        };
+        let result = f(&wave);
+        self.shared.lockstep.update(|state| {
+            let (members, left) = state.scopes.get_mut(&wave.scope).unwrap();
+            *left |= 1 << self.index;
+            if left == members {
+                state.scopes.remove(&wave.scope);
+            }
+        });
+        Some(result)
+    }
+
+    /// Determines if this lane is the active lane with the lowest index, like `WaveIsFirstLane`.
+    #[track_caller]
+    pub fn is_first_lane(&self) -> bool {
+        let values = self.exchange(());
+        values.iter().position(Option::is_some) == Some(self.lane as usize)
+    }
+
+    /// Returns the number of lanes in the wave, like `WaveGetLaneCount`.
+    pub fn lane_count(&self) -> u32 {
+        self.size
+    }
+
+    /// Returns the index of this lane within the wave, like `WaveGetLaneIndex`.
+    pub fn lane_index(&self) -> u32 {
+        self.lane
+    }
+
+    /// Counts the active lanes below this lane where `condition` is true, like `WavePrefixCountBits`.
+    #[track_caller]
+    pub fn prefix_count_bits(&self, condition: bool) -> u32 {
+        let below = (1u64 << self.lane) - 1;
+        (self.ballot(condition) & below).count_ones()
+    }
+
+    /// Computes the product of `value` over the active lanes below this lane, like `WavePrefixProduct`.
+    #[track_caller]
+    pub fn prefix_product<T: WaveNumeric>(&self, value: T) -> T {
+        self.prefix(value, T::one(), T::mul)
+    }
+
+    /// Computes the sum of `value` over the active lanes below this lane, like `WavePrefixSum`.
+    #[track_caller]
+    pub fn prefix_sum<T: WaveNumeric>(&self, value: T) -> T {
+        self.prefix(value, T::zero(), T::add)
+    }
+
+    /// Returns `value` of the lane at index `lane`, like `WaveReadLaneAt`.
+    /// Panics if that lane is not active.
+    #[track_caller]
+    pub fn read_lane_at<T: Copy + Send + 'static>(&self, value: T, lane: u32) -> T {
+        let values = self.exchange(value);
+        values[lane as usize].expect("the lane to read must be active")
+    }
+
+    /// Returns `value` of the active lane with the lowest index, like `WaveReadLaneFirst`.
+    #[track_caller]
+    pub fn read_lane_first<T: Copy + Send + 'static>(&self, value: T) -> T {
+        let values = self.exchange(value);
+        self.read_first(&values)
+    }
+
+    /// Returns the mask of the active lanes where `condition` is true.
+    #[track_caller]
+    fn ballot(&self, condition: bool) -> u64 {
+        let values = self.exchange(condition);
+        (0..self.size)
+            .filter(|lane| values[*lane as usize] == Some(true))
+            .fold(0, |mask, lane| mask | (1 << lane))
+    }
+
+    /// Publishes the value of this lane and returns the values of all lanes in lane order.
+    /// Only the lanes of the innermost branch that have not returned are active; the others are `None`.
+    #[track_caller]
+    fn exchange<T: Copy + Send + 'static>(&self, value: T) -> Vec<Option<T>> {
+        let values = self.exchange_threads(value);
+        let mut lanes = vec![None; self.size as usize];
+        for (lane, value) in self.shared.lanes.iter().zip(values) {
+            lanes[*lane as usize] = value;
+        }
+        lanes
+    }
+
+    /// Publishes the value of this lane and returns the values of all threads of the wave in index order.
+    #[track_caller]
+    fn exchange_threads<T: Copy + Send + 'static>(&self, value: T) -> Vec<Option<T>> {
+        let site = Location::caller();
+        let key = (self.scope.clone(), self.calls.get());
+        self.calls.set(key.1 + 1);
+        let count = self.shared.lanes.len();
+        self.shared.lockstep.update(|state| {
+            let call = state.calls.entry(key.clone()).or_insert_with(|| WaveCall {
+                site,
+                values: (0..count).map(|_| None).collect(),
+                read: 0,
+            });
+            assert!(
+                call.site == site,
+                "the lanes of the wave called different wave intrinsics at {} and {}; use Wave::branch for divergent control flow",
+                call.site,
+                site
+            );
+            call.values[self.index] = Some(Box::new(value));
+        });
+        self.shared.lockstep.wait_until(|state, returned| {
+            let (members, left) = state.scopes[&key.0];
+            let call = state.calls.get_mut(&key).unwrap();
+            let pending = (0..count).any(|index| {
+                members & !left & (1 << index) != 0
+                    && !returned[index]
+                    && call.values[index].is_none()
+            });
+            if pending {
+                return None;
+            }
+            let values = call
+                .values
+                .iter()
+                .map(|value| {
+                    value.as_ref().map(|value| {
+                        *value
+                            .downcast_ref::<T>()
+                            .expect("all lanes must call the same wave intrinsic")
+                    })
+                })
+                .collect();
+            call.read += 1;
+            if call.read == call.values.iter().flatten().count() {
+                state.calls.remove(&key);
+            }
+            Some(values)
+        })
+    }
+
+    /// Combines the values of the active lanes below this lane, starting from `identity`.
+    #[track_caller]
+    fn prefix<T: WaveNumeric>(&self, value: T, identity: T, f: impl Fn(T, T) -> T) -> T {
+        let values = self.exchange(value);
+        values[..self.lane as usize]
+            .iter()
+            .flatten()
+            .fold(identity, |a, b| f(a, *b))
+    }
+
+    /// Returns the value of the active lane with the lowest index.
+    fn read_first<T: Copy>(&self, values: &[Option<T>]) -> T {
+        values.iter().flatten().copied().next().unwrap()
+    }
+
+    /// Combines the values of all active lanes in lane order.
+    #[track_caller]
+    fn reduce<T: WaveNumeric>(&self, value: T, f: impl Fn(T, T) -> T) -> T {
+        let values = self.exchange(value);
+        let first = self.read_first(&values);
+        values.iter().flatten().skip(1).fold(first, |a, b| f(a, *b))
+    }
+}
@@ -1,8 +1,122 @@
+#[cfg(test)]
+mod bool2;
+
+#[cfg(test)]
+mod bool3;
+
+#[cfg(test)]
+mod bool4;
+
+#[cfg(test)]
+mod bvh;
+
+#[cfg(test)]
+mod compute;
+
+#[cfg(test)]
+mod construct;
+
+#[cfg(test)]
+mod decompose;
+
+#[cfg(test)]
+mod double2;
+
+#[cfg(test)]
+mod double3;
+
+#[cfg(test)]
+mod double4;
+
 #[cfg(test)]
 mod float2;
 
+#[cfg(test)]
+mod float2x2;
+
+#[cfg(test)]
+mod float2x3;
+
+#[cfg(test)]
+mod float2x4;
+
 #[cfg(test)]
 mod float3;
 
 #[cfg(test)]
-mod float4;
\ No newline at end of file
+mod float3x2;
+
+#[cfg(test)]
+mod float3x3;
+
+#[cfg(test)]
+mod float3x4;
+
+#[cfg(test)]
+mod float4;
+
+#[cfg(test)]
+mod float4x2;
+
+#[cfg(test)]
+mod float4x3;
+
+#[cfg(test)]
+mod float4x4;
+
+#[cfg(test)]
+mod geometry;
+
+#[cfg(test)]
+mod half;
+
+#[cfg(test)]
+mod half2;
+
+#[cfg(test)]
+mod half3;
+
+#[cfg(test)]
+mod half4;
+
+#[cfg(test)]
+mod helpers;
+
+#[cfg(test)]
+mod int2;
+
+#[cfg(test)]
+mod int3;
+
+#[cfg(test)]
+mod int4;
+
+#[cfg(test)]
+mod packed;
+
+#[cfg(test)]
+mod quad;
+
+#[cfg(test)]
+mod quaternion;
+
+#[cfg(test)]
+mod reinterpret;
+
+#[cfg(test)]
+mod select;
+
+#[cfg(test)]
+mod swizzle;
+
+#[cfg(test)]
+mod uint2;
+
+#[cfg(test)]
+mod uint3;
+
+#[cfg(test)]
+mod uint4;
+
+#[cfg(test)]
+mod wave;
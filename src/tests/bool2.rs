@@ -0,0 +1,99 @@
+use crate::math::Bool2;
+use crate::math::Bool3;
+use crate::math::Bool4;
+
+#[test]
+fn bool2_from() {
+    let result = Bool2::new(true, false);
+    assert!(result.x);
+    assert!(!result.y);
+    assert_eq!(Bool2::from(true), Bool2::new(true, true));
+}
+
+#[test]
+fn bool2_ops_bitand() {
+    let a = Bool2::new(true, false);
+    let b = Bool2::new(true, true);
+    assert_eq!(a & b, Bool2::new(true, false));
+    assert_eq!(a & true, Bool2::new(true, false));
+    let mut result = a;
+    result &= b;
+    assert_eq!(result, Bool2::new(true, false));
+}
+
+#[test]
+fn bool2_ops_bitor() {
+    let a = Bool2::new(true, false);
+    let b = Bool2::new(true, true);
+    assert_eq!(a | b, Bool2::new(true, true));
+    assert_eq!(a | true, Bool2::new(true, true));
+    let mut result = a;
+    result |= b;
+    assert_eq!(result, Bool2::new(true, true));
+}
+
+#[test]
+fn bool2_ops_bitxor() {
+    let a = Bool2::new(true, false);
+    let b = Bool2::new(true, true);
+    assert_eq!(a ^ b, Bool2::new(false, true));
+    assert_eq!(a ^ true, Bool2::new(false, true));
+    let mut result = a;
+    result ^= b;
+    assert_eq!(result, Bool2::new(false, true));
+}
+
+#[test]
+fn bool2_ops_not() {
+    let result = !Bool2::new(true, false);
+    assert_eq!(result, Bool2::new(false, true));
+}
+
+#[test]
+fn bool2_all() {
+    assert!(Bool2::from(true).all());
+    assert!(!Bool2::new(true, false).all());
+    assert!(!Bool2::from(false).all());
+}
+
+#[test]
+fn bool2_any() {
+    assert!(Bool2::from(true).any());
+    assert!(Bool2::new(false, true).any());
+    assert!(!Bool2::from(false).any());
+}
+
+#[test]
+fn bool2_swizzle() {
+    let a = Bool2::new(true, false);
+    let x = true;
+    let y = false;
+    assert_eq!(a.xx(), Bool2::new(x, x));
+    assert_eq!(a.xy(), Bool2::new(x, y));
+    assert_eq!(a.yx(), Bool2::new(y, x));
+    assert_eq!(a.yy(), Bool2::new(y, y));
+    assert_eq!(a.xxx(), Bool3::new(x, x, x));
+    assert_eq!(a.xxy(), Bool3::new(x, x, y));
+    assert_eq!(a.xyx(), Bool3::new(x, y, x));
+    assert_eq!(a.xyy(), Bool3::new(x, y, y));
+    assert_eq!(a.yxx(), Bool3::new(y, x, x));
+    assert_eq!(a.yxy(), Bool3::new(y, x, y));
+    assert_eq!(a.yyx(), Bool3::new(y, y, x));
+    assert_eq!(a.yyy(), Bool3::new(y, y, y));
+    assert_eq!(a.xxxx(), Bool4::new(x, x, x, x));
+    assert_eq!(a.xxxy(), Bool4::new(x, x, x, y));
+    assert_eq!(a.xxyx(), Bool4::new(x, x, y, x));
+    assert_eq!(a.xxyy(), Bool4::new(x, x, y, y));
+    assert_eq!(a.xyxx(), Bool4::new(x, y, x, x));
+    assert_eq!(a.xyxy(), Bool4::new(x, y, x, y));
+    assert_eq!(a.xyyx(), Bool4::new(x, y, y, x));
+    assert_eq!(a.xyyy(), Bool4::new(x, y, y, y));
+    assert_eq!(a.yxxx(), Bool4::new(y, x, x, x));
+    assert_eq!(a.yxxy(), Bool4::new(y, x, x, y));
+    assert_eq!(a.yxyx(), Bool4::new(y, x, y, x));
+    assert_eq!(a.yxyy(), Bool4::new(y, x, y, y));
+    assert_eq!(a.yyxx(), Bool4::new(y, y, x, x));
+    assert_eq!(a.yyxy(), Bool4::new(y, y, x, y));
+    assert_eq!(a.yyyx(), Bool4::new(y, y, y, x));
+    assert_eq!(a.yyyy(), Bool4::new(y, y, y, y));
+}
@@ -0,0 +1,190 @@
+use crate::math::Bool2;
+use crate::math::Bool3;
+use crate::math::Bool4;
+
+#[test]
+fn bool3_from() {
+    let result = Bool3::new(true, false, true);
+    assert!(result.x);
+    assert!(!result.y);
+    assert!(result.z);
+    assert_eq!(Bool3::from(true), Bool3::new(true, true, true));
+}
+
+#[test]
+fn bool3_ops_bitand() {
+    let a = Bool3::new(true, false, true);
+    let b = Bool3::new(true, true, false);
+    assert_eq!(a & b, Bool3::new(true, false, false));
+    assert_eq!(a & true, Bool3::new(true, false, true));
+    let mut result = a;
+    result &= b;
+    assert_eq!(result, Bool3::new(true, false, false));
+}
+
+#[test]
+fn bool3_ops_bitor() {
+    let a = Bool3::new(true, false, true);
+    let b = Bool3::new(true, true, false);
+    assert_eq!(a | b, Bool3::new(true, true, true));
+    assert_eq!(a | true, Bool3::new(true, true, true));
+    let mut result = a;
+    result |= b;
+    assert_eq!(result, Bool3::new(true, true, true));
+}
+
+#[test]
+fn bool3_ops_bitxor() {
+    let a = Bool3::new(true, false, true);
+    let b = Bool3::new(true, true, false);
+    assert_eq!(a ^ b, Bool3::new(false, true, true));
+    assert_eq!(a ^ true, Bool3::new(false, true, false));
+    let mut result = a;
+    result ^= b;
+    assert_eq!(result, Bool3::new(false, true, true));
+}
+
+#[test]
+fn bool3_ops_not() {
+    let result = !Bool3::new(true, false, true);
+    assert_eq!(result, Bool3::new(false, true, false));
+}
+
+#[test]
+fn bool3_all() {
+    assert!(Bool3::from(true).all());
+    assert!(!Bool3::new(true, false, true).all());
+    assert!(!Bool3::from(false).all());
+}
+
+#[test]
+fn bool3_any() {
+    assert!(Bool3::from(true).any());
+    assert!(Bool3::new(false, false, true).any());
+    assert!(!Bool3::from(false).any());
+}
+
+#[test]
+fn bool3_swizzle() {
+    let a = Bool3::new(true, false, true);
+    let x = true;
+    let y = false;
+    let z = true;
+    assert_eq!(a.xx(), Bool2::new(x, x));
+    assert_eq!(a.xy(), Bool2::new(x, y));
+    assert_eq!(a.xz(), Bool2::new(x, z));
+    assert_eq!(a.yx(), Bool2::new(y, x));
+    assert_eq!(a.yy(), Bool2::new(y, y));
+    assert_eq!(a.yz(), Bool2::new(y, z));
+    assert_eq!(a.zx(), Bool2::new(z, x));
+    assert_eq!(a.zy(), Bool2::new(z, y));
+    assert_eq!(a.zz(), Bool2::new(z, z));
+    assert_eq!(a.xxx(), Bool3::new(x, x, x));
+    assert_eq!(a.xxy(), Bool3::new(x, x, y));
+    assert_eq!(a.xxz(), Bool3::new(x, x, z));
+    assert_eq!(a.xyx(), Bool3::new(x, y, x));
+    assert_eq!(a.xyy(), Bool3::new(x, y, y));
+    assert_eq!(a.xyz(), Bool3::new(x, y, z));
+    assert_eq!(a.xzx(), Bool3::new(x, z, x));
+    assert_eq!(a.xzy(), Bool3::new(x, z, y));
+    assert_eq!(a.xzz(), Bool3::new(x, z, z));
+    assert_eq!(a.yxx(), Bool3::new(y, x, x));
+    assert_eq!(a.yxy(), Bool3::new(y, x, y));
+    assert_eq!(a.yxz(), Bool3::new(y, x, z));
+    assert_eq!(a.yyx(), Bool3::new(y, y, x));
+    assert_eq!(a.yyy(), Bool3::new(y, y, y));
+    assert_eq!(a.yyz(), Bool3::new(y, y, z));
+    assert_eq!(a.yzx(), Bool3::new(y, z, x));
+    assert_eq!(a.yzy(), Bool3::new(y, z, y));
+    assert_eq!(a.yzz(), Bool3::new(y, z, z));
+    assert_eq!(a.zxx(), Bool3::new(z, x, x));
+    assert_eq!(a.zxy(), Bool3::new(z, x, y));
+    assert_eq!(a.zxz(), Bool3::new(z, x, z));
+    assert_eq!(a.zyx(), Bool3::new(z, y, x));
+    assert_eq!(a.zyy(), Bool3::new(z, y, y));
+    assert_eq!(a.zyz(), Bool3::new(z, y, z));
+    assert_eq!(a.zzx(), Bool3::new(z, z, x));
+    assert_eq!(a.zzy(), Bool3::new(z, z, y));
+    assert_eq!(a.zzz(), Bool3::new(z, z, z));
+    assert_eq!(a.xxxx(), Bool4::new(x, x, x, x));
+    assert_eq!(a.xxxy(), Bool4::new(x, x, x, y));
+    assert_eq!(a.xxxz(), Bool4::new(x, x, x, z));
+    assert_eq!(a.xxyx(), Bool4::new(x, x, y, x));
+    assert_eq!(a.xxyy(), Bool4::new(x, x, y, y));
+    assert_eq!(a.xxyz(), Bool4::new(x, x, y, z));
+    assert_eq!(a.xxzx(), Bool4::new(x, x, z, x));
+    assert_eq!(a.xxzy(), Bool4::new(x, x, z, y));
+    assert_eq!(a.xxzz(), Bool4::new(x, x, z, z));
+    assert_eq!(a.xyxx(), Bool4::new(x, y, x, x));
+    assert_eq!(a.xyxy(), Bool4::new(x, y, x, y));
+    assert_eq!(a.xyxz(), Bool4::new(x, y, x, z));
+    assert_eq!(a.xyyx(), Bool4::new(x, y, y, x));
+    assert_eq!(a.xyyy(), Bool4::new(x, y, y, y));
+    assert_eq!(a.xyyz(), Bool4::new(x, y, y, z));
+    assert_eq!(a.xyzx(), Bool4::new(x, y, z, x));
+    assert_eq!(a.xyzy(), Bool4::new(x, y, z, y));
+    assert_eq!(a.xyzz(), Bool4::new(x, y, z, z));
+    assert_eq!(a.xzxx(), Bool4::new(x, z, x, x));
+    assert_eq!(a.xzxy(), Bool4::new(x, z, x, y));
+    assert_eq!(a.xzxz(), Bool4::new(x, z, x, z));
+    assert_eq!(a.xzyx(), Bool4::new(x, z, y, x));
+    assert_eq!(a.xzyy(), Bool4::new(x, z, y, y));
+    assert_eq!(a.xzyz(), Bool4::new(x, z, y, z));
+    assert_eq!(a.xzzx(), Bool4::new(x, z, z, x));
+    assert_eq!(a.xzzy(), Bool4::new(x, z, z, y));
+    assert_eq!(a.xzzz(), Bool4::new(x, z, z, z));
+    assert_eq!(a.yxxx(), Bool4::new(y, x, x, x));
+    assert_eq!(a.yxxy(), Bool4::new(y, x, x, y));
+    assert_eq!(a.yxxz(), Bool4::new(y, x, x, z));
+    assert_eq!(a.yxyx(), Bool4::new(y, x, y, x));
+    assert_eq!(a.yxyy(), Bool4::new(y, x, y, y));
+    assert_eq!(a.yxyz(), Bool4::new(y, x, y, z));
+    assert_eq!(a.yxzx(), Bool4::new(y, x, z, x));
+    assert_eq!(a.yxzy(), Bool4::new(y, x, z, y));
+    assert_eq!(a.yxzz(), Bool4::new(y, x, z, z));
+    assert_eq!(a.yyxx(), Bool4::new(y, y, x, x));
+    assert_eq!(a.yyxy(), Bool4::new(y, y, x, y));
+    assert_eq!(a.yyxz(), Bool4::new(y, y, x, z));
+    assert_eq!(a.yyyx(), Bool4::new(y, y, y, x));
+    assert_eq!(a.yyyy(), Bool4::new(y, y, y, y));
+    assert_eq!(a.yyyz(), Bool4::new(y, y, y, z));
+    assert_eq!(a.yyzx(), Bool4::new(y, y, z, x));
+    assert_eq!(a.yyzy(), Bool4::new(y, y, z, y));
+    assert_eq!(a.yyzz(), Bool4::new(y, y, z, z));
+    assert_eq!(a.yzxx(), Bool4::new(y, z, x, x));
+    assert_eq!(a.yzxy(), Bool4::new(y, z, x, y));
+    assert_eq!(a.yzxz(), Bool4::new(y, z, x, z));
+    assert_eq!(a.yzyx(), Bool4::new(y, z, y, x));
+    assert_eq!(a.yzyy(), Bool4::new(y, z, y, y));
+    assert_eq!(a.yzyz(), Bool4::new(y, z, y, z));
+    assert_eq!(a.yzzx(), Bool4::new(y, z, z, x));
+    assert_eq!(a.yzzy(), Bool4::new(y, z, z, y));
+    assert_eq!(a.yzzz(), Bool4::new(y, z, z, z));
+    assert_eq!(a.zxxx(), Bool4::new(z, x, x, x));
+    assert_eq!(a.zxxy(), Bool4::new(z, x, x, y));
+    assert_eq!(a.zxxz(), Bool4::new(z, x, x, z));
+    assert_eq!(a.zxyx(), Bool4::new(z, x, y, x));
+    assert_eq!(a.zxyy(), Bool4::new(z, x, y, y));
+    assert_eq!(a.zxyz(), Bool4::new(z, x, y, z));
+    assert_eq!(a.zxzx(), Bool4::new(z, x, z, x));
+    assert_eq!(a.zxzy(), Bool4::new(z, x, z, y));
+    assert_eq!(a.zxzz(), Bool4::new(z, x, z, z));
+    assert_eq!(a.zyxx(), Bool4::new(z, y, x, x));
+    assert_eq!(a.zyxy(), Bool4::new(z, y, x, y));
+    assert_eq!(a.zyxz(), Bool4::new(z, y, x, z));
+    assert_eq!(a.zyyx(), Bool4::new(z, y, y, x));
+    assert_eq!(a.zyyy(), Bool4::new(z, y, y, y));
+    assert_eq!(a.zyyz(), Bool4::new(z, y, y, z));
+    assert_eq!(a.zyzx(), Bool4::new(z, y, z, x));
+    assert_eq!(a.zyzy(), Bool4::new(z, y, z, y));
+    assert_eq!(a.zyzz(), Bool4::new(z, y, z, z));
+    assert_eq!(a.zzxx(), Bool4::new(z, z, x, x));
+    assert_eq!(a.zzxy(), Bool4::new(z, z, x, y));
+    assert_eq!(a.zzxz(), Bool4::new(z, z, x, z));
+    assert_eq!(a.zzyx(), Bool4::new(z, z, y, x));
+    assert_eq!(a.zzyy(), Bool4::new(z, z, y, y));
+    assert_eq!(a.zzyz(), Bool4::new(z, z, y, z));
+    assert_eq!(a.zzzx(), Bool4::new(z, z, z, x));
+    assert_eq!(a.zzzy(), Bool4::new(z, z, z, y));
+    assert_eq!(a.zzzz(), Bool4::new(z, z, z, z));
+}
@@ -0,0 +1,411 @@
+use crate::math::Bool2;
+use crate::math::Bool3;
+use crate::math::Bool4;
+
+#[test]
+fn bool4_from() {
+    let result = Bool4::new(true, false, true, false);
+    assert!(result.x);
+    assert!(!result.y);
+    assert!(result.z);
+    assert!(!result.w);
+    assert_eq!(Bool4::from(true), Bool4::new(true, true, true, true));
+}
+
+#[test]
+fn bool4_ops_bitand() {
+    let a = Bool4::new(true, false, true, false);
+    let b = Bool4::new(true, true, false, false);
+    assert_eq!(a & b, Bool4::new(true, false, false, false));
+    assert_eq!(a & true, Bool4::new(true, false, true, false));
+    let mut result = a;
+    result &= b;
+    assert_eq!(result, Bool4::new(true, false, false, false));
+}
+
+#[test]
+fn bool4_ops_bitor() {
+    let a = Bool4::new(true, false, true, false);
+    let b = Bool4::new(true, true, false, false);
+    assert_eq!(a | b, Bool4::new(true, true, true, false));
+    assert_eq!(a | true, Bool4::new(true, true, true, true));
+    let mut result = a;
+    result |= b;
+    assert_eq!(result, Bool4::new(true, true, true, false));
+}
+
+#[test]
+fn bool4_ops_bitxor() {
+    let a = Bool4::new(true, false, true, false);
+    let b = Bool4::new(true, true, false, false);
+    assert_eq!(a ^ b, Bool4::new(false, true, true, false));
+    assert_eq!(a ^ true, Bool4::new(false, true, false, true));
+    let mut result = a;
+    result ^= b;
+    assert_eq!(result, Bool4::new(false, true, true, false));
+}
+
+#[test]
+fn bool4_ops_not() {
+    let result = !Bool4::new(true, false, true, false);
+    assert_eq!(result, Bool4::new(false, true, false, true));
+}
+
+#[test]
+fn bool4_all() {
+    assert!(Bool4::from(true).all());
+    assert!(!Bool4::new(true, false, true, false).all());
+    assert!(!Bool4::from(false).all());
+}
+
+#[test]
+fn bool4_any() {
+    assert!(Bool4::from(true).any());
+    assert!(Bool4::new(false, false, false, true).any());
+    assert!(!Bool4::from(false).any());
+}
+
+#[test]
+fn bool4_swizzle() {
+    let a = Bool4::new(true, false, true, false);
+    let x = true;
+    let y = false;
+    let z = true;
+    let w = false;
+    assert_eq!(a.xx(), Bool2::new(x, x));
+    assert_eq!(a.xy(), Bool2::new(x, y));
+    assert_eq!(a.xz(), Bool2::new(x, z));
+    assert_eq!(a.xw(), Bool2::new(x, w));
+    assert_eq!(a.yx(), Bool2::new(y, x));
+    assert_eq!(a.yy(), Bool2::new(y, y));
+    assert_eq!(a.yz(), Bool2::new(y, z));
+    assert_eq!(a.yw(), Bool2::new(y, w));
+    assert_eq!(a.zx(), Bool2::new(z, x));
+    assert_eq!(a.zy(), Bool2::new(z, y));
+    assert_eq!(a.zz(), Bool2::new(z, z));
+    assert_eq!(a.zw(), Bool2::new(z, w));
+    assert_eq!(a.wx(), Bool2::new(w, x));
+    assert_eq!(a.wy(), Bool2::new(w, y));
+    assert_eq!(a.wz(), Bool2::new(w, z));
+    assert_eq!(a.ww(), Bool2::new(w, w));
+    assert_eq!(a.xxx(), Bool3::new(x, x, x));
+    assert_eq!(a.xxy(), Bool3::new(x, x, y));
+    assert_eq!(a.xxz(), Bool3::new(x, x, z));
+    assert_eq!(a.xxw(), Bool3::new(x, x, w));
+    assert_eq!(a.xyx(), Bool3::new(x, y, x));
+    assert_eq!(a.xyy(), Bool3::new(x, y, y));
+    assert_eq!(a.xyz(), Bool3::new(x, y, z));
+    assert_eq!(a.xyw(), Bool3::new(x, y, w));
+    assert_eq!(a.xzx(), Bool3::new(x, z, x));
+    assert_eq!(a.xzy(), Bool3::new(x, z, y));
+    assert_eq!(a.xzz(), Bool3::new(x, z, z));
+    assert_eq!(a.xzw(), Bool3::new(x, z, w));
+    assert_eq!(a.xwx(), Bool3::new(x, w, x));
+    assert_eq!(a.xwy(), Bool3::new(x, w, y));
+    assert_eq!(a.xwz(), Bool3::new(x, w, z));
+    assert_eq!(a.xww(), Bool3::new(x, w, w));
+    assert_eq!(a.yxx(), Bool3::new(y, x, x));
+    assert_eq!(a.yxy(), Bool3::new(y, x, y));
+    assert_eq!(a.yxz(), Bool3::new(y, x, z));
+    assert_eq!(a.yxw(), Bool3::new(y, x, w));
+    assert_eq!(a.yyx(), Bool3::new(y, y, x));
+    assert_eq!(a.yyy(), Bool3::new(y, y, y));
+    assert_eq!(a.yyz(), Bool3::new(y, y, z));
+    assert_eq!(a.yyw(), Bool3::new(y, y, w));
+    assert_eq!(a.yzx(), Bool3::new(y, z, x));
+    assert_eq!(a.yzy(), Bool3::new(y, z, y));
+    assert_eq!(a.yzz(), Bool3::new(y, z, z));
+    assert_eq!(a.yzw(), Bool3::new(y, z, w));
+    assert_eq!(a.ywx(), Bool3::new(y, w, x));
+    assert_eq!(a.ywy(), Bool3::new(y, w, y));
+    assert_eq!(a.ywz(), Bool3::new(y, w, z));
+    assert_eq!(a.yww(), Bool3::new(y, w, w));
+    assert_eq!(a.zxx(), Bool3::new(z, x, x));
+    assert_eq!(a.zxy(), Bool3::new(z, x, y));
+    assert_eq!(a.zxz(), Bool3::new(z, x, z));
+    assert_eq!(a.zxw(), Bool3::new(z, x, w));
+    assert_eq!(a.zyx(), Bool3::new(z, y, x));
+    assert_eq!(a.zyy(), Bool3::new(z, y, y));
+    assert_eq!(a.zyz(), Bool3::new(z, y, z));
+    assert_eq!(a.zyw(), Bool3::new(z, y, w));
+    assert_eq!(a.zzx(), Bool3::new(z, z, x));
+    assert_eq!(a.zzy(), Bool3::new(z, z, y));
+    assert_eq!(a.zzz(), Bool3::new(z, z, z));
+    assert_eq!(a.zzw(), Bool3::new(z, z, w));
+    assert_eq!(a.zwx(), Bool3::new(z, w, x));
+    assert_eq!(a.zwy(), Bool3::new(z, w, y));
+    assert_eq!(a.zwz(), Bool3::new(z, w, z));
+    assert_eq!(a.zww(), Bool3::new(z, w, w));
+    assert_eq!(a.wxx(), Bool3::new(w, x, x));
+    assert_eq!(a.wxy(), Bool3::new(w, x, y));
+    assert_eq!(a.wxz(), Bool3::new(w, x, z));
+    assert_eq!(a.wxw(), Bool3::new(w, x, w));
+    assert_eq!(a.wyx(), Bool3::new(w, y, x));
+    assert_eq!(a.wyy(), Bool3::new(w, y, y));
+    assert_eq!(a.wyz(), Bool3::new(w, y, z));
+    assert_eq!(a.wyw(), Bool3::new(w, y, w));
+    assert_eq!(a.wzx(), Bool3::new(w, z, x));
+    assert_eq!(a.wzy(), Bool3::new(w, z, y));
+    assert_eq!(a.wzz(), Bool3::new(w, z, z));
+    assert_eq!(a.wzw(), Bool3::new(w, z, w));
+    assert_eq!(a.wwx(), Bool3::new(w, w, x));
+    assert_eq!(a.wwy(), Bool3::new(w, w, y));
+    assert_eq!(a.wwz(), Bool3::new(w, w, z));
+    assert_eq!(a.www(), Bool3::new(w, w, w));
+    assert_eq!(a.xxxx(), Bool4::new(x, x, x, x));
+    assert_eq!(a.xxxy(), Bool4::new(x, x, x, y));
+    assert_eq!(a.xxxz(), Bool4::new(x, x, x, z));
+    assert_eq!(a.xxxw(), Bool4::new(x, x, x, w));
+    assert_eq!(a.xxyx(), Bool4::new(x, x, y, x));
+    assert_eq!(a.xxyy(), Bool4::new(x, x, y, y));
+    assert_eq!(a.xxyz(), Bool4::new(x, x, y, z));
+    assert_eq!(a.xxyw(), Bool4::new(x, x, y, w));
+    assert_eq!(a.xxzx(), Bool4::new(x, x, z, x));
+    assert_eq!(a.xxzy(), Bool4::new(x, x, z, y));
+    assert_eq!(a.xxzz(), Bool4::new(x, x, z, z));
+    assert_eq!(a.xxzw(), Bool4::new(x, x, z, w));
+    assert_eq!(a.xxwx(), Bool4::new(x, x, w, x));
+    assert_eq!(a.xxwy(), Bool4::new(x, x, w, y));
+    assert_eq!(a.xxwz(), Bool4::new(x, x, w, z));
+    assert_eq!(a.xxww(), Bool4::new(x, x, w, w));
+    assert_eq!(a.xyxx(), Bool4::new(x, y, x, x));
+    assert_eq!(a.xyxy(), Bool4::new(x, y, x, y));
+    assert_eq!(a.xyxz(), Bool4::new(x, y, x, z));
+    assert_eq!(a.xyxw(), Bool4::new(x, y, x, w));
+    assert_eq!(a.xyyx(), Bool4::new(x, y, y, x));
+    assert_eq!(a.xyyy(), Bool4::new(x, y, y, y));
+    assert_eq!(a.xyyz(), Bool4::new(x, y, y, z));
+    assert_eq!(a.xyyw(), Bool4::new(x, y, y, w));
+    assert_eq!(a.xyzx(), Bool4::new(x, y, z, x));
+    assert_eq!(a.xyzy(), Bool4::new(x, y, z, y));
+    assert_eq!(a.xyzz(), Bool4::new(x, y, z, z));
+    assert_eq!(a.xyzw(), Bool4::new(x, y, z, w));
+    assert_eq!(a.xywx(), Bool4::new(x, y, w, x));
+    assert_eq!(a.xywy(), Bool4::new(x, y, w, y));
+    assert_eq!(a.xywz(), Bool4::new(x, y, w, z));
+    assert_eq!(a.xyww(), Bool4::new(x, y, w, w));
+    assert_eq!(a.xzxx(), Bool4::new(x, z, x, x));
+    assert_eq!(a.xzxy(), Bool4::new(x, z, x, y));
+    assert_eq!(a.xzxz(), Bool4::new(x, z, x, z));
+    assert_eq!(a.xzxw(), Bool4::new(x, z, x, w));
+    assert_eq!(a.xzyx(), Bool4::new(x, z, y, x));
+    assert_eq!(a.xzyy(), Bool4::new(x, z, y, y));
+    assert_eq!(a.xzyz(), Bool4::new(x, z, y, z));
+    assert_eq!(a.xzyw(), Bool4::new(x, z, y, w));
+    assert_eq!(a.xzzx(), Bool4::new(x, z, z, x));
+    assert_eq!(a.xzzy(), Bool4::new(x, z, z, y));
+    assert_eq!(a.xzzz(), Bool4::new(x, z, z, z));
+    assert_eq!(a.xzzw(), Bool4::new(x, z, z, w));
+    assert_eq!(a.xzwx(), Bool4::new(x, z, w, x));
+    assert_eq!(a.xzwy(), Bool4::new(x, z, w, y));
+    assert_eq!(a.xzwz(), Bool4::new(x, z, w, z));
+    assert_eq!(a.xzww(), Bool4::new(x, z, w, w));
+    assert_eq!(a.xwxx(), Bool4::new(x, w, x, x));
+    assert_eq!(a.xwxy(), Bool4::new(x, w, x, y));
+    assert_eq!(a.xwxz(), Bool4::new(x, w, x, z));
+    assert_eq!(a.xwxw(), Bool4::new(x, w, x, w));
+    assert_eq!(a.xwyx(), Bool4::new(x, w, y, x));
+    assert_eq!(a.xwyy(), Bool4::new(x, w, y, y));
+    assert_eq!(a.xwyz(), Bool4::new(x, w, y, z));
+    assert_eq!(a.xwyw(), Bool4::new(x, w, y, w));
+    assert_eq!(a.xwzx(), Bool4::new(x, w, z, x));
+    assert_eq!(a.xwzy(), Bool4::new(x, w, z, y));
+    assert_eq!(a.xwzz(), Bool4::new(x, w, z, z));
+    assert_eq!(a.xwzw(), Bool4::new(x, w, z, w));
+    assert_eq!(a.xwwx(), Bool4::new(x, w, w, x));
+    assert_eq!(a.xwwy(), Bool4::new(x, w, w, y));
+    assert_eq!(a.xwwz(), Bool4::new(x, w, w, z));
+    assert_eq!(a.xwww(), Bool4::new(x, w, w, w));
+    assert_eq!(a.yxxx(), Bool4::new(y, x, x, x));
+    assert_eq!(a.yxxy(), Bool4::new(y, x, x, y));
+    assert_eq!(a.yxxz(), Bool4::new(y, x, x, z));
+    assert_eq!(a.yxxw(), Bool4::new(y, x, x, w));
+    assert_eq!(a.yxyx(), Bool4::new(y, x, y, x));
+    assert_eq!(a.yxyy(), Bool4::new(y, x, y, y));
+    assert_eq!(a.yxyz(), Bool4::new(y, x, y, z));
+    assert_eq!(a.yxyw(), Bool4::new(y, x, y, w));
+    assert_eq!(a.yxzx(), Bool4::new(y, x, z, x));
+    assert_eq!(a.yxzy(), Bool4::new(y, x, z, y));
+    assert_eq!(a.yxzz(), Bool4::new(y, x, z, z));
+    assert_eq!(a.yxzw(), Bool4::new(y, x, z, w));
+    assert_eq!(a.yxwx(), Bool4::new(y, x, w, x));
+    assert_eq!(a.yxwy(), Bool4::new(y, x, w, y));
+    assert_eq!(a.yxwz(), Bool4::new(y, x, w, z));
+    assert_eq!(a.yxww(), Bool4::new(y, x, w, w));
+    assert_eq!(a.yyxx(), Bool4::new(y, y, x, x));
+    assert_eq!(a.yyxy(), Bool4::new(y, y, x, y));
+    assert_eq!(a.yyxz(), Bool4::new(y, y, x, z));
+    assert_eq!(a.yyxw(), Bool4::new(y, y, x, w));
+    assert_eq!(a.yyyx(), Bool4::new(y, y, y, x));
+    assert_eq!(a.yyyy(), Bool4::new(y, y, y, y));
+    assert_eq!(a.yyyz(), Bool4::new(y, y, y, z));
+    assert_eq!(a.yyyw(), Bool4::new(y, y, y, w));
+    assert_eq!(a.yyzx(), Bool4::new(y, y, z, x));
+    assert_eq!(a.yyzy(), Bool4::new(y, y, z, y));
+    assert_eq!(a.yyzz(), Bool4::new(y, y, z, z));
+    assert_eq!(a.yyzw(), Bool4::new(y, y, z, w));
+    assert_eq!(a.yywx(), Bool4::new(y, y, w, x));
+    assert_eq!(a.yywy(), Bool4::new(y, y, w, y));
+    assert_eq!(a.yywz(), Bool4::new(y, y, w, z));
+    assert_eq!(a.yyww(), Bool4::new(y, y, w, w));
+    assert_eq!(a.yzxx(), Bool4::new(y, z, x, x));
+    assert_eq!(a.yzxy(), Bool4::new(y, z, x, y));
+    assert_eq!(a.yzxz(), Bool4::new(y, z, x, z));
+    assert_eq!(a.yzxw(), Bool4::new(y, z, x, w));
+    assert_eq!(a.yzyx(), Bool4::new(y, z, y, x));
+    assert_eq!(a.yzyy(), Bool4::new(y, z, y, y));
+    assert_eq!(a.yzyz(), Bool4::new(y, z, y, z));
+    assert_eq!(a.yzyw(), Bool4::new(y, z, y, w));
+    assert_eq!(a.yzzx(), Bool4::new(y, z, z, x));
+    assert_eq!(a.yzzy(), Bool4::new(y, z, z, y));
+    assert_eq!(a.yzzz(), Bool4::new(y, z, z, z));
+    assert_eq!(a.yzzw(), Bool4::new(y, z, z, w));
+    assert_eq!(a.yzwx(), Bool4::new(y, z, w, x));
+    assert_eq!(a.yzwy(), Bool4::new(y, z, w, y));
+    assert_eq!(a.yzwz(), Bool4::new(y, z, w, z));
+    assert_eq!(a.yzww(), Bool4::new(y, z, w, w));
+    assert_eq!(a.ywxx(), Bool4::new(y, w, x, x));
+    assert_eq!(a.ywxy(), Bool4::new(y, w, x, y));
+    assert_eq!(a.ywxz(), Bool4::new(y, w, x, z));
+    assert_eq!(a.ywxw(), Bool4::new(y, w, x, w));
+    assert_eq!(a.ywyx(), Bool4::new(y, w, y, x));
+    assert_eq!(a.ywyy(), Bool4::new(y, w, y, y));
+    assert_eq!(a.ywyz(), Bool4::new(y, w, y, z));
+    assert_eq!(a.ywyw(), Bool4::new(y, w, y, w));
+    assert_eq!(a.ywzx(), Bool4::new(y, w, z, x));
+    assert_eq!(a.ywzy(), Bool4::new(y, w, z, y));
+    assert_eq!(a.ywzz(), Bool4::new(y, w, z, z));
+    assert_eq!(a.ywzw(), Bool4::new(y, w, z, w));
+    assert_eq!(a.ywwx(), Bool4::new(y, w, w, x));
+    assert_eq!(a.ywwy(), Bool4::new(y, w, w, y));
+    assert_eq!(a.ywwz(), Bool4::new(y, w, w, z));
+    assert_eq!(a.ywww(), Bool4::new(y, w, w, w));
+    assert_eq!(a.zxxx(), Bool4::new(z, x, x, x));
+    assert_eq!(a.zxxy(), Bool4::new(z, x, x, y));
+    assert_eq!(a.zxxz(), Bool4::new(z, x, x, z));
+    assert_eq!(a.zxxw(), Bool4::new(z, x, x, w));
+    assert_eq!(a.zxyx(), Bool4::new(z, x, y, x));
+    assert_eq!(a.zxyy(), Bool4::new(z, x, y, y));
+    assert_eq!(a.zxyz(), Bool4::new(z, x, y, z));
+    assert_eq!(a.zxyw(), Bool4::new(z, x, y, w));
+    assert_eq!(a.zxzx(), Bool4::new(z, x, z, x));
+    assert_eq!(a.zxzy(), Bool4::new(z, x, z, y));
+    assert_eq!(a.zxzz(), Bool4::new(z, x, z, z));
+    assert_eq!(a.zxzw(), Bool4::new(z, x, z, w));
+    assert_eq!(a.zxwx(), Bool4::new(z, x, w, x));
+    assert_eq!(a.zxwy(), Bool4::new(z, x, w, y));
+    assert_eq!(a.zxwz(), Bool4::new(z, x, w, z));
+    assert_eq!(a.zxww(), Bool4::new(z, x, w, w));
+    assert_eq!(a.zyxx(), Bool4::new(z, y, x, x));
+    assert_eq!(a.zyxy(), Bool4::new(z, y, x, y));
+    assert_eq!(a.zyxz(), Bool4::new(z, y, x, z));
+    assert_eq!(a.zyxw(), Bool4::new(z, y, x, w));
+    assert_eq!(a.zyyx(), Bool4::new(z, y, y, x));
+    assert_eq!(a.zyyy(), Bool4::new(z, y, y, y));
+    assert_eq!(a.zyyz(), Bool4::new(z, y, y, z));
+    assert_eq!(a.zyyw(), Bool4::new(z, y, y, w));
+    assert_eq!(a.zyzx(), Bool4::new(z, y, z, x));
+    assert_eq!(a.zyzy(), Bool4::new(z, y, z, y));
+    assert_eq!(a.zyzz(), Bool4::new(z, y, z, z));
+    assert_eq!(a.zyzw(), Bool4::new(z, y, z, w));
+    assert_eq!(a.zywx(), Bool4::new(z, y, w, x));
+    assert_eq!(a.zywy(), Bool4::new(z, y, w, y));
+    assert_eq!(a.zywz(), Bool4::new(z, y, w, z));
+    assert_eq!(a.zyww(), Bool4::new(z, y, w, w));
+    assert_eq!(a.zzxx(), Bool4::new(z, z, x, x));
+    assert_eq!(a.zzxy(), Bool4::new(z, z, x, y));
+    assert_eq!(a.zzxz(), Bool4::new(z, z, x, z));
+    assert_eq!(a.zzxw(), Bool4::new(z, z, x, w));
+    assert_eq!(a.zzyx(), Bool4::new(z, z, y, x));
+    assert_eq!(a.zzyy(), Bool4::new(z, z, y, y));
+    assert_eq!(a.zzyz(), Bool4::new(z, z, y, z));
+    assert_eq!(a.zzyw(), Bool4::new(z, z, y, w));
+    assert_eq!(a.zzzx(), Bool4::new(z, z, z, x));
+    assert_eq!(a.zzzy(), Bool4::new(z, z, z, y));
+    assert_eq!(a.zzzz(), Bool4::new(z, z, z, z));
+    assert_eq!(a.zzzw(), Bool4::new(z, z, z, w));
+    assert_eq!(a.zzwx(), Bool4::new(z, z, w, x));
+    assert_eq!(a.zzwy(), Bool4::new(z, z, w, y));
+    assert_eq!(a.zzwz(), Bool4::new(z, z, w, z));
+    assert_eq!(a.zzww(), Bool4::new(z, z, w, w));
+    assert_eq!(a.zwxx(), Bool4::new(z, w, x, x));
+    assert_eq!(a.zwxy(), Bool4::new(z, w, x, y));
+    assert_eq!(a.zwxz(), Bool4::new(z, w, x, z));
+    assert_eq!(a.zwxw(), Bool4::new(z, w, x, w));
+    assert_eq!(a.zwyx(), Bool4::new(z, w, y, x));
+    assert_eq!(a.zwyy(), Bool4::new(z, w, y, y));
+    assert_eq!(a.zwyz(), Bool4::new(z, w, y, z));
+    assert_eq!(a.zwyw(), Bool4::new(z, w, y, w));
+    assert_eq!(a.zwzx(), Bool4::new(z, w, z, x));
+    assert_eq!(a.zwzy(), Bool4::new(z, w, z, y));
+    assert_eq!(a.zwzz(), Bool4::new(z, w, z, z));
+    assert_eq!(a.zwzw(), Bool4::new(z, w, z, w));
+    assert_eq!(a.zwwx(), Bool4::new(z, w, w, x));
+    assert_eq!(a.zwwy(), Bool4::new(z, w, w, y));
+    assert_eq!(a.zwwz(), Bool4::new(z, w, w, z));
+    assert_eq!(a.zwww(), Bool4::new(z, w, w, w));
+    assert_eq!(a.wxxx(), Bool4::new(w, x, x, x));
+    assert_eq!(a.wxxy(), Bool4::new(w, x, x, y));
+    assert_eq!(a.wxxz(), Bool4::new(w, x, x, z));
+    assert_eq!(a.wxxw(), Bool4::new(w, x, x, w));
+    assert_eq!(a.wxyx(), Bool4::new(w, x, y, x));
+    assert_eq!(a.wxyy(), Bool4::new(w, x, y, y));
+    assert_eq!(a.wxyz(), Bool4::new(w, x, y, z));
+    assert_eq!(a.wxyw(), Bool4::new(w, x, y, w));
+    assert_eq!(a.wxzx(), Bool4::new(w, x, z, x));
+    assert_eq!(a.wxzy(), Bool4::new(w, x, z, y));
+    assert_eq!(a.wxzz(), Bool4::new(w, x, z, z));
+    assert_eq!(a.wxzw(), Bool4::new(w, x, z, w));
+    assert_eq!(a.wxwx(), Bool4::new(w, x, w, x));
+    assert_eq!(a.wxwy(), Bool4::new(w, x, w, y));
+    assert_eq!(a.wxwz(), Bool4::new(w, x, w, z));
+    assert_eq!(a.wxww(), Bool4::new(w, x, w, w));
+    assert_eq!(a.wyxx(), Bool4::new(w, y, x, x));
+    assert_eq!(a.wyxy(), Bool4::new(w, y, x, y));
+    assert_eq!(a.wyxz(), Bool4::new(w, y, x, z));
+    assert_eq!(a.wyxw(), Bool4::new(w, y, x, w));
+    assert_eq!(a.wyyx(), Bool4::new(w, y, y, x));
+    assert_eq!(a.wyyy(), Bool4::new(w, y, y, y));
+    assert_eq!(a.wyyz(), Bool4::new(w, y, y, z));
+    assert_eq!(a.wyyw(), Bool4::new(w, y, y, w));
+    assert_eq!(a.wyzx(), Bool4::new(w, y, z, x));
+    assert_eq!(a.wyzy(), Bool4::new(w, y, z, y));
+    assert_eq!(a.wyzz(), Bool4::new(w, y, z, z));
+    assert_eq!(a.wyzw(), Bool4::new(w, y, z, w));
+    assert_eq!(a.wywx(), Bool4::new(w, y, w, x));
+    assert_eq!(a.wywy(), Bool4::new(w, y, w, y));
+    assert_eq!(a.wywz(), Bool4::new(w, y, w, z));
+    assert_eq!(a.wyww(), Bool4::new(w, y, w, w));
+    assert_eq!(a.wzxx(), Bool4::new(w, z, x, x));
+    assert_eq!(a.wzxy(), Bool4::new(w, z, x, y));
+    assert_eq!(a.wzxz(), Bool4::new(w, z, x, z));
+    assert_eq!(a.wzxw(), Bool4::new(w, z, x, w));
+    assert_eq!(a.wzyx(), Bool4::new(w, z, y, x));
+    assert_eq!(a.wzyy(), Bool4::new(w, z, y, y));
+    assert_eq!(a.wzyz(), Bool4::new(w, z, y, z));
+    assert_eq!(a.wzyw(), Bool4::new(w, z, y, w));
+    assert_eq!(a.wzzx(), Bool4::new(w, z, z, x));
+    assert_eq!(a.wzzy(), Bool4::new(w, z, z, y));
+    assert_eq!(a.wzzz(), Bool4::new(w, z, z, z));
+    assert_eq!(a.wzzw(), Bool4::new(w, z, z, w));
+    assert_eq!(a.wzwx(), Bool4::new(w, z, w, x));
+    assert_eq!(a.wzwy(), Bool4::new(w, z, w, y));
+    assert_eq!(a.wzwz(), Bool4::new(w, z, w, z));
+    assert_eq!(a.wzww(), Bool4::new(w, z, w, w));
+    assert_eq!(a.wwxx(), Bool4::new(w, w, x, x));
+    assert_eq!(a.wwxy(), Bool4::new(w, w, x, y));
+    assert_eq!(a.wwxz(), Bool4::new(w, w, x, z));
+    assert_eq!(a.wwxw(), Bool4::new(w, w, x, w));
+    assert_eq!(a.wwyx(), Bool4::new(w, w, y, x));
+    assert_eq!(a.wwyy(), Bool4::new(w, w, y, y));
+    assert_eq!(a.wwyz(), Bool4::new(w, w, y, z));
+    assert_eq!(a.wwyw(), Bool4::new(w, w, y, w));
+    assert_eq!(a.wwzx(), Bool4::new(w, w, z, x));
+    assert_eq!(a.wwzy(), Bool4::new(w, w, z, y));
+    assert_eq!(a.wwzz(), Bool4::new(w, w, z, z));
+    assert_eq!(a.wwzw(), Bool4::new(w, w, z, w));
+    assert_eq!(a.wwwx(), Bool4::new(w, w, w, x));
+    assert_eq!(a.wwwy(), Bool4::new(w, w, w, y));
+    assert_eq!(a.wwwz(), Bool4::new(w, w, w, z));
+    assert_eq!(a.wwww(), Bool4::new(w, w, w, w));
+}
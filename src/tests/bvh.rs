@@ -0,0 +1,173 @@
+use crate::math::Aabb;
+use crate::math::Bvh;
+use crate::math::Float2;
+use crate::math::Float3;
+use crate::math::Ray;
+use crate::math::Triangle;
+
+/// Generates deterministic pseudo-random values in the range 0 to 1.
+fn random(state: &mut u32) -> f32 {
+    *state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+    (*state >> 8) as f32 / (1 << 24) as f32
+}
+
+fn random_point(state: &mut u32, scale: f32) -> Float3 {
+    Float3::new(random(state), random(state), random(state)) * scale
+}
+
+fn random_triangles(count: usize) -> Vec<Triangle> {
+    let mut state = 1;
+    (0..count)
+        .map(|_| {
+            let center = random_point(&mut state, 20.0);
+            Triangle::new(
+                center + random_point(&mut state, 2.0),
+                center + random_point(&mut state, 2.0),
+                center + random_point(&mut state, 2.0),
+            )
+        })
+        .collect()
+}
+
+fn brute_force(triangles: &[Triangle], ray: &Ray) -> Option<(f32, usize)> {
+    triangles
+        .iter()
+        .enumerate()
+        .filter_map(|(i, triangle)| ray.intersect_triangle(triangle).map(|(t, _)| (t, i)))
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+}
+
+#[test]
+fn bvh_closest_hit() {
+    let triangles = random_triangles(500);
+    let bvh = Bvh::new(&triangles);
+    assert_eq!(bvh.triangles(), &triangles[..]);
+    let mut state = 7;
+    let mut hits = 0;
+    for _ in 0..500 {
+        let origin = random_point(&mut state, 30.0) - 5.0;
+        let direction = random_point(&mut state, 20.0) - origin;
+        let ray = Ray::new(origin, direction);
+        let expected = brute_force(&triangles, &ray);
+        let hit = bvh.closest_hit(&ray, f32::INFINITY);
+        assert_eq!(hit.map(|hit| (hit.distance, hit.triangle)), expected);
+        if let Some(hit) = hit {
+            let (_, barycentrics) = ray.intersect_triangle(&triangles[hit.triangle]).unwrap();
+            assert_eq!(hit.barycentrics, barycentrics);
+            hits += 1;
+        }
+    }
+    assert!(hits > 100);
+}
+
+#[test]
+fn bvh_any_hit() {
+    let triangles = random_triangles(300);
+    let bvh = Bvh::new(&triangles);
+    let mut state = 3;
+    for _ in 0..300 {
+        let origin = random_point(&mut state, 30.0) - 5.0;
+        let direction = random_point(&mut state, 2.0) - 1.0;
+        let ray = Ray::new(origin, direction);
+        let closest = bvh.closest_hit(&ray, 10.0);
+        let any = bvh.any_hit(&ray, 10.0);
+        assert_eq!(any.is_some(), closest.is_some());
+        if let Some(any) = any {
+            assert!(any.distance <= 10.0);
+            let (t, barycentrics) = ray.intersect_triangle(&triangles[any.triangle]).unwrap();
+            assert_eq!((any.distance, any.barycentrics), (t, barycentrics));
+        }
+    }
+}
+
+#[test]
+fn bvh_from_indexed() {
+    let positions = [
+        Float3::new(0.0, 0.0, 0.0),
+        Float3::new(1.0, 0.0, 0.0),
+        Float3::new(0.0, 1.0, 0.0),
+        Float3::new(1.0, 1.0, 0.0),
+    ];
+    let bvh = Bvh::from_indexed(&positions, &[0, 1, 2, 2, 1, 3]);
+    assert_eq!(
+        bvh.bounds(),
+        Aabb::new(Float3::from(0.0), Float3::new(1.0, 1.0, 0.0))
+    );
+    let ray = Ray::new(Float3::new(0.75, 0.75, 2.0), Float3::new(0.0, 0.0, -1.0));
+    let hit = bvh.closest_hit(&ray, f32::INFINITY).unwrap();
+    assert_eq!(hit.distance, 2.0);
+    assert_eq!(hit.triangle, 1);
+    assert_eq!(hit.barycentrics, Float2::new(0.25, 0.5));
+    assert_eq!(bvh.closest_hit(&ray, 1.5), None);
+    assert_eq!(bvh.any_hit(&ray, 1.5), None);
+}
+
+#[test]
+fn bvh_axis_aligned() {
+    // the ray is parallel to x and y and starts on the bounds planes of the flat mesh.
+    let positions = [
+        Float3::new(0.0, 0.0, 0.0),
+        Float3::new(1.0, 0.0, 0.0),
+        Float3::new(0.0, 1.0, 0.0),
+        Float3::new(1.0, 1.0, 0.0),
+    ];
+    let bvh = Bvh::from_indexed(&positions, &[0, 1, 2, 2, 1, 3]);
+    let ray = Ray::new(Float3::new(0.0, 0.5, 2.0), Float3::new(0.0, 0.0, -1.0));
+    let hit = bvh.closest_hit(&ray, f32::INFINITY).unwrap();
+    assert_eq!(hit.distance, 2.0);
+    assert_eq!(hit.triangle, 0);
+    assert_eq!(hit.barycentrics, Float2::new(0.0, 0.5));
+    assert!(bvh.any_hit(&ray, f32::INFINITY).is_some());
+
+    // axis-aligned rays along the grid lines of a staircase hit the same triangles as brute force.
+    let triangles: Vec<Triangle> = (0..8)
+        .flat_map(|i| {
+            let (x, z) = (i as f32, i as f32 * 0.5);
+            [
+                Triangle::new(
+                    Float3::new(x, 0.0, z),
+                    Float3::new(x + 1.0, 0.0, z),
+                    Float3::new(x, 4.0, z),
+                ),
+                Triangle::new(
+                    Float3::new(x + 1.0, 0.0, z),
+                    Float3::new(x + 1.0, 4.0, z),
+                    Float3::new(x, 4.0, z),
+                ),
+            ]
+        })
+        .collect();
+    let bvh = Bvh::new(&triangles);
+    let mut hits = 0;
+    for x in 0..=16 {
+        for y in 0..=4 {
+            let ray = Ray::new(
+                Float3::new(x as f32 * 0.5, y as f32, 10.0),
+                Float3::new(0.0, 0.0, -1.0),
+            );
+            // rays along a shared edge hit both triangles, so only the distance is unique.
+            let expected = brute_force(&triangles, &ray).map(|(t, _)| t);
+            let hit = bvh.closest_hit(&ray, f32::INFINITY);
+            assert_eq!(hit.map(|hit| hit.distance), expected);
+            if let Some(hit) = hit {
+                let (t, _) = ray.intersect_triangle(&triangles[hit.triangle]).unwrap();
+                assert_eq!(t, hit.distance);
+            }
+            assert_eq!(
+                bvh.any_hit(&ray, f32::INFINITY).is_some(),
+                expected.is_some()
+            );
+            hits += expected.is_some() as u32;
+        }
+    }
+    assert!(hits > 50);
+}
+
+#[test]
+fn bvh_empty() {
+    let bvh = Bvh::new(&[]);
+    assert!(bvh.bounds().is_empty());
+    let ray = Ray::new(Float3::from(0.0), Float3::new(0.0, 0.0, 1.0));
+    assert_eq!(bvh.closest_hit(&ray, f32::INFINITY), None);
+    assert_eq!(bvh.any_hit(&ray, f32::INFINITY), None);
+}
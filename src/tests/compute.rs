@@ -0,0 +1,132 @@
+use crate::math::Compute;
+use crate::math::SharedArray;
+use crate::math::UInt3;
+
+#[test]
+fn compute_system_values() {
+    let output = SharedArray::new(
+        2 * 3 * 4 * 2,
+        (UInt3::from(0), UInt3::from(0), UInt3::from(0), 0),
+    );
+    Compute::dispatch(
+        UInt3::new(2, 1, 2),
+        UInt3::new(3, 2, 2),
+        || (),
+        |compute| {
+            let id = compute.dispatch_thread_id();
+            let index = (id.z * 2 + id.y) * 6 + id.x;
+            output.set(
+                index as usize,
+                (
+                    id,
+                    compute.group_id(),
+                    compute.group_thread_id(),
+                    compute.group_index(),
+                ),
+            );
+        },
+    );
+    let output = output.to_vec();
+    assert_eq!(
+        output[0],
+        (
+            UInt3::new(0, 0, 0),
+            UInt3::new(0, 0, 0),
+            UInt3::new(0, 0, 0),
+            0
+        )
+    );
+    // dispatch thread (4, 1, 3) is thread (1, 1, 1) of group (1, 0, 1).
+    let index = (3 * 2 + 1) * 6 + 4;
+    assert_eq!(
+        output[index],
+        (
+            UInt3::new(4, 1, 3),
+            UInt3::new(1, 0, 1),
+            UInt3::new(1, 1, 1),
+            10
+        )
+    );
+}
+
+#[test]
+fn compute_groupshared_reduction() {
+    // sums 256 values per group with a tree reduction in groupshared memory.
+    let input: Vec<u32> = (0..1024).collect();
+    let output = SharedArray::new(4, 0);
+    Compute::dispatch(
+        UInt3::new(4, 1, 1),
+        UInt3::new(256, 1, 1),
+        || SharedArray::new(256, 0u32),
+        |compute| {
+            let cache = compute.shared();
+            let index = compute.group_index() as usize;
+            cache.set(index, input[compute.dispatch_thread_id().x as usize]);
+            compute.group_memory_barrier_with_group_sync();
+            let mut stride = 128;
+            while stride > 0 {
+                if index < stride {
+                    cache.set(index, cache.get(index) + cache.get(index + stride));
+                }
+                compute.group_memory_barrier_with_group_sync();
+                stride /= 2;
+            }
+            if index == 0 {
+                output.set(compute.group_id().x as usize, cache.get(0));
+            }
+        },
+    );
+    assert_eq!(output.to_vec(), [32640, 98176, 163712, 229248]);
+}
+
+#[test]
+fn compute_interlocked() {
+    let counter = SharedArray::new(1, 0u32);
+    let slots = SharedArray::new(64, u32::MAX);
+    Compute::dispatch(
+        UInt3::new(2, 1, 1),
+        UInt3::new(4, 4, 2),
+        || (),
+        |compute| {
+            let slot = counter.update(0, |value| value + 1);
+            slots.set(slot as usize, compute.dispatch_thread_id().x);
+        },
+    );
+    assert_eq!(counter.get(0), 64);
+    assert!(slots.to_vec().iter().all(|value| *value < 8));
+}
+
+#[test]
+fn compute_shared_array() {
+    let array = SharedArray::from(vec![1, 2, 3]);
+    assert_eq!(array.len(), 3);
+    assert!(!array.is_empty());
+    array.set(1, 5);
+    assert_eq!(array.update(2, |value| value * 2), 3);
+    assert_eq!(array.to_vec(), [1, 5, 6]);
+}
+
+#[test]
+#[should_panic(expected = "a thread group must have 1 to 1024 threads")]
+fn compute_numthreads() {
+    Compute::dispatch(UInt3::from(1), UInt3::new(32, 32, 2), || (), |_| {});
+}
+
+#[test]
+#[should_panic(
+    expected = "a thread of the group returned before reaching a barrier the other threads wait at"
+)]
+fn compute_early_return() {
+    // the threads waiting at the barrier panic instead of hanging the dispatch.
+    Compute::dispatch(
+        UInt3::new(1, 1, 1),
+        UInt3::new(8, 1, 1),
+        || (),
+        |compute| {
+            if compute.group_index() == 5 {
+                return;
+            }
+            compute.group_memory_barrier_with_group_sync();
+        },
+    );
+}
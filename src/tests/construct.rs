@@ -0,0 +1,51 @@
+use crate::math::Bool3;
+use crate::math::Float2;
+use crate::math::Float3;
+use crate::math::Float4;
+use crate::math::Int4;
+
+#[test]
+fn construct_float3_from() {
+    let uv = Float2::new(1.0, 2.0);
+    assert_eq!(Float3::from((uv, 3.0)), Float3::new(1.0, 2.0, 3.0));
+    assert_eq!(Float3::from((3.0, uv)), Float3::new(3.0, 1.0, 2.0));
+}
+
+#[test]
+fn construct_float4_from() {
+    let a = Float2::new(1.0, 2.0);
+    let b = Float2::new(3.0, 4.0);
+    let p = Float3::new(5.0, 6.0, 7.0);
+    assert_eq!(Float4::from((p, 1.0)), Float4::new(5.0, 6.0, 7.0, 1.0));
+    assert_eq!(Float4::from((1.0, p)), Float4::new(1.0, 5.0, 6.0, 7.0));
+    assert_eq!(Float4::from((a, b)), Float4::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(Float4::from((a, 8.0, 9.0)), Float4::new(1.0, 2.0, 8.0, 9.0));
+    assert_eq!(Float4::from((8.0, a, 9.0)), Float4::new(8.0, 1.0, 2.0, 9.0));
+    assert_eq!(Float4::from((8.0, 9.0, a)), Float4::new(8.0, 9.0, 1.0, 2.0));
+}
+
+#[test]
+fn construct_macros() {
+    let pos = Float4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(
+        crate::float4!(pos.xyz(), 1.0),
+        Float4::new(1.0, 2.0, 3.0, 1.0)
+    );
+    assert_eq!(
+        crate::float4!(pos.xy(), pos.wz()),
+        Float4::new(1.0, 2.0, 4.0, 3.0)
+    );
+    assert_eq!(crate::float4!(0.5), Float4::from(0.5));
+    assert_eq!(crate::float4!(pos), pos);
+    assert_eq!(crate::float4!(1.0, 2.0, 3.0, 4.0), pos);
+    assert_eq!(crate::float3!(pos.xy(), 0.0), Float3::new(1.0, 2.0, 0.0));
+    assert_eq!(crate::float2!(1.0, 2.0,), Float2::new(1.0, 2.0));
+    assert_eq!(
+        crate::int4!(1, crate::int2!(2, 3), 4),
+        Int4::new(1, 2, 3, 4)
+    );
+    assert_eq!(
+        crate::bool3!(true, crate::bool2!(false)),
+        Bool3::new(true, false, false)
+    );
+}
@@ -0,0 +1,117 @@
+use crate::math::DecomposeError;
+use crate::math::EulerOrder;
+use crate::math::Float3;
+use crate::math::Float4;
+use crate::math::Float4x3;
+use crate::math::Float4x4;
+use crate::math::Quaternion;
+use crate::math::mul;
+use crate::tests::helpers::assert_near;
+use crate::tests::helpers::assert_near_quaternion;
+
+fn compose(translation: Float3, angles: Float3, scale: Float3) -> Float4x4 {
+    let rotation = Float4x4::rotation_euler(&angles, EulerOrder::Zxy);
+    mul(
+        mul(Float4x4::scale(&scale), rotation),
+        Float4x4::translation(&translation),
+    )
+}
+
+fn to_float4x3(m: Float4x4) -> Float4x3 {
+    Float4x3::new(m.r0.xyz(), m.r1.xyz(), m.r2.xyz(), m.r3.xyz())
+}
+
+#[test]
+fn decompose_float4x4() {
+    let angles = Float3::new(0.4, -1.2, 2.5);
+    let m = compose(
+        Float3::new(1.0, -2.0, 3.0),
+        angles,
+        Float3::new(2.0, 0.5, 3.0),
+    );
+    let (translation, rotation, scale) = m.decompose().unwrap();
+    assert_near(translation, Float3::new(1.0, -2.0, 3.0));
+    assert_near_quaternion(rotation, Quaternion::from_euler(&angles, EulerOrder::Zxy));
+    assert_near(scale, Float3::new(2.0, 0.5, 3.0));
+    let (translation, rotation, scale) = Float4x4::identity().decompose().unwrap();
+    assert_eq!(translation, Float3::from(0.0));
+    assert_eq!(rotation, Quaternion::identity());
+    assert_eq!(scale, Float3::from(1.0));
+}
+
+#[test]
+fn decompose_float4x3() {
+    let angles = Float3::new(-0.3, 0.8, 0.1);
+    let m = to_float4x3(compose(
+        Float3::new(4.0, 5.0, 6.0),
+        angles,
+        Float3::from(0.25),
+    ));
+    let (translation, rotation, scale) = m.decompose().unwrap();
+    assert_near(translation, Float3::new(4.0, 5.0, 6.0));
+    assert_near_quaternion(rotation, Quaternion::from_euler(&angles, EulerOrder::Zxy));
+    assert_near(scale, Float3::from(0.25));
+}
+
+#[test]
+fn decompose_float3x4() {
+    let angles = Float3::new(1.0, 2.0, -0.5);
+    let m = to_float4x3(compose(
+        Float3::new(-7.0, 0.0, 9.0),
+        angles,
+        Float3::new(1.0, 2.0, 3.0),
+    ));
+    let (translation, rotation, scale) = m.transpose().decompose().unwrap();
+    assert_near(translation, Float3::new(-7.0, 0.0, 9.0));
+    assert_near_quaternion(rotation, Quaternion::from_euler(&angles, EulerOrder::Zxy));
+    assert_near(scale, Float3::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn decompose_reflection() {
+    let angles = Float3::new(0.2, 0.3, 0.4);
+    let m = compose(
+        Float3::new(1.0, 2.0, 3.0),
+        angles,
+        Float3::new(2.0, -3.0, 4.0),
+    );
+    let (translation, rotation, scale) = m.decompose().unwrap();
+    assert_near(translation, Float3::new(1.0, 2.0, 3.0));
+    assert!(scale.x < 0.0);
+    assert_near(scale.abs(), Float3::new(2.0, 3.0, 4.0));
+    let rows = rotation.to_float3x3();
+    assert_near(rows.r0 * scale.x, m.r0.xyz());
+    assert_near(rows.r1 * scale.y, m.r1.xyz());
+    assert_near(rows.r2 * scale.z, m.r2.xyz());
+}
+
+#[test]
+fn decompose_errors() {
+    let shear = Float4x4::new(
+        Float4::new(1.0, 0.0, 0.0, 0.0),
+        Float4::new(0.5, 1.0, 0.0, 0.0),
+        Float4::new(0.0, 0.0, 1.0, 0.0),
+        Float4::new(0.0, 0.0, 0.0, 1.0),
+    );
+    assert_eq!(shear.decompose(), Err(DecomposeError::Shear));
+    let singular = Float4x4::scale(&Float3::new(1.0, 0.0, 1.0));
+    assert_eq!(singular.decompose(), Err(DecomposeError::Singular));
+    let collapsed = Float4x4::scale(&Float3::new(1.0, 1.0e-7, 1.0));
+    assert_eq!(collapsed.decompose(), Err(DecomposeError::Singular));
+    let projection = Float4x4::perspective_fov_lh(1.0, 1.0, 0.1, 10.0);
+    assert_eq!(projection.decompose(), Err(DecomposeError::NotAffine));
+    assert_eq!(
+        DecomposeError::Shear.to_string(),
+        "the matrix contains shear"
+    );
+}
+
+#[test]
+fn decompose_tiny_scale() {
+    // a uniform scale far below the tolerance is still a valid transform.
+    let m = Float4x4::scale(&Float3::from(1.0e-6));
+    let (translation, rotation, scale) = m.decompose().unwrap();
+    assert_eq!(translation, Float3::from(0.0));
+    assert_near_quaternion(rotation, Quaternion::identity());
+    assert_eq!(scale, Float3::from(1.0e-6));
+}
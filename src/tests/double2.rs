@@ -0,0 +1,459 @@
+use crate::math::Bool2;
+use crate::math::Double2;
+use crate::math::Double3;
+use crate::math::Double4;
+use crate::math::Float2;
+use core::f64::consts::PI;
+
+#[test]
+fn double2_from() {
+    let result = Double2::new(1.5, -2.25);
+    assert_eq!(result.x, 1.5);
+    assert_eq!(result.y, -2.25);
+    assert_eq!(Double2::from(0.5), Double2::new(0.5, 0.5));
+    assert_eq!(Double2::from((1.5, -2.25)), result);
+}
+
+#[test]
+fn double2_ops_add() {
+    let a = Double2::new(1.5, -2.25);
+    let b = Double2::new(3.0, 0.75);
+    assert_eq!(a + b, Double2::new(4.5, -1.5));
+    assert_eq!(a + 0.5, Double2::new(2.0, -1.75));
+    let mut result = a;
+    result += b;
+    assert_eq!(result, Double2::new(4.5, -1.5));
+    result += 0.5;
+    assert_eq!(result, Double2::new(5.0, -1.0));
+}
+
+#[test]
+fn double2_ops_sub() {
+    let a = Double2::new(1.5, -2.25);
+    let b = Double2::new(3.0, 0.75);
+    assert_eq!(a - b, Double2::new(-1.5, -3.0));
+    assert_eq!(a - 0.5, Double2::new(1.0, -2.75));
+    let mut result = a;
+    result -= b;
+    assert_eq!(result, Double2::new(-1.5, -3.0));
+    result -= 0.5;
+    assert_eq!(result, Double2::new(-2.0, -3.5));
+}
+
+#[test]
+fn double2_ops_mul() {
+    let a = Double2::new(1.5, -2.25);
+    let b = Double2::new(3.0, 0.75);
+    assert_eq!(a * b, Double2::new(4.5, -1.6875));
+    assert_eq!(a * 0.5, Double2::new(0.75, -1.125));
+    let mut result = a;
+    result *= b;
+    assert_eq!(result, Double2::new(4.5, -1.6875));
+    result *= 0.5;
+    assert_eq!(result, Double2::new(2.25, -0.84375));
+}
+
+#[test]
+fn double2_ops_div() {
+    let a = Double2::new(1.5, -2.25);
+    let b = Double2::new(3.0, 0.75);
+    assert_eq!(a / b, Double2::new(0.5, -3.0));
+    assert_eq!(a / 0.5, Double2::new(3.0, -4.5));
+    let mut result = a;
+    result /= b;
+    assert_eq!(result, Double2::new(0.5, -3.0));
+    result /= 0.5;
+    assert_eq!(result, Double2::new(1.0, -6.0));
+}
+
+#[test]
+fn double2_ops_neg() {
+    let result = -Double2::new(1.5, -2.25);
+    assert_eq!(result, Double2::new(-1.5, 2.25));
+}
+
+#[test]
+fn double2_precision() {
+    // values far from the origin keep sub-unit precision.
+    let a = Double2::from(1.0e9);
+    let b = Double2::from(0.125);
+    assert_eq!((a + b) - a, b);
+}
+
+#[test]
+fn double2_float2() {
+    let a = Float2::new(1.5, -2.25);
+    let result = Double2::from(a);
+    assert_eq!(result, Double2::new(1.5, -2.25));
+    assert_eq!(result.to_float2(), a);
+    assert_eq!(Double2::from(0.1).to_float2(), Float2::from(0.1));
+}
+
+#[test]
+fn double2_abs() {
+    let a = Double2::new(1.5, -2.25);
+    assert_eq!(a.abs(), Double2::new(a.x.abs(), a.y.abs()));
+}
+
+#[test]
+fn double2_acos() {
+    let a = Double2::new(0.5, -0.5);
+    assert_eq!(a.acos(), Double2::new(a.x.acos(), a.y.acos()));
+}
+
+#[test]
+fn double2_all() {
+    assert!(Double2::new(1.5, -2.25).all());
+    assert!(!Double2::new(0.0, -2.25).all());
+}
+
+#[test]
+fn double2_any() {
+    assert!(Double2::new(0.0, 0.1).any());
+    assert!(!Double2::from(0.0).any());
+}
+
+#[test]
+fn double2_asin() {
+    let a = Double2::new(0.5, -0.5);
+    assert_eq!(a.asin(), Double2::new(a.x.asin(), a.y.asin()));
+}
+
+#[test]
+fn double2_atan() {
+    let a = Double2::new(1.5, -2.25);
+    assert_eq!(a.atan(), Double2::new(a.x.atan(), a.y.atan()));
+}
+
+#[test]
+fn double2_atan2() {
+    let a = Double2::new(1.0, 1.0);
+    assert_eq!(a.atan2(), 1.0f64.atan2(1.0));
+}
+
+#[test]
+fn double2_ceil() {
+    let a = Double2::new(1.5, -2.25);
+    assert_eq!(a.ceil(), Double2::new(a.x.ceil(), a.y.ceil()));
+}
+
+#[test]
+fn double2_clamp() {
+    let a = Double2::new(1.5, -2.25);
+    assert_eq!(a.clamp(0.0, 1.0), Double2::new(1.0, 0.0));
+}
+
+#[test]
+fn double2_cos() {
+    let a = Double2::new(1.5, -2.25);
+    assert_eq!(a.cos(), Double2::new(a.x.cos(), a.y.cos()));
+}
+
+#[test]
+fn double2_cosh() {
+    let a = Double2::new(1.5, -2.25);
+    assert_eq!(a.cosh(), Double2::new(a.x.cosh(), a.y.cosh()));
+}
+
+#[test]
+fn double2_degrees() {
+    let a = Double2::from(PI);
+    assert_eq!(a.degrees(), Double2::from(180.0));
+}
+
+#[test]
+fn double2_distance() {
+    let a = Double2::from(1.0);
+    let b = Double2::from(3.0);
+    assert_eq!(a.distance(&b), (4.0 * 2.0f64).sqrt());
+}
+
+#[test]
+fn double2_dot() {
+    let a = Double2::new(1.5, -2.25);
+    let b = Double2::new(3.0, 0.75);
+    assert_eq!(a.dot(&b), 2.8125);
+}
+
+#[test]
+fn double2_eq() {
+    let a = Double2::new(1.5, -2.25);
+    let b = Double2::new(1.5, 0.75);
+    assert_eq!(a.eq(&b), Bool2::new(true, false));
+}
+
+#[test]
+fn double2_exp() {
+    let a = Double2::new(1.5, -2.25);
+    assert_eq!(a.exp(), Double2::new(a.x.exp(), a.y.exp()));
+}
+
+#[test]
+fn double2_exp2() {
+    let a = Double2::new(1.5, -2.25);
+    assert_eq!(a.exp2(), Double2::new(a.x.exp2(), a.y.exp2()));
+}
+
+#[test]
+fn double2_floor() {
+    let a = Double2::new(1.5, -2.25);
+    assert_eq!(a.floor(), Double2::new(a.x.floor(), a.y.floor()));
+}
+
+#[test]
+fn double2_fmod() {
+    let a = Double2::new(1.5, -2.25);
+    let b = Double2::new(3.0, 0.75);
+    assert_eq!(a.fmod(&b), Double2::new(a.x % b.x, a.y % b.y));
+}
+
+#[test]
+fn double2_frac() {
+    let a = Double2::new(1.5, -2.25);
+    assert_eq!(a.frac(), Double2::new(a.x.fract(), a.y.fract()));
+}
+
+#[test]
+fn double2_ge() {
+    let a = Double2::new(1.5, -2.25);
+    let b = Double2::new(1.5, 0.75);
+    assert_eq!(a.ge(&b), Bool2::new(true, false));
+}
+
+#[test]
+fn double2_gt() {
+    let a = Double2::new(1.5, -2.25);
+    let b = Double2::new(1.5, 0.75);
+    assert_eq!(a.gt(&b), Bool2::new(false, false));
+}
+
+#[test]
+fn double2_ldexp() {
+    let a = Double2::new(1.5, -2.25);
+    let b = Double2::from(2.0);
+    assert_eq!(a.ldexp(&b), Double2::new(6.0, -9.0));
+}
+
+#[test]
+fn double2_le() {
+    let a = Double2::new(1.5, -2.25);
+    let b = Double2::new(1.5, 0.75);
+    assert_eq!(a.le(&b), Bool2::new(true, true));
+}
+
+#[test]
+fn double2_length() {
+    let a = Double2::from(2.0);
+    assert_eq!(a.length(), (4.0 * 2.0f64).sqrt());
+}
+
+#[test]
+fn double2_lerp() {
+    let a = Double2::from(1.0);
+    let b = Double2::from(3.0);
+    assert_eq!(a.lerp(&b, 0.25), Double2::from(1.5));
+}
+
+#[test]
+fn double2_log() {
+    let a = Double2::new(1.5, 2.25);
+    assert_eq!(a.log(), Double2::new(a.x.ln(), a.y.ln()));
+}
+
+#[test]
+fn double2_log10() {
+    let a = Double2::new(1.5, 2.25);
+    assert_eq!(a.log10(), Double2::new(a.x.log10(), a.y.log10()));
+}
+
+#[test]
+fn double2_log2() {
+    let a = Double2::new(1.5, 2.25);
+    assert_eq!(a.log2(), Double2::new(a.x.log2(), a.y.log2()));
+}
+
+#[test]
+fn double2_lt() {
+    let a = Double2::new(1.5, -2.25);
+    let b = Double2::new(1.5, 0.75);
+    assert_eq!(a.lt(&b), Bool2::new(false, true));
+}
+
+#[test]
+fn double2_mad() {
+    let a = Double2::new(1.5, -2.25);
+    let b = Double2::new(3.0, 0.75);
+    let c = Double2::from(1.0);
+    assert_eq!(a.mad(&b, &c), Double2::new(5.5, -0.6875));
+}
+
+#[test]
+fn double2_max() {
+    let a = Double2::new(1.5, -2.25);
+    let b = Double2::new(3.0, 0.75);
+    assert_eq!(a.max(&b), Double2::new(3.0, 0.75));
+}
+
+#[test]
+fn double2_min() {
+    let a = Double2::new(1.5, -2.25);
+    let b = Double2::new(3.0, 0.75);
+    assert_eq!(a.min(&b), Double2::new(1.5, -2.25));
+}
+
+#[test]
+fn double2_ne() {
+    let a = Double2::new(1.5, -2.25);
+    let b = Double2::new(1.5, 0.75);
+    assert_eq!(a.ne(&b), Bool2::new(false, true));
+}
+
+#[test]
+fn double2_normalize() {
+    let a = Double2::new(3.0, 4.0);
+    assert_eq!(a.normalize(), Double2::new(0.6, 0.8));
+    assert_eq!(Double2::from(0.0).normalize(), Double2::from(0.0));
+}
+
+#[test]
+fn double2_pow() {
+    let a = Double2::new(1.5, 2.25);
+    assert_eq!(a.pow(2.0), Double2::new(2.25, 5.0625));
+}
+
+#[test]
+fn double2_radians() {
+    let a = Double2::from(180.0);
+    assert_eq!(a.radians(), Double2::from(PI));
+}
+
+#[test]
+fn double2_rcp() {
+    let a = Double2::new(2.0, 4.0);
+    assert_eq!(a.rcp(), Double2::new(0.5, 0.25));
+    assert_eq!(Double2::from(0.0).rcp_safe(), Double2::from(0.0));
+}
+
+#[test]
+fn double2_reflect() {
+    let incident = Double2::new(1.0, -1.0);
+    let normal = Double2::new(0.0, 1.0);
+    assert_eq!(incident.reflect(&normal), Double2::new(1.0, 1.0));
+}
+
+#[test]
+fn double2_refract() {
+    let incident = Double2::new(1.0, -1.0);
+    let normal = Double2::new(0.0, 1.0);
+    assert_eq!(incident.refract(&normal, 0.5), Double2::new(0.5, -1.0));
+}
+
+#[test]
+fn double2_round() {
+    let a = Double2::new(1.5, -2.25);
+    assert_eq!(a.round(), Double2::new(a.x.round(), a.y.round()));
+}
+
+#[test]
+fn double2_rsqrt() {
+    let a = Double2::new(1.0, 4.0);
+    assert_eq!(a.rsqrt(), Double2::new(1.0, 0.5));
+}
+
+#[test]
+fn double2_saturate() {
+    let a = Double2::new(1.5, -2.25);
+    assert_eq!(a.saturate(), Double2::new(1.0, 0.0));
+}
+
+#[test]
+fn double2_sign() {
+    let a = Double2::new(1.5, -2.25);
+    assert_eq!(a.sign(), Double2::new(1.0, -1.0));
+}
+
+#[test]
+fn double2_sin() {
+    let a = Double2::new(1.5, -2.25);
+    assert_eq!(a.sin(), Double2::new(a.x.sin(), a.y.sin()));
+}
+
+#[test]
+fn double2_sinh() {
+    let a = Double2::new(1.5, -2.25);
+    assert_eq!(a.sinh(), Double2::new(a.x.sinh(), a.y.sinh()));
+}
+
+#[test]
+fn double2_smoothstep() {
+    let min = Double2::from(0.0);
+    let max = Double2::from(1.0);
+    let a = Double2::new(0.5, 1.5);
+    assert_eq!(a.smoothstep(&min, &max), Double2::new(0.5, 1.0));
+}
+
+#[test]
+fn double2_sqrt() {
+    let a = Double2::new(4.0, 9.0);
+    assert_eq!(a.sqrt(), Double2::new(2.0, 3.0));
+}
+
+#[test]
+fn double2_step() {
+    let a = Double2::new(1.5, -2.25);
+    let edge = Double2::from(1.0);
+    assert_eq!(a.step(&edge), Double2::new(1.0, 0.0));
+}
+
+#[test]
+fn double2_tan() {
+    let a = Double2::new(1.5, -2.25);
+    assert_eq!(a.tan(), Double2::new(a.x.tan(), a.y.tan()));
+}
+
+#[test]
+fn double2_tanh() {
+    let a = Double2::new(1.5, -2.25);
+    assert_eq!(a.tanh(), Double2::new(a.x.tanh(), a.y.tanh()));
+}
+
+#[test]
+fn double2_trunc() {
+    let a = Double2::new(1.5, -2.25);
+    assert_eq!(a.trunc(), Double2::new(a.x.trunc(), a.y.trunc()));
+}
+
+#[test]
+fn double2_swizzle() {
+    let a = Double2::new(1.0, 2.0);
+    let x = 1.0;
+    let y = 2.0;
+    assert_eq!(a.xx(), Double2::new(x, x));
+    assert_eq!(a.xy(), Double2::new(x, y));
+    assert_eq!(a.yx(), Double2::new(y, x));
+    assert_eq!(a.yy(), Double2::new(y, y));
+    assert_eq!(a.xxx(), Double3::new(x, x, x));
+    assert_eq!(a.xxy(), Double3::new(x, x, y));
+    assert_eq!(a.xyx(), Double3::new(x, y, x));
+    assert_eq!(a.xyy(), Double3::new(x, y, y));
+    assert_eq!(a.yxx(), Double3::new(y, x, x));
+    assert_eq!(a.yxy(), Double3::new(y, x, y));
+    assert_eq!(a.yyx(), Double3::new(y, y, x));
+    assert_eq!(a.yyy(), Double3::new(y, y, y));
+    assert_eq!(a.xxxx(), Double4::new(x, x, x, x));
+    assert_eq!(a.xxxy(), Double4::new(x, x, x, y));
+    assert_eq!(a.xxyx(), Double4::new(x, x, y, x));
+    assert_eq!(a.xxyy(), Double4::new(x, x, y, y));
+    assert_eq!(a.xyxx(), Double4::new(x, y, x, x));
+    assert_eq!(a.xyxy(), Double4::new(x, y, x, y));
+    assert_eq!(a.xyyx(), Double4::new(x, y, y, x));
+    assert_eq!(a.xyyy(), Double4::new(x, y, y, y));
+    assert_eq!(a.yxxx(), Double4::new(y, x, x, x));
+    assert_eq!(a.yxxy(), Double4::new(y, x, x, y));
+    assert_eq!(a.yxyx(), Double4::new(y, x, y, x));
+    assert_eq!(a.yxyy(), Double4::new(y, x, y, y));
+    assert_eq!(a.yyxx(), Double4::new(y, y, x, x));
+    assert_eq!(a.yyxy(), Double4::new(y, y, x, y));
+    assert_eq!(a.yyyx(), Double4::new(y, y, y, x));
+    assert_eq!(a.yyyy(), Double4::new(y, y, y, y));
+}
@@ -0,0 +1,567 @@
+use crate::math::Bool3;
+use crate::math::Double2;
+use crate::math::Double3;
+use crate::math::Double4;
+use crate::math::Float3;
+use core::f64::consts::PI;
+
+#[test]
+fn double3_from() {
+    let result = Double3::new(1.5, -2.25, 4.0);
+    assert_eq!(result.x, 1.5);
+    assert_eq!(result.y, -2.25);
+    assert_eq!(result.z, 4.0);
+    assert_eq!(Double3::from(0.5), Double3::new(0.5, 0.5, 0.5));
+    assert_eq!(Double3::from((1.5, -2.25, 4.0)), result);
+}
+
+#[test]
+fn double3_ops_add() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    let b = Double3::new(3.0, 0.75, -1.0);
+    assert_eq!(a + b, Double3::new(4.5, -1.5, 3.0));
+    assert_eq!(a + 0.5, Double3::new(2.0, -1.75, 4.5));
+    let mut result = a;
+    result += b;
+    assert_eq!(result, Double3::new(4.5, -1.5, 3.0));
+    result += 0.5;
+    assert_eq!(result, Double3::new(5.0, -1.0, 3.5));
+}
+
+#[test]
+fn double3_ops_sub() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    let b = Double3::new(3.0, 0.75, -1.0);
+    assert_eq!(a - b, Double3::new(-1.5, -3.0, 5.0));
+    assert_eq!(a - 0.5, Double3::new(1.0, -2.75, 3.5));
+    let mut result = a;
+    result -= b;
+    assert_eq!(result, Double3::new(-1.5, -3.0, 5.0));
+    result -= 0.5;
+    assert_eq!(result, Double3::new(-2.0, -3.5, 4.5));
+}
+
+#[test]
+fn double3_ops_mul() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    let b = Double3::new(3.0, 0.75, -1.0);
+    assert_eq!(a * b, Double3::new(4.5, -1.6875, -4.0));
+    assert_eq!(a * 0.5, Double3::new(0.75, -1.125, 2.0));
+    let mut result = a;
+    result *= b;
+    assert_eq!(result, Double3::new(4.5, -1.6875, -4.0));
+    result *= 0.5;
+    assert_eq!(result, Double3::new(2.25, -0.84375, -2.0));
+}
+
+#[test]
+fn double3_ops_div() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    let b = Double3::new(3.0, 0.75, -1.0);
+    assert_eq!(a / b, Double3::new(0.5, -3.0, -4.0));
+    assert_eq!(a / 0.5, Double3::new(3.0, -4.5, 8.0));
+    let mut result = a;
+    result /= b;
+    assert_eq!(result, Double3::new(0.5, -3.0, -4.0));
+    result /= 0.5;
+    assert_eq!(result, Double3::new(1.0, -6.0, -8.0));
+}
+
+#[test]
+fn double3_ops_neg() {
+    let result = -Double3::new(1.5, -2.25, 4.0);
+    assert_eq!(result, Double3::new(-1.5, 2.25, -4.0));
+}
+
+#[test]
+fn double3_precision() {
+    // values far from the origin keep sub-unit precision.
+    let a = Double3::from(1.0e9);
+    let b = Double3::from(0.125);
+    assert_eq!((a + b) - a, b);
+}
+
+#[test]
+fn double3_float3() {
+    let a = Float3::new(1.5, -2.25, 4.0);
+    let result = Double3::from(a);
+    assert_eq!(result, Double3::new(1.5, -2.25, 4.0));
+    assert_eq!(result.to_float3(), a);
+    assert_eq!(Double3::from(0.1).to_float3(), Float3::from(0.1));
+}
+
+#[test]
+fn double3_abs() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    assert_eq!(a.abs(), Double3::new(a.x.abs(), a.y.abs(), a.z.abs()));
+}
+
+#[test]
+fn double3_acos() {
+    let a = Double3::new(0.5, -0.5, 0.0);
+    assert_eq!(a.acos(), Double3::new(a.x.acos(), a.y.acos(), a.z.acos()));
+}
+
+#[test]
+fn double3_all() {
+    assert!(Double3::new(1.5, -2.25, 4.0).all());
+    assert!(!Double3::new(0.0, -2.25, 4.0).all());
+}
+
+#[test]
+fn double3_any() {
+    assert!(Double3::new(0.0, 0.0, 0.1).any());
+    assert!(!Double3::from(0.0).any());
+}
+
+#[test]
+fn double3_asin() {
+    let a = Double3::new(0.5, -0.5, 0.0);
+    assert_eq!(a.asin(), Double3::new(a.x.asin(), a.y.asin(), a.z.asin()));
+}
+
+#[test]
+fn double3_atan() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    assert_eq!(a.atan(), Double3::new(a.x.atan(), a.y.atan(), a.z.atan()));
+}
+
+#[test]
+fn double3_ceil() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    assert_eq!(a.ceil(), Double3::new(a.x.ceil(), a.y.ceil(), a.z.ceil()));
+}
+
+#[test]
+fn double3_clamp() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    assert_eq!(a.clamp(0.0, 1.0), Double3::new(1.0, 0.0, 1.0));
+}
+
+#[test]
+fn double3_cos() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    assert_eq!(a.cos(), Double3::new(a.x.cos(), a.y.cos(), a.z.cos()));
+}
+
+#[test]
+fn double3_cosh() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    assert_eq!(a.cosh(), Double3::new(a.x.cosh(), a.y.cosh(), a.z.cosh()));
+}
+
+#[test]
+fn double3_degrees() {
+    let a = Double3::from(PI);
+    assert_eq!(a.degrees(), Double3::from(180.0));
+}
+
+#[test]
+fn double3_distance() {
+    let a = Double3::from(1.0);
+    let b = Double3::from(3.0);
+    assert_eq!(a.distance(&b), (4.0 * 3.0f64).sqrt());
+}
+
+#[test]
+fn double3_dot() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    let b = Double3::new(3.0, 0.75, -1.0);
+    assert_eq!(a.dot(&b), -1.1875);
+}
+
+#[test]
+fn double3_eq() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    let b = Double3::new(1.5, 0.75, 4.0);
+    assert_eq!(a.eq(&b), Bool3::new(true, false, true));
+}
+
+#[test]
+fn double3_exp() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    assert_eq!(a.exp(), Double3::new(a.x.exp(), a.y.exp(), a.z.exp()));
+}
+
+#[test]
+fn double3_exp2() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    assert_eq!(a.exp2(), Double3::new(a.x.exp2(), a.y.exp2(), a.z.exp2()));
+}
+
+#[test]
+fn double3_floor() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    assert_eq!(
+        a.floor(),
+        Double3::new(a.x.floor(), a.y.floor(), a.z.floor())
+    );
+}
+
+#[test]
+fn double3_fmod() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    let b = Double3::new(3.0, 0.75, -1.0);
+    assert_eq!(a.fmod(&b), Double3::new(a.x % b.x, a.y % b.y, a.z % b.z));
+}
+
+#[test]
+fn double3_frac() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    assert_eq!(
+        a.frac(),
+        Double3::new(a.x.fract(), a.y.fract(), a.z.fract())
+    );
+}
+
+#[test]
+fn double3_frexp() {
+    let a = Double3::new(8.0, -0.375, f64::MIN_POSITIVE / 4.0);
+    let (mantissa, exponent) = a.frexp();
+    assert_eq!(mantissa, Double3::new(0.5, -0.75, 0.5));
+    assert_eq!(exponent, Double3::new(4.0, -1.0, -1023.0));
+}
+
+#[test]
+fn double3_ge() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    let b = Double3::new(1.5, 0.75, 4.0);
+    assert_eq!(a.ge(&b), Bool3::new(true, false, true));
+}
+
+#[test]
+fn double3_gt() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    let b = Double3::new(1.5, 0.75, 4.0);
+    assert_eq!(a.gt(&b), Bool3::new(false, false, false));
+}
+
+#[test]
+fn double3_ldexp() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    let b = Double3::from(2.0);
+    assert_eq!(a.ldexp(&b), Double3::new(6.0, -9.0, 16.0));
+}
+
+#[test]
+fn double3_le() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    let b = Double3::new(1.5, 0.75, 4.0);
+    assert_eq!(a.le(&b), Bool3::new(true, true, true));
+}
+
+#[test]
+fn double3_length() {
+    let a = Double3::from(2.0);
+    assert_eq!(a.length(), (4.0 * 3.0f64).sqrt());
+}
+
+#[test]
+fn double3_lerp() {
+    let a = Double3::from(1.0);
+    let b = Double3::from(3.0);
+    assert_eq!(a.lerp(&b, 0.25), Double3::from(1.5));
+}
+
+#[test]
+fn double3_log() {
+    let a = Double3::new(1.5, 2.25, 4.0);
+    assert_eq!(a.log(), Double3::new(a.x.ln(), a.y.ln(), a.z.ln()));
+}
+
+#[test]
+fn double3_log10() {
+    let a = Double3::new(1.5, 2.25, 4.0);
+    assert_eq!(
+        a.log10(),
+        Double3::new(a.x.log10(), a.y.log10(), a.z.log10())
+    );
+}
+
+#[test]
+fn double3_log2() {
+    let a = Double3::new(1.5, 2.25, 4.0);
+    assert_eq!(a.log2(), Double3::new(a.x.log2(), a.y.log2(), a.z.log2()));
+}
+
+#[test]
+fn double3_lt() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    let b = Double3::new(1.5, 0.75, 4.0);
+    assert_eq!(a.lt(&b), Bool3::new(false, true, false));
+}
+
+#[test]
+fn double3_mad() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    let b = Double3::new(3.0, 0.75, -1.0);
+    let c = Double3::from(1.0);
+    assert_eq!(a.mad(&b, &c), Double3::new(5.5, -0.6875, -3.0));
+}
+
+#[test]
+fn double3_max() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    let b = Double3::new(3.0, 0.75, -1.0);
+    assert_eq!(a.max(&b), Double3::new(3.0, 0.75, 4.0));
+}
+
+#[test]
+fn double3_min() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    let b = Double3::new(3.0, 0.75, -1.0);
+    assert_eq!(a.min(&b), Double3::new(1.5, -2.25, -1.0));
+}
+
+#[test]
+fn double3_ne() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    let b = Double3::new(1.5, 0.75, 4.0);
+    assert_eq!(a.ne(&b), Bool3::new(false, true, false));
+}
+
+#[test]
+fn double3_normalize() {
+    let a = Double3::new(3.0, 4.0, 0.0);
+    assert_eq!(a.normalize(), Double3::new(0.6, 0.8, 0.0));
+    assert_eq!(Double3::from(0.0).normalize(), Double3::from(0.0));
+}
+
+#[test]
+fn double3_pow() {
+    let a = Double3::new(1.5, 2.25, 4.0);
+    assert_eq!(a.pow(2.0), Double3::new(2.25, 5.0625, 16.0));
+}
+
+#[test]
+fn double3_radians() {
+    let a = Double3::from(180.0);
+    assert_eq!(a.radians(), Double3::from(PI));
+}
+
+#[test]
+fn double3_rcp() {
+    let a = Double3::new(2.0, 4.0, 8.0);
+    assert_eq!(a.rcp(), Double3::new(0.5, 0.25, 0.125));
+    assert_eq!(Double3::from(0.0).rcp_safe(), Double3::from(0.0));
+}
+
+#[test]
+fn double3_reflect() {
+    let incident = Double3::new(1.0, -1.0, 1.0);
+    let normal = Double3::new(0.0, 1.0, 0.0);
+    assert_eq!(incident.reflect(&normal), Double3::new(1.0, 1.0, 1.0));
+}
+
+#[test]
+fn double3_refract() {
+    let incident = Double3::new(1.0, -1.0, 0.0);
+    let normal = Double3::new(0.0, 1.0, 0.0);
+    assert_eq!(incident.refract(&normal, 0.5), Double3::new(0.5, -1.0, 0.0));
+}
+
+#[test]
+fn double3_round() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    assert_eq!(
+        a.round(),
+        Double3::new(a.x.round(), a.y.round(), a.z.round())
+    );
+}
+
+#[test]
+fn double3_rsqrt() {
+    let a = Double3::new(1.0, 4.0, 16.0);
+    assert_eq!(a.rsqrt(), Double3::new(1.0, 0.5, 0.25));
+}
+
+#[test]
+fn double3_saturate() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    assert_eq!(a.saturate(), Double3::new(1.0, 0.0, 1.0));
+}
+
+#[test]
+fn double3_sign() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    assert_eq!(a.sign(), Double3::new(1.0, -1.0, 1.0));
+}
+
+#[test]
+fn double3_sin() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    assert_eq!(a.sin(), Double3::new(a.x.sin(), a.y.sin(), a.z.sin()));
+}
+
+#[test]
+fn double3_sinh() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    assert_eq!(a.sinh(), Double3::new(a.x.sinh(), a.y.sinh(), a.z.sinh()));
+}
+
+#[test]
+fn double3_smoothstep() {
+    let min = Double3::from(0.0);
+    let max = Double3::from(1.0);
+    let a = Double3::new(0.5, 1.5, -1.0);
+    assert_eq!(a.smoothstep(&min, &max), Double3::new(0.5, 1.0, 0.0));
+}
+
+#[test]
+fn double3_sqrt() {
+    let a = Double3::new(4.0, 9.0, 16.0);
+    assert_eq!(a.sqrt(), Double3::new(2.0, 3.0, 4.0));
+}
+
+#[test]
+fn double3_step() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    let edge = Double3::from(1.0);
+    assert_eq!(a.step(&edge), Double3::new(1.0, 0.0, 1.0));
+}
+
+#[test]
+fn double3_tan() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    assert_eq!(a.tan(), Double3::new(a.x.tan(), a.y.tan(), a.z.tan()));
+}
+
+#[test]
+fn double3_tanh() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    assert_eq!(a.tanh(), Double3::new(a.x.tanh(), a.y.tanh(), a.z.tanh()));
+}
+
+#[test]
+fn double3_trunc() {
+    let a = Double3::new(1.5, -2.25, 4.0);
+    assert_eq!(
+        a.trunc(),
+        Double3::new(a.x.trunc(), a.y.trunc(), a.z.trunc())
+    );
+}
+
+#[test]
+fn double3_swizzle() {
+    let a = Double3::new(1.0, 2.0, 3.0);
+    let x = 1.0;
+    let y = 2.0;
+    let z = 3.0;
+    assert_eq!(a.xx(), Double2::new(x, x));
+    assert_eq!(a.xy(), Double2::new(x, y));
+    assert_eq!(a.xz(), Double2::new(x, z));
+    assert_eq!(a.yx(), Double2::new(y, x));
+    assert_eq!(a.yy(), Double2::new(y, y));
+    assert_eq!(a.yz(), Double2::new(y, z));
+    assert_eq!(a.zx(), Double2::new(z, x));
+    assert_eq!(a.zy(), Double2::new(z, y));
+    assert_eq!(a.zz(), Double2::new(z, z));
+    assert_eq!(a.xxx(), Double3::new(x, x, x));
+    assert_eq!(a.xxy(), Double3::new(x, x, y));
+    assert_eq!(a.xxz(), Double3::new(x, x, z));
+    assert_eq!(a.xyx(), Double3::new(x, y, x));
+    assert_eq!(a.xyy(), Double3::new(x, y, y));
+    assert_eq!(a.xyz(), Double3::new(x, y, z));
+    assert_eq!(a.xzx(), Double3::new(x, z, x));
+    assert_eq!(a.xzy(), Double3::new(x, z, y));
+    assert_eq!(a.xzz(), Double3::new(x, z, z));
+    assert_eq!(a.yxx(), Double3::new(y, x, x));
+    assert_eq!(a.yxy(), Double3::new(y, x, y));
+    assert_eq!(a.yxz(), Double3::new(y, x, z));
+    assert_eq!(a.yyx(), Double3::new(y, y, x));
+    assert_eq!(a.yyy(), Double3::new(y, y, y));
+    assert_eq!(a.yyz(), Double3::new(y, y, z));
+    assert_eq!(a.yzx(), Double3::new(y, z, x));
+    assert_eq!(a.yzy(), Double3::new(y, z, y));
+    assert_eq!(a.yzz(), Double3::new(y, z, z));
+    assert_eq!(a.zxx(), Double3::new(z, x, x));
+    assert_eq!(a.zxy(), Double3::new(z, x, y));
+    assert_eq!(a.zxz(), Double3::new(z, x, z));
+    assert_eq!(a.zyx(), Double3::new(z, y, x));
+    assert_eq!(a.zyy(), Double3::new(z, y, y));
+    assert_eq!(a.zyz(), Double3::new(z, y, z));
+    assert_eq!(a.zzx(), Double3::new(z, z, x));
+    assert_eq!(a.zzy(), Double3::new(z, z, y));
+    assert_eq!(a.zzz(), Double3::new(z, z, z));
+    assert_eq!(a.xxxx(), Double4::new(x, x, x, x));
+    assert_eq!(a.xxxy(), Double4::new(x, x, x, y));
+    assert_eq!(a.xxxz(), Double4::new(x, x, x, z));
+    assert_eq!(a.xxyx(), Double4::new(x, x, y, x));
+    assert_eq!(a.xxyy(), Double4::new(x, x, y, y));
+    assert_eq!(a.xxyz(), Double4::new(x, x, y, z));
+    assert_eq!(a.xxzx(), Double4::new(x, x, z, x));
+    assert_eq!(a.xxzy(), Double4::new(x, x, z, y));
+    assert_eq!(a.xxzz(), Double4::new(x, x, z, z));
+    assert_eq!(a.xyxx(), Double4::new(x, y, x, x));
+    assert_eq!(a.xyxy(), Double4::new(x, y, x, y));
+    assert_eq!(a.xyxz(), Double4::new(x, y, x, z));
+    assert_eq!(a.xyyx(), Double4::new(x, y, y, x));
+    assert_eq!(a.xyyy(), Double4::new(x, y, y, y));
+    assert_eq!(a.xyyz(), Double4::new(x, y, y, z));
+    assert_eq!(a.xyzx(), Double4::new(x, y, z, x));
+    assert_eq!(a.xyzy(), Double4::new(x, y, z, y));
+    assert_eq!(a.xyzz(), Double4::new(x, y, z, z));
+    assert_eq!(a.xzxx(), Double4::new(x, z, x, x));
+    assert_eq!(a.xzxy(), Double4::new(x, z, x, y));
+    assert_eq!(a.xzxz(), Double4::new(x, z, x, z));
+    assert_eq!(a.xzyx(), Double4::new(x, z, y, x));
+    assert_eq!(a.xzyy(), Double4::new(x, z, y, y));
+    assert_eq!(a.xzyz(), Double4::new(x, z, y, z));
+    assert_eq!(a.xzzx(), Double4::new(x, z, z, x));
+    assert_eq!(a.xzzy(), Double4::new(x, z, z, y));
+    assert_eq!(a.xzzz(), Double4::new(x, z, z, z));
+    assert_eq!(a.yxxx(), Double4::new(y, x, x, x));
+    assert_eq!(a.yxxy(), Double4::new(y, x, x, y));
+    assert_eq!(a.yxxz(), Double4::new(y, x, x, z));
+    assert_eq!(a.yxyx(), Double4::new(y, x, y, x));
+    assert_eq!(a.yxyy(), Double4::new(y, x, y, y));
+    assert_eq!(a.yxyz(), Double4::new(y, x, y, z));
+    assert_eq!(a.yxzx(), Double4::new(y, x, z, x));
+    assert_eq!(a.yxzy(), Double4::new(y, x, z, y));
+    assert_eq!(a.yxzz(), Double4::new(y, x, z, z));
+    assert_eq!(a.yyxx(), Double4::new(y, y, x, x));
+    assert_eq!(a.yyxy(), Double4::new(y, y, x, y));
+    assert_eq!(a.yyxz(), Double4::new(y, y, x, z));
+    assert_eq!(a.yyyx(), Double4::new(y, y, y, x));
+    assert_eq!(a.yyyy(), Double4::new(y, y, y, y));
+    assert_eq!(a.yyyz(), Double4::new(y, y, y, z));
+    assert_eq!(a.yyzx(), Double4::new(y, y, z, x));
+    assert_eq!(a.yyzy(), Double4::new(y, y, z, y));
+    assert_eq!(a.yyzz(), Double4::new(y, y, z, z));
+    assert_eq!(a.yzxx(), Double4::new(y, z, x, x));
+    assert_eq!(a.yzxy(), Double4::new(y, z, x, y));
+    assert_eq!(a.yzxz(), Double4::new(y, z, x, z));
+    assert_eq!(a.yzyx(), Double4::new(y, z, y, x));
+    assert_eq!(a.yzyy(), Double4::new(y, z, y, y));
+    assert_eq!(a.yzyz(), Double4::new(y, z, y, z));
+    assert_eq!(a.yzzx(), Double4::new(y, z, z, x));
+    assert_eq!(a.yzzy(), Double4::new(y, z, z, y));
+    assert_eq!(a.yzzz(), Double4::new(y, z, z, z));
+    assert_eq!(a.zxxx(), Double4::new(z, x, x, x));
+    assert_eq!(a.zxxy(), Double4::new(z, x, x, y));
+    assert_eq!(a.zxxz(), Double4::new(z, x, x, z));
+    assert_eq!(a.zxyx(), Double4::new(z, x, y, x));
+    assert_eq!(a.zxyy(), Double4::new(z, x, y, y));
+    assert_eq!(a.zxyz(), Double4::new(z, x, y, z));
+    assert_eq!(a.zxzx(), Double4::new(z, x, z, x));
+    assert_eq!(a.zxzy(), Double4::new(z, x, z, y));
+    assert_eq!(a.zxzz(), Double4::new(z, x, z, z));
+    assert_eq!(a.zyxx(), Double4::new(z, y, x, x));
+    assert_eq!(a.zyxy(), Double4::new(z, y, x, y));
+    assert_eq!(a.zyxz(), Double4::new(z, y, x, z));
+    assert_eq!(a.zyyx(), Double4::new(z, y, y, x));
+    assert_eq!(a.zyyy(), Double4::new(z, y, y, y));
+    assert_eq!(a.zyyz(), Double4::new(z, y, y, z));
+    assert_eq!(a.zyzx(), Double4::new(z, y, z, x));
+    assert_eq!(a.zyzy(), Double4::new(z, y, z, y));
+    assert_eq!(a.zyzz(), Double4::new(z, y, z, z));
+    assert_eq!(a.zzxx(), Double4::new(z, z, x, x));
+    assert_eq!(a.zzxy(), Double4::new(z, z, x, y));
+    assert_eq!(a.zzxz(), Double4::new(z, z, x, z));
+    assert_eq!(a.zzyx(), Double4::new(z, z, y, x));
+    assert_eq!(a.zzyy(), Double4::new(z, z, y, y));
+    assert_eq!(a.zzyz(), Double4::new(z, z, y, z));
+    assert_eq!(a.zzzx(), Double4::new(z, z, z, x));
+    assert_eq!(a.zzzy(), Double4::new(z, z, z, y));
+    assert_eq!(a.zzzz(), Double4::new(z, z, z, z));
+}
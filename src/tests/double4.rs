@@ -0,0 +1,834 @@
+use crate::math::Bool4;
+use crate::math::Double2;
+use crate::math::Double3;
+use crate::math::Double4;
+use crate::math::Float4;
+use core::f64::consts::PI;
+
+#[test]
+fn double4_from() {
+    let result = Double4::new(1.5, -2.25, 4.0, 0.5);
+    assert_eq!(result.x, 1.5);
+    assert_eq!(result.y, -2.25);
+    assert_eq!(result.z, 4.0);
+    assert_eq!(result.w, 0.5);
+    assert_eq!(Double4::from(0.5), Double4::new(0.5, 0.5, 0.5, 0.5));
+    assert_eq!(Double4::from((1.5, -2.25, 4.0, 0.5)), result);
+}
+
+#[test]
+fn double4_ops_add() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    let b = Double4::new(3.0, 0.75, -1.0, 2.0);
+    assert_eq!(a + b, Double4::new(4.5, -1.5, 3.0, 2.5));
+    assert_eq!(a + 0.5, Double4::new(2.0, -1.75, 4.5, 1.0));
+    let mut result = a;
+    result += b;
+    assert_eq!(result, Double4::new(4.5, -1.5, 3.0, 2.5));
+    result += 0.5;
+    assert_eq!(result, Double4::new(5.0, -1.0, 3.5, 3.0));
+}
+
+#[test]
+fn double4_ops_sub() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    let b = Double4::new(3.0, 0.75, -1.0, 2.0);
+    assert_eq!(a - b, Double4::new(-1.5, -3.0, 5.0, -1.5));
+    assert_eq!(a - 0.5, Double4::new(1.0, -2.75, 3.5, 0.0));
+    let mut result = a;
+    result -= b;
+    assert_eq!(result, Double4::new(-1.5, -3.0, 5.0, -1.5));
+    result -= 0.5;
+    assert_eq!(result, Double4::new(-2.0, -3.5, 4.5, -2.0));
+}
+
+#[test]
+fn double4_ops_mul() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    let b = Double4::new(3.0, 0.75, -1.0, 2.0);
+    assert_eq!(a * b, Double4::new(4.5, -1.6875, -4.0, 1.0));
+    assert_eq!(a * 0.5, Double4::new(0.75, -1.125, 2.0, 0.25));
+    let mut result = a;
+    result *= b;
+    assert_eq!(result, Double4::new(4.5, -1.6875, -4.0, 1.0));
+    result *= 0.5;
+    assert_eq!(result, Double4::new(2.25, -0.84375, -2.0, 0.5));
+}
+
+#[test]
+fn double4_ops_div() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    let b = Double4::new(3.0, 0.75, -1.0, 2.0);
+    assert_eq!(a / b, Double4::new(0.5, -3.0, -4.0, 0.25));
+    assert_eq!(a / 0.5, Double4::new(3.0, -4.5, 8.0, 1.0));
+    let mut result = a;
+    result /= b;
+    assert_eq!(result, Double4::new(0.5, -3.0, -4.0, 0.25));
+    result /= 0.5;
+    assert_eq!(result, Double4::new(1.0, -6.0, -8.0, 0.5));
+}
+
+#[test]
+fn double4_ops_neg() {
+    let result = -Double4::new(1.5, -2.25, 4.0, 0.5);
+    assert_eq!(result, Double4::new(-1.5, 2.25, -4.0, -0.5));
+}
+
+#[test]
+fn double4_precision() {
+    // values far from the origin keep sub-unit precision.
+    let a = Double4::from(1.0e9);
+    let b = Double4::from(0.125);
+    assert_eq!((a + b) - a, b);
+}
+
+#[test]
+fn double4_float4() {
+    let a = Float4::new(1.5, -2.25, 4.0, 0.5);
+    let result = Double4::from(a);
+    assert_eq!(result, Double4::new(1.5, -2.25, 4.0, 0.5));
+    assert_eq!(result.to_float4(), a);
+    assert_eq!(Double4::from(0.1).to_float4(), Float4::from(0.1));
+}
+
+#[test]
+fn double4_abs() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    assert_eq!(
+        a.abs(),
+        Double4::new(a.x.abs(), a.y.abs(), a.z.abs(), a.w.abs())
+    );
+}
+
+#[test]
+fn double4_acos() {
+    let a = Double4::new(0.5, -0.5, 0.0, 1.0);
+    assert_eq!(
+        a.acos(),
+        Double4::new(a.x.acos(), a.y.acos(), a.z.acos(), a.w.acos())
+    );
+}
+
+#[test]
+fn double4_all() {
+    assert!(Double4::new(1.5, -2.25, 4.0, 0.5).all());
+    assert!(!Double4::new(0.0, -2.25, 4.0, 0.5).all());
+}
+
+#[test]
+fn double4_any() {
+    assert!(Double4::new(0.0, 0.0, 0.0, 0.1).any());
+    assert!(!Double4::from(0.0).any());
+}
+
+#[test]
+fn double4_asin() {
+    let a = Double4::new(0.5, -0.5, 0.0, 1.0);
+    assert_eq!(
+        a.asin(),
+        Double4::new(a.x.asin(), a.y.asin(), a.z.asin(), a.w.asin())
+    );
+}
+
+#[test]
+fn double4_atan() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    assert_eq!(
+        a.atan(),
+        Double4::new(a.x.atan(), a.y.atan(), a.z.atan(), a.w.atan())
+    );
+}
+
+#[test]
+fn double4_ceil() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    assert_eq!(
+        a.ceil(),
+        Double4::new(a.x.ceil(), a.y.ceil(), a.z.ceil(), a.w.ceil())
+    );
+}
+
+#[test]
+fn double4_clamp() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    assert_eq!(a.clamp(0.0, 1.0), Double4::new(1.0, 0.0, 1.0, 0.5));
+}
+
+#[test]
+fn double4_cos() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    assert_eq!(
+        a.cos(),
+        Double4::new(a.x.cos(), a.y.cos(), a.z.cos(), a.w.cos())
+    );
+}
+
+#[test]
+fn double4_cosh() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    assert_eq!(
+        a.cosh(),
+        Double4::new(a.x.cosh(), a.y.cosh(), a.z.cosh(), a.w.cosh())
+    );
+}
+
+#[test]
+fn double4_degrees() {
+    let a = Double4::from(PI);
+    assert_eq!(a.degrees(), Double4::from(180.0));
+}
+
+#[test]
+fn double4_distance() {
+    let a = Double4::from(1.0);
+    let b = Double4::from(3.0);
+    assert_eq!(a.distance(&b), (4.0 * 4.0f64).sqrt());
+}
+
+#[test]
+fn double4_dot() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    let b = Double4::new(3.0, 0.75, -1.0, 2.0);
+    assert_eq!(a.dot(&b), -0.1875);
+}
+
+#[test]
+fn double4_eq() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    let b = Double4::new(1.5, 0.75, 4.0, 2.0);
+    assert_eq!(a.eq(&b), Bool4::new(true, false, true, false));
+}
+
+#[test]
+fn double4_exp() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    assert_eq!(
+        a.exp(),
+        Double4::new(a.x.exp(), a.y.exp(), a.z.exp(), a.w.exp())
+    );
+}
+
+#[test]
+fn double4_exp2() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    assert_eq!(
+        a.exp2(),
+        Double4::new(a.x.exp2(), a.y.exp2(), a.z.exp2(), a.w.exp2())
+    );
+}
+
+#[test]
+fn double4_floor() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    assert_eq!(
+        a.floor(),
+        Double4::new(a.x.floor(), a.y.floor(), a.z.floor(), a.w.floor())
+    );
+}
+
+#[test]
+fn double4_fmod() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    let b = Double4::new(3.0, 0.75, -1.0, 2.0);
+    assert_eq!(
+        a.fmod(&b),
+        Double4::new(a.x % b.x, a.y % b.y, a.z % b.z, a.w % b.w)
+    );
+}
+
+#[test]
+fn double4_frac() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    assert_eq!(
+        a.frac(),
+        Double4::new(a.x.fract(), a.y.fract(), a.z.fract(), a.w.fract())
+    );
+}
+
+#[test]
+fn double4_ge() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    let b = Double4::new(1.5, 0.75, 4.0, 2.0);
+    assert_eq!(a.ge(&b), Bool4::new(true, false, true, false));
+}
+
+#[test]
+fn double4_gt() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    let b = Double4::new(1.5, 0.75, 4.0, 2.0);
+    assert_eq!(a.gt(&b), Bool4::new(false, false, false, false));
+}
+
+#[test]
+fn double4_ldexp() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    let b = Double4::from(2.0);
+    assert_eq!(a.ldexp(&b), Double4::new(6.0, -9.0, 16.0, 2.0));
+}
+
+#[test]
+fn double4_le() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    let b = Double4::new(1.5, 0.75, 4.0, 2.0);
+    assert_eq!(a.le(&b), Bool4::new(true, true, true, true));
+}
+
+#[test]
+fn double4_length() {
+    let a = Double4::from(2.0);
+    assert_eq!(a.length(), (4.0 * 4.0f64).sqrt());
+}
+
+#[test]
+fn double4_lerp() {
+    let a = Double4::from(1.0);
+    let b = Double4::from(3.0);
+    assert_eq!(a.lerp(&b, 0.25), Double4::from(1.5));
+}
+
+#[test]
+fn double4_log() {
+    let a = Double4::new(1.5, 2.25, 4.0, 0.5);
+    assert_eq!(
+        a.log(),
+        Double4::new(a.x.ln(), a.y.ln(), a.z.ln(), a.w.ln())
+    );
+}
+
+#[test]
+fn double4_log10() {
+    let a = Double4::new(1.5, 2.25, 4.0, 0.5);
+    assert_eq!(
+        a.log10(),
+        Double4::new(a.x.log10(), a.y.log10(), a.z.log10(), a.w.log10())
+    );
+}
+
+#[test]
+fn double4_log2() {
+    let a = Double4::new(1.5, 2.25, 4.0, 0.5);
+    assert_eq!(
+        a.log2(),
+        Double4::new(a.x.log2(), a.y.log2(), a.z.log2(), a.w.log2())
+    );
+}
+
+#[test]
+fn double4_lt() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    let b = Double4::new(1.5, 0.75, 4.0, 2.0);
+    assert_eq!(a.lt(&b), Bool4::new(false, true, false, true));
+}
+
+#[test]
+fn double4_mad() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    let b = Double4::new(3.0, 0.75, -1.0, 2.0);
+    let c = Double4::from(1.0);
+    assert_eq!(a.mad(&b, &c), Double4::new(5.5, -0.6875, -3.0, 2.0));
+}
+
+#[test]
+fn double4_max() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    let b = Double4::new(3.0, 0.75, -1.0, 2.0);
+    assert_eq!(a.max(&b), Double4::new(3.0, 0.75, 4.0, 2.0));
+}
+
+#[test]
+fn double4_min() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    let b = Double4::new(3.0, 0.75, -1.0, 2.0);
+    assert_eq!(a.min(&b), Double4::new(1.5, -2.25, -1.0, 0.5));
+}
+
+#[test]
+fn double4_ne() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    let b = Double4::new(1.5, 0.75, 4.0, 2.0);
+    assert_eq!(a.ne(&b), Bool4::new(false, true, false, true));
+}
+
+#[test]
+fn double4_normalize() {
+    let a = Double4::new(3.0, 4.0, 0.0, 0.0);
+    assert_eq!(a.normalize(), Double4::new(0.6, 0.8, 0.0, 0.0));
+    assert_eq!(Double4::from(0.0).normalize(), Double4::from(0.0));
+}
+
+#[test]
+fn double4_pow() {
+    let a = Double4::new(1.5, 2.25, 4.0, 0.5);
+    assert_eq!(a.pow(2.0), Double4::new(2.25, 5.0625, 16.0, 0.25));
+}
+
+#[test]
+fn double4_radians() {
+    let a = Double4::from(180.0);
+    assert_eq!(a.radians(), Double4::from(PI));
+}
+
+#[test]
+fn double4_rcp() {
+    let a = Double4::new(2.0, 4.0, 8.0, 0.5);
+    assert_eq!(a.rcp(), Double4::new(0.5, 0.25, 0.125, 2.0));
+    assert_eq!(Double4::from(0.0).rcp_safe(), Double4::from(0.0));
+}
+
+#[test]
+fn double4_reflect() {
+    let incident = Double4::new(1.0, -1.0, 1.0, 1.0);
+    let normal = Double4::new(0.0, 1.0, 0.0, 0.0);
+    assert_eq!(incident.reflect(&normal), Double4::new(1.0, 1.0, 1.0, 1.0));
+}
+
+#[test]
+fn double4_refract() {
+    let incident = Double4::new(1.0, -1.0, 0.0, 0.0);
+    let normal = Double4::new(0.0, 1.0, 0.0, 0.0);
+    assert_eq!(
+        incident.refract(&normal, 0.5),
+        Double4::new(0.5, -1.0, 0.0, 0.0)
+    );
+}
+
+#[test]
+fn double4_round() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    assert_eq!(
+        a.round(),
+        Double4::new(a.x.round(), a.y.round(), a.z.round(), a.w.round())
+    );
+}
+
+#[test]
+fn double4_rsqrt() {
+    let a = Double4::new(1.0, 4.0, 16.0, 0.25);
+    assert_eq!(a.rsqrt(), Double4::new(1.0, 0.5, 0.25, 2.0));
+}
+
+#[test]
+fn double4_saturate() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    assert_eq!(a.saturate(), Double4::new(1.0, 0.0, 1.0, 0.5));
+}
+
+#[test]
+fn double4_sign() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    assert_eq!(a.sign(), Double4::new(1.0, -1.0, 1.0, 1.0));
+}
+
+#[test]
+fn double4_sin() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    assert_eq!(
+        a.sin(),
+        Double4::new(a.x.sin(), a.y.sin(), a.z.sin(), a.w.sin())
+    );
+}
+
+#[test]
+fn double4_sinh() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    assert_eq!(
+        a.sinh(),
+        Double4::new(a.x.sinh(), a.y.sinh(), a.z.sinh(), a.w.sinh())
+    );
+}
+
+#[test]
+fn double4_smoothstep() {
+    let min = Double4::from(0.0);
+    let max = Double4::from(1.0);
+    let a = Double4::new(0.5, 1.5, -1.0, 0.25);
+    assert_eq!(
+        a.smoothstep(&min, &max),
+        Double4::new(0.5, 1.0, 0.0, 0.15625)
+    );
+}
+
+#[test]
+fn double4_sqrt() {
+    let a = Double4::new(4.0, 9.0, 16.0, 0.25);
+    assert_eq!(a.sqrt(), Double4::new(2.0, 3.0, 4.0, 0.5));
+}
+
+#[test]
+fn double4_step() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    let edge = Double4::from(1.0);
+    assert_eq!(a.step(&edge), Double4::new(1.0, 0.0, 1.0, 0.0));
+}
+
+#[test]
+fn double4_tan() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    assert_eq!(
+        a.tan(),
+        Double4::new(a.x.tan(), a.y.tan(), a.z.tan(), a.w.tan())
+    );
+}
+
+#[test]
+fn double4_tanh() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    assert_eq!(
+        a.tanh(),
+        Double4::new(a.x.tanh(), a.y.tanh(), a.z.tanh(), a.w.tanh())
+    );
+}
+
+#[test]
+fn double4_trunc() {
+    let a = Double4::new(1.5, -2.25, 4.0, 0.5);
+    assert_eq!(
+        a.trunc(),
+        Double4::new(a.x.trunc(), a.y.trunc(), a.z.trunc(), a.w.trunc())
+    );
+}
+
+#[test]
+fn double4_swizzle() {
+    let a = Double4::new(1.0, 2.0, 3.0, 4.0);
+    let x = 1.0;
+    let y = 2.0;
+    let z = 3.0;
+    let w = 4.0;
+    assert_eq!(a.xx(), Double2::new(x, x));
+    assert_eq!(a.xy(), Double2::new(x, y));
+    assert_eq!(a.xz(), Double2::new(x, z));
+    assert_eq!(a.xw(), Double2::new(x, w));
+    assert_eq!(a.yx(), Double2::new(y, x));
+    assert_eq!(a.yy(), Double2::new(y, y));
+    assert_eq!(a.yz(), Double2::new(y, z));
+    assert_eq!(a.yw(), Double2::new(y, w));
+    assert_eq!(a.zx(), Double2::new(z, x));
+    assert_eq!(a.zy(), Double2::new(z, y));
+    assert_eq!(a.zz(), Double2::new(z, z));
+    assert_eq!(a.zw(), Double2::new(z, w));
+    assert_eq!(a.wx(), Double2::new(w, x));
+    assert_eq!(a.wy(), Double2::new(w, y));
+    assert_eq!(a.wz(), Double2::new(w, z));
+    assert_eq!(a.ww(), Double2::new(w, w));
+    assert_eq!(a.xxx(), Double3::new(x, x, x));
+    assert_eq!(a.xxy(), Double3::new(x, x, y));
+    assert_eq!(a.xxz(), Double3::new(x, x, z));
+    assert_eq!(a.xxw(), Double3::new(x, x, w));
+    assert_eq!(a.xyx(), Double3::new(x, y, x));
+    assert_eq!(a.xyy(), Double3::new(x, y, y));
+    assert_eq!(a.xyz(), Double3::new(x, y, z));
+    assert_eq!(a.xyw(), Double3::new(x, y, w));
+    assert_eq!(a.xzx(), Double3::new(x, z, x));
+    assert_eq!(a.xzy(), Double3::new(x, z, y));
+    assert_eq!(a.xzz(), Double3::new(x, z, z));
+    assert_eq!(a.xzw(), Double3::new(x, z, w));
+    assert_eq!(a.xwx(), Double3::new(x, w, x));
+    assert_eq!(a.xwy(), Double3::new(x, w, y));
+    assert_eq!(a.xwz(), Double3::new(x, w, z));
+    assert_eq!(a.xww(), Double3::new(x, w, w));
+    assert_eq!(a.yxx(), Double3::new(y, x, x));
+    assert_eq!(a.yxy(), Double3::new(y, x, y));
+    assert_eq!(a.yxz(), Double3::new(y, x, z));
+    assert_eq!(a.yxw(), Double3::new(y, x, w));
+    assert_eq!(a.yyx(), Double3::new(y, y, x));
+    assert_eq!(a.yyy(), Double3::new(y, y, y));
+    assert_eq!(a.yyz(), Double3::new(y, y, z));
+    assert_eq!(a.yyw(), Double3::new(y, y, w));
+    assert_eq!(a.yzx(), Double3::new(y, z, x));
+    assert_eq!(a.yzy(), Double3::new(y, z, y));
+    assert_eq!(a.yzz(), Double3::new(y, z, z));
+    assert_eq!(a.yzw(), Double3::new(y, z, w));
+    assert_eq!(a.ywx(), Double3::new(y, w, x));
+    assert_eq!(a.ywy(), Double3::new(y, w, y));
+    assert_eq!(a.ywz(), Double3::new(y, w, z));
+    assert_eq!(a.yww(), Double3::new(y, w, w));
+    assert_eq!(a.zxx(), Double3::new(z, x, x));
+    assert_eq!(a.zxy(), Double3::new(z, x, y));
+    assert_eq!(a.zxz(), Double3::new(z, x, z));
+    assert_eq!(a.zxw(), Double3::new(z, x, w));
+    assert_eq!(a.zyx(), Double3::new(z, y, x));
+    assert_eq!(a.zyy(), Double3::new(z, y, y));
+    assert_eq!(a.zyz(), Double3::new(z, y, z));
+    assert_eq!(a.zyw(), Double3::new(z, y, w));
+    assert_eq!(a.zzx(), Double3::new(z, z, x));
+    assert_eq!(a.zzy(), Double3::new(z, z, y));
+    assert_eq!(a.zzz(), Double3::new(z, z, z));
+    assert_eq!(a.zzw(), Double3::new(z, z, w));
+    assert_eq!(a.zwx(), Double3::new(z, w, x));
+    assert_eq!(a.zwy(), Double3::new(z, w, y));
+    assert_eq!(a.zwz(), Double3::new(z, w, z));
+    assert_eq!(a.zww(), Double3::new(z, w, w));
+    assert_eq!(a.wxx(), Double3::new(w, x, x));
+    assert_eq!(a.wxy(), Double3::new(w, x, y));
+    assert_eq!(a.wxz(), Double3::new(w, x, z));
+    assert_eq!(a.wxw(), Double3::new(w, x, w));
+    assert_eq!(a.wyx(), Double3::new(w, y, x));
+    assert_eq!(a.wyy(), Double3::new(w, y, y));
+    assert_eq!(a.wyz(), Double3::new(w, y, z));
+    assert_eq!(a.wyw(), Double3::new(w, y, w));
+    assert_eq!(a.wzx(), Double3::new(w, z, x));
+    assert_eq!(a.wzy(), Double3::new(w, z, y));
+    assert_eq!(a.wzz(), Double3::new(w, z, z));
+    assert_eq!(a.wzw(), Double3::new(w, z, w));
+    assert_eq!(a.wwx(), Double3::new(w, w, x));
+    assert_eq!(a.wwy(), Double3::new(w, w, y));
+    assert_eq!(a.wwz(), Double3::new(w, w, z));
+    assert_eq!(a.www(), Double3::new(w, w, w));
+    assert_eq!(a.xxxx(), Double4::new(x, x, x, x));
+    assert_eq!(a.xxxy(), Double4::new(x, x, x, y));
+    assert_eq!(a.xxxz(), Double4::new(x, x, x, z));
+    assert_eq!(a.xxxw(), Double4::new(x, x, x, w));
+    assert_eq!(a.xxyx(), Double4::new(x, x, y, x));
+    assert_eq!(a.xxyy(), Double4::new(x, x, y, y));
+    assert_eq!(a.xxyz(), Double4::new(x, x, y, z));
+    assert_eq!(a.xxyw(), Double4::new(x, x, y, w));
+    assert_eq!(a.xxzx(), Double4::new(x, x, z, x));
+    assert_eq!(a.xxzy(), Double4::new(x, x, z, y));
+    assert_eq!(a.xxzz(), Double4::new(x, x, z, z));
+    assert_eq!(a.xxzw(), Double4::new(x, x, z, w));
+    assert_eq!(a.xxwx(), Double4::new(x, x, w, x));
+    assert_eq!(a.xxwy(), Double4::new(x, x, w, y));
+    assert_eq!(a.xxwz(), Double4::new(x, x, w, z));
+    assert_eq!(a.xxww(), Double4::new(x, x, w, w));
+    assert_eq!(a.xyxx(), Double4::new(x, y, x, x));
+    assert_eq!(a.xyxy(), Double4::new(x, y, x, y));
+    assert_eq!(a.xyxz(), Double4::new(x, y, x, z));
+    assert_eq!(a.xyxw(), Double4::new(x, y, x, w));
+    assert_eq!(a.xyyx(), Double4::new(x, y, y, x));
+    assert_eq!(a.xyyy(), Double4::new(x, y, y, y));
+    assert_eq!(a.xyyz(), Double4::new(x, y, y, z));
+    assert_eq!(a.xyyw(), Double4::new(x, y, y, w));
+    assert_eq!(a.xyzx(), Double4::new(x, y, z, x));
+    assert_eq!(a.xyzy(), Double4::new(x, y, z, y));
+    assert_eq!(a.xyzz(), Double4::new(x, y, z, z));
+    assert_eq!(a.xyzw(), Double4::new(x, y, z, w));
+    assert_eq!(a.xywx(), Double4::new(x, y, w, x));
+    assert_eq!(a.xywy(), Double4::new(x, y, w, y));
+    assert_eq!(a.xywz(), Double4::new(x, y, w, z));
+    assert_eq!(a.xyww(), Double4::new(x, y, w, w));
+    assert_eq!(a.xzxx(), Double4::new(x, z, x, x));
+    assert_eq!(a.xzxy(), Double4::new(x, z, x, y));
+    assert_eq!(a.xzxz(), Double4::new(x, z, x, z));
+    assert_eq!(a.xzxw(), Double4::new(x, z, x, w));
+    assert_eq!(a.xzyx(), Double4::new(x, z, y, x));
+    assert_eq!(a.xzyy(), Double4::new(x, z, y, y));
+    assert_eq!(a.xzyz(), Double4::new(x, z, y, z));
+    assert_eq!(a.xzyw(), Double4::new(x, z, y, w));
+    assert_eq!(a.xzzx(), Double4::new(x, z, z, x));
+    assert_eq!(a.xzzy(), Double4::new(x, z, z, y));
+    assert_eq!(a.xzzz(), Double4::new(x, z, z, z));
+    assert_eq!(a.xzzw(), Double4::new(x, z, z, w));
+    assert_eq!(a.xzwx(), Double4::new(x, z, w, x));
+    assert_eq!(a.xzwy(), Double4::new(x, z, w, y));
+    assert_eq!(a.xzwz(), Double4::new(x, z, w, z));
+    assert_eq!(a.xzww(), Double4::new(x, z, w, w));
+    assert_eq!(a.xwxx(), Double4::new(x, w, x, x));
+    assert_eq!(a.xwxy(), Double4::new(x, w, x, y));
+    assert_eq!(a.xwxz(), Double4::new(x, w, x, z));
+    assert_eq!(a.xwxw(), Double4::new(x, w, x, w));
+    assert_eq!(a.xwyx(), Double4::new(x, w, y, x));
+    assert_eq!(a.xwyy(), Double4::new(x, w, y, y));
+    assert_eq!(a.xwyz(), Double4::new(x, w, y, z));
+    assert_eq!(a.xwyw(), Double4::new(x, w, y, w));
+    assert_eq!(a.xwzx(), Double4::new(x, w, z, x));
+    assert_eq!(a.xwzy(), Double4::new(x, w, z, y));
+    assert_eq!(a.xwzz(), Double4::new(x, w, z, z));
+    assert_eq!(a.xwzw(), Double4::new(x, w, z, w));
+    assert_eq!(a.xwwx(), Double4::new(x, w, w, x));
+    assert_eq!(a.xwwy(), Double4::new(x, w, w, y));
+    assert_eq!(a.xwwz(), Double4::new(x, w, w, z));
+    assert_eq!(a.xwww(), Double4::new(x, w, w, w));
+    assert_eq!(a.yxxx(), Double4::new(y, x, x, x));
+    assert_eq!(a.yxxy(), Double4::new(y, x, x, y));
+    assert_eq!(a.yxxz(), Double4::new(y, x, x, z));
+    assert_eq!(a.yxxw(), Double4::new(y, x, x, w));
+    assert_eq!(a.yxyx(), Double4::new(y, x, y, x));
+    assert_eq!(a.yxyy(), Double4::new(y, x, y, y));
+    assert_eq!(a.yxyz(), Double4::new(y, x, y, z));
+    assert_eq!(a.yxyw(), Double4::new(y, x, y, w));
+    assert_eq!(a.yxzx(), Double4::new(y, x, z, x));
+    assert_eq!(a.yxzy(), Double4::new(y, x, z, y));
+    assert_eq!(a.yxzz(), Double4::new(y, x, z, z));
+    assert_eq!(a.yxzw(), Double4::new(y, x, z, w));
+    assert_eq!(a.yxwx(), Double4::new(y, x, w, x));
+    assert_eq!(a.yxwy(), Double4::new(y, x, w, y));
+    assert_eq!(a.yxwz(), Double4::new(y, x, w, z));
+    assert_eq!(a.yxww(), Double4::new(y, x, w, w));
+    assert_eq!(a.yyxx(), Double4::new(y, y, x, x));
+    assert_eq!(a.yyxy(), Double4::new(y, y, x, y));
+    assert_eq!(a.yyxz(), Double4::new(y, y, x, z));
+    assert_eq!(a.yyxw(), Double4::new(y, y, x, w));
+    assert_eq!(a.yyyx(), Double4::new(y, y, y, x));
+    assert_eq!(a.yyyy(), Double4::new(y, y, y, y));
+    assert_eq!(a.yyyz(), Double4::new(y, y, y, z));
+    assert_eq!(a.yyyw(), Double4::new(y, y, y, w));
+    assert_eq!(a.yyzx(), Double4::new(y, y, z, x));
+    assert_eq!(a.yyzy(), Double4::new(y, y, z, y));
+    assert_eq!(a.yyzz(), Double4::new(y, y, z, z));
+    assert_eq!(a.yyzw(), Double4::new(y, y, z, w));
+    assert_eq!(a.yywx(), Double4::new(y, y, w, x));
+    assert_eq!(a.yywy(), Double4::new(y, y, w, y));
+    assert_eq!(a.yywz(), Double4::new(y, y, w, z));
+    assert_eq!(a.yyww(), Double4::new(y, y, w, w));
+    assert_eq!(a.yzxx(), Double4::new(y, z, x, x));
+    assert_eq!(a.yzxy(), Double4::new(y, z, x, y));
+    assert_eq!(a.yzxz(), Double4::new(y, z, x, z));
+    assert_eq!(a.yzxw(), Double4::new(y, z, x, w));
+    assert_eq!(a.yzyx(), Double4::new(y, z, y, x));
+    assert_eq!(a.yzyy(), Double4::new(y, z, y, y));
+    assert_eq!(a.yzyz(), Double4::new(y, z, y, z));
+    assert_eq!(a.yzyw(), Double4::new(y, z, y, w));
+    assert_eq!(a.yzzx(), Double4::new(y, z, z, x));
+    assert_eq!(a.yzzy(), Double4::new(y, z, z, y));
+    assert_eq!(a.yzzz(), Double4::new(y, z, z, z));
+    assert_eq!(a.yzzw(), Double4::new(y, z, z, w));
+    assert_eq!(a.yzwx(), Double4::new(y, z, w, x));
+    assert_eq!(a.yzwy(), Double4::new(y, z, w, y));
+    assert_eq!(a.yzwz(), Double4::new(y, z, w, z));
+    assert_eq!(a.yzww(), Double4::new(y, z, w, w));
+    assert_eq!(a.ywxx(), Double4::new(y, w, x, x));
+    assert_eq!(a.ywxy(), Double4::new(y, w, x, y));
+    assert_eq!(a.ywxz(), Double4::new(y, w, x, z));
+    assert_eq!(a.ywxw(), Double4::new(y, w, x, w));
+    assert_eq!(a.ywyx(), Double4::new(y, w, y, x));
+    assert_eq!(a.ywyy(), Double4::new(y, w, y, y));
+    assert_eq!(a.ywyz(), Double4::new(y, w, y, z));
+    assert_eq!(a.ywyw(), Double4::new(y, w, y, w));
+    assert_eq!(a.ywzx(), Double4::new(y, w, z, x));
+    assert_eq!(a.ywzy(), Double4::new(y, w, z, y));
+    assert_eq!(a.ywzz(), Double4::new(y, w, z, z));
+    assert_eq!(a.ywzw(), Double4::new(y, w, z, w));
+    assert_eq!(a.ywwx(), Double4::new(y, w, w, x));
+    assert_eq!(a.ywwy(), Double4::new(y, w, w, y));
+    assert_eq!(a.ywwz(), Double4::new(y, w, w, z));
+    assert_eq!(a.ywww(), Double4::new(y, w, w, w));
+    assert_eq!(a.zxxx(), Double4::new(z, x, x, x));
+    assert_eq!(a.zxxy(), Double4::new(z, x, x, y));
+    assert_eq!(a.zxxz(), Double4::new(z, x, x, z));
+    assert_eq!(a.zxxw(), Double4::new(z, x, x, w));
+    assert_eq!(a.zxyx(), Double4::new(z, x, y, x));
+    assert_eq!(a.zxyy(), Double4::new(z, x, y, y));
+    assert_eq!(a.zxyz(), Double4::new(z, x, y, z));
+    assert_eq!(a.zxyw(), Double4::new(z, x, y, w));
+    assert_eq!(a.zxzx(), Double4::new(z, x, z, x));
+    assert_eq!(a.zxzy(), Double4::new(z, x, z, y));
+    assert_eq!(a.zxzz(), Double4::new(z, x, z, z));
+    assert_eq!(a.zxzw(), Double4::new(z, x, z, w));
+    assert_eq!(a.zxwx(), Double4::new(z, x, w, x));
+    assert_eq!(a.zxwy(), Double4::new(z, x, w, y));
+    assert_eq!(a.zxwz(), Double4::new(z, x, w, z));
+    assert_eq!(a.zxww(), Double4::new(z, x, w, w));
+    assert_eq!(a.zyxx(), Double4::new(z, y, x, x));
+    assert_eq!(a.zyxy(), Double4::new(z, y, x, y));
+    assert_eq!(a.zyxz(), Double4::new(z, y, x, z));
+    assert_eq!(a.zyxw(), Double4::new(z, y, x, w));
+    assert_eq!(a.zyyx(), Double4::new(z, y, y, x));
+    assert_eq!(a.zyyy(), Double4::new(z, y, y, y));
+    assert_eq!(a.zyyz(), Double4::new(z, y, y, z));
+    assert_eq!(a.zyyw(), Double4::new(z, y, y, w));
+    assert_eq!(a.zyzx(), Double4::new(z, y, z, x));
+    assert_eq!(a.zyzy(), Double4::new(z, y, z, y));
+    assert_eq!(a.zyzz(), Double4::new(z, y, z, z));
+    assert_eq!(a.zyzw(), Double4::new(z, y, z, w));
+    assert_eq!(a.zywx(), Double4::new(z, y, w, x));
+    assert_eq!(a.zywy(), Double4::new(z, y, w, y));
+    assert_eq!(a.zywz(), Double4::new(z, y, w, z));
+    assert_eq!(a.zyww(), Double4::new(z, y, w, w));
+    assert_eq!(a.zzxx(), Double4::new(z, z, x, x));
+    assert_eq!(a.zzxy(), Double4::new(z, z, x, y));
+    assert_eq!(a.zzxz(), Double4::new(z, z, x, z));
+    assert_eq!(a.zzxw(), Double4::new(z, z, x, w));
+    assert_eq!(a.zzyx(), Double4::new(z, z, y, x));
+    assert_eq!(a.zzyy(), Double4::new(z, z, y, y));
+    assert_eq!(a.zzyz(), Double4::new(z, z, y, z));
+    assert_eq!(a.zzyw(), Double4::new(z, z, y, w));
+    assert_eq!(a.zzzx(), Double4::new(z, z, z, x));
+    assert_eq!(a.zzzy(), Double4::new(z, z, z, y));
+    assert_eq!(a.zzzz(), Double4::new(z, z, z, z));
+    assert_eq!(a.zzzw(), Double4::new(z, z, z, w));
+    assert_eq!(a.zzwx(), Double4::new(z, z, w, x));
+    assert_eq!(a.zzwy(), Double4::new(z, z, w, y));
+    assert_eq!(a.zzwz(), Double4::new(z, z, w, z));
+    assert_eq!(a.zzww(), Double4::new(z, z, w, w));
+    assert_eq!(a.zwxx(), Double4::new(z, w, x, x));
+    assert_eq!(a.zwxy(), Double4::new(z, w, x, y));
+    assert_eq!(a.zwxz(), Double4::new(z, w, x, z));
+    assert_eq!(a.zwxw(), Double4::new(z, w, x, w));
+    assert_eq!(a.zwyx(), Double4::new(z, w, y, x));
+    assert_eq!(a.zwyy(), Double4::new(z, w, y, y));
+    assert_eq!(a.zwyz(), Double4::new(z, w, y, z));
+    assert_eq!(a.zwyw(), Double4::new(z, w, y, w));
+    assert_eq!(a.zwzx(), Double4::new(z, w, z, x));
+    assert_eq!(a.zwzy(), Double4::new(z, w, z, y));
+    assert_eq!(a.zwzz(), Double4::new(z, w, z, z));
+    assert_eq!(a.zwzw(), Double4::new(z, w, z, w));
+    assert_eq!(a.zwwx(), Double4::new(z, w, w, x));
+    assert_eq!(a.zwwy(), Double4::new(z, w, w, y));
+    assert_eq!(a.zwwz(), Double4::new(z, w, w, z));
+    assert_eq!(a.zwww(), Double4::new(z, w, w, w));
+    assert_eq!(a.wxxx(), Double4::new(w, x, x, x));
+    assert_eq!(a.wxxy(), Double4::new(w, x, x, y));
+    assert_eq!(a.wxxz(), Double4::new(w, x, x, z));
+    assert_eq!(a.wxxw(), Double4::new(w, x, x, w));
+    assert_eq!(a.wxyx(), Double4::new(w, x, y, x));
+    assert_eq!(a.wxyy(), Double4::new(w, x, y, y));
+    assert_eq!(a.wxyz(), Double4::new(w, x, y, z));
+    assert_eq!(a.wxyw(), Double4::new(w, x, y, w));
+    assert_eq!(a.wxzx(), Double4::new(w, x, z, x));
+    assert_eq!(a.wxzy(), Double4::new(w, x, z, y));
+    assert_eq!(a.wxzz(), Double4::new(w, x, z, z));
+    assert_eq!(a.wxzw(), Double4::new(w, x, z, w));
+    assert_eq!(a.wxwx(), Double4::new(w, x, w, x));
+    assert_eq!(a.wxwy(), Double4::new(w, x, w, y));
+    assert_eq!(a.wxwz(), Double4::new(w, x, w, z));
+    assert_eq!(a.wxww(), Double4::new(w, x, w, w));
+    assert_eq!(a.wyxx(), Double4::new(w, y, x, x));
+    assert_eq!(a.wyxy(), Double4::new(w, y, x, y));
+    assert_eq!(a.wyxz(), Double4::new(w, y, x, z));
+    assert_eq!(a.wyxw(), Double4::new(w, y, x, w));
+    assert_eq!(a.wyyx(), Double4::new(w, y, y, x));
+    assert_eq!(a.wyyy(), Double4::new(w, y, y, y));
+    assert_eq!(a.wyyz(), Double4::new(w, y, y, z));
+    assert_eq!(a.wyyw(), Double4::new(w, y, y, w));
+    assert_eq!(a.wyzx(), Double4::new(w, y, z, x));
+    assert_eq!(a.wyzy(), Double4::new(w, y, z, y));
+    assert_eq!(a.wyzz(), Double4::new(w, y, z, z));
+    assert_eq!(a.wyzw(), Double4::new(w, y, z, w));
+    assert_eq!(a.wywx(), Double4::new(w, y, w, x));
+    assert_eq!(a.wywy(), Double4::new(w, y, w, y));
+    assert_eq!(a.wywz(), Double4::new(w, y, w, z));
+    assert_eq!(a.wyww(), Double4::new(w, y, w, w));
+    assert_eq!(a.wzxx(), Double4::new(w, z, x, x));
+    assert_eq!(a.wzxy(), Double4::new(w, z, x, y));
+    assert_eq!(a.wzxz(), Double4::new(w, z, x, z));
+    assert_eq!(a.wzxw(), Double4::new(w, z, x, w));
+    assert_eq!(a.wzyx(), Double4::new(w, z, y, x));
+    assert_eq!(a.wzyy(), Double4::new(w, z, y, y));
+    assert_eq!(a.wzyz(), Double4::new(w, z, y, z));
+    assert_eq!(a.wzyw(), Double4::new(w, z, y, w));
+    assert_eq!(a.wzzx(), Double4::new(w, z, z, x));
+    assert_eq!(a.wzzy(), Double4::new(w, z, z, y));
+    assert_eq!(a.wzzz(), Double4::new(w, z, z, z));
+    assert_eq!(a.wzzw(), Double4::new(w, z, z, w));
+    assert_eq!(a.wzwx(), Double4::new(w, z, w, x));
+    assert_eq!(a.wzwy(), Double4::new(w, z, w, y));
+    assert_eq!(a.wzwz(), Double4::new(w, z, w, z));
+    assert_eq!(a.wzww(), Double4::new(w, z, w, w));
+    assert_eq!(a.wwxx(), Double4::new(w, w, x, x));
+    assert_eq!(a.wwxy(), Double4::new(w, w, x, y));
+    assert_eq!(a.wwxz(), Double4::new(w, w, x, z));
+    assert_eq!(a.wwxw(), Double4::new(w, w, x, w));
+    assert_eq!(a.wwyx(), Double4::new(w, w, y, x));
+    assert_eq!(a.wwyy(), Double4::new(w, w, y, y));
+    assert_eq!(a.wwyz(), Double4::new(w, w, y, z));
+    assert_eq!(a.wwyw(), Double4::new(w, w, y, w));
+    assert_eq!(a.wwzx(), Double4::new(w, w, z, x));
+    assert_eq!(a.wwzy(), Double4::new(w, w, z, y));
+    assert_eq!(a.wwzz(), Double4::new(w, w, z, z));
+    assert_eq!(a.wwzw(), Double4::new(w, w, z, w));
+    assert_eq!(a.wwwx(), Double4::new(w, w, w, x));
+    assert_eq!(a.wwwy(), Double4::new(w, w, w, y));
+    assert_eq!(a.wwwz(), Double4::new(w, w, w, z));
+    assert_eq!(a.wwww(), Double4::new(w, w, w, w));
+}
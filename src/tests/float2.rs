@@ -1,3 +1,5 @@
+use crate::math::Bool2;
+use crate::math::Discard;
 use crate::math::Float2;
 use crate::math::Float3;
 use crate::math::Float4;
@@ -255,6 +257,14 @@ fn float2_clamp() {
     assert_eq!(result.y, 0.0);
 }
 
+#[test]
+fn float2_clip() {
+    assert_eq!(Float2::from(0.0).clip(), Ok(()));
+    assert_eq!(Float2::new(1.0, 2.0).clip(), Ok(()));
+    assert_eq!(Float2::new(1.0, -0.5).clip(), Err(Discard));
+    assert_eq!(Float2::from(f32::NAN).clip(), Ok(()));
+}
+
 #[test]
 fn float2_cos() {
     let a = Float2::new(PI, PI * 2.0);
@@ -295,6 +305,13 @@ fn float2_dot() {
     assert_eq!(result, 10.0);
 }
 
+#[test]
+fn float2_eq() {
+    let a = Float2::new(1.0, 2.0);
+    let b = Float2::new(2.0, 2.0);
+    assert_eq!(a.eq(&b), Bool2::new(false, true));
+}
+
 #[test]
 fn float2_exp() {
     let a = Float2::new(2.0, 4.0);
@@ -311,6 +328,20 @@ fn float2_exp2() {
     assert_eq!(result.y, 16.0);
 }
 
+#[test]
+fn float2_faceforward() {
+    // expected values follow DXC's lowering `dot(i, ng) < 0 ? n : -n` with an ordered compare.
+    let n = Float2::new(0.0, 1.0);
+    let ng = Float2::new(0.0, 1.0);
+    assert_eq!(n.faceforward(&Float2::new(0.0, -1.0), &ng), n);
+    assert_eq!(
+        n.faceforward(&Float2::new(1.0, 1.0), &ng),
+        Float2::new(-0.0, -1.0)
+    );
+    // a NaN dot product fails the compare, so the normal is flipped.
+    assert_eq!(n.faceforward(&Float2::new(f32::NAN, 1.0), &ng), -n);
+}
+
 #[test]
 fn float2_floor() {
     let a = Float2::new(0.9, -0.2);
@@ -336,6 +367,50 @@ fn float2_frac() {
     assert_eq!(result.y, 0.25);
 }
 
+#[test]
+fn float2_frexp() {
+    let a = Float2::new(8.0, -0.375);
+    let (mantissa, exponent) = a.frexp();
+    assert_eq!(mantissa, Float2::new(0.5, -0.75));
+    assert_eq!(exponent, Float2::new(4.0, -1.0));
+    assert_eq!(mantissa.ldexp(&exponent), a);
+    let (mantissa, exponent) = Float2::from(f32::MIN_POSITIVE / 4.0).frexp();
+    assert_eq!(mantissa, Float2::from(0.5));
+    assert_eq!(exponent, Float2::from(-127.0));
+}
+
+#[test]
+fn float2_ge() {
+    let a = Float2::new(1.0, 2.0);
+    let b = Float2::new(2.0, 2.0);
+    assert_eq!(a.ge(&b), Bool2::new(false, true));
+}
+
+#[test]
+fn float2_gt() {
+    let a = Float2::new(1.0, 2.0);
+    let b = Float2::new(2.0, 2.0);
+    assert_eq!(a.gt(&b), Bool2::new(false, false));
+}
+
+#[test]
+fn float2_isfinite() {
+    let a = Float2::new(f32::NAN, f32::INFINITY);
+    assert_eq!(a.isfinite(), Bool2::new(false, false));
+}
+
+#[test]
+fn float2_isinf() {
+    let a = Float2::new(f32::NAN, f32::INFINITY);
+    assert_eq!(a.isinf(), Bool2::new(false, true));
+}
+
+#[test]
+fn float2_isnan() {
+    let a = Float2::new(f32::NAN, f32::INFINITY);
+    assert_eq!(a.isnan(), Bool2::new(true, false));
+}
+
 #[test]
 fn float2_ldexp() {
     let value = Float2::new(1.5, 2.5);
@@ -345,6 +420,13 @@ fn float2_ldexp() {
     assert_eq!(result.y, 1.25);
 }
 
+#[test]
+fn float2_le() {
+    let a = Float2::new(1.0, 2.0);
+    let b = Float2::new(2.0, 2.0);
+    assert_eq!(a.le(&b), Bool2::new(true, true));
+}
+
 #[test]
 fn float2_length() {
     let a = Float2::new(0.7, 0.714143);
@@ -378,6 +460,13 @@ fn float2_log2() {
     assert_eq!(a.log2(), Float2::new(0.0, 1.0));
 }
 
+#[test]
+fn float2_lt() {
+    let a = Float2::new(1.0, 2.0);
+    let b = Float2::new(2.0, 2.0);
+    assert_eq!(a.lt(&b), Bool2::new(true, false));
+}
+
 #[test]
 fn float2_mad() {
     let a = Float2::new(2.0, 2.0);
@@ -400,6 +489,21 @@ fn float2_min() {
     assert_eq!(a.min(&b), Float2::new(2.0, 1.0));
 }
 
+#[test]
+fn float2_modf() {
+    let a = Float2::new(-2.75, 2.5);
+    let (fractional, integer) = a.modf();
+    assert_eq!(fractional, Float2::new(-0.75, 0.5));
+    assert_eq!(integer, Float2::new(-2.0, 2.0));
+}
+
+#[test]
+fn float2_ne() {
+    let a = Float2::new(1.0, 2.0);
+    let b = Float2::new(2.0, 2.0);
+    assert_eq!(a.ne(&b), Bool2::new(true, false));
+}
+
 #[test]
 fn float2_normalize() {
     let a = Float2::new(2.0, 1.0);
@@ -492,6 +596,14 @@ fn float2_sin() {
     assert_eq!(result.y, 1.7484555e-7);
 }
 
+#[test]
+fn float2_sincos() {
+    let a = Float2::new(0.0, 1.0);
+    let (sin, cos) = a.sincos();
+    assert_eq!(sin, a.sin());
+    assert_eq!(cos, a.cos());
+}
+
 #[test]
 fn float2_sinh() {
     let a = Float2::new(0.9, -0.2);
@@ -0,0 +1,111 @@
+use crate::math::Float2;
+use crate::math::Float2x2;
+use crate::math::mul;
+
+#[test]
+fn float2x2_from() {
+    let result = Float2x2::new(Float2::new(1.0, 2.0), Float2::new(3.0, 4.0));
+    assert_eq!(result.r0, Float2::new(1.0, 2.0));
+    assert_eq!(result.r1, Float2::new(3.0, 4.0));
+
+    let result = Float2x2::from(1.5);
+    assert_eq!(result.r0, Float2::new(1.5, 1.5));
+    assert_eq!(result.r1, Float2::new(1.5, 1.5));
+}
+
+#[test]
+fn float2x2_ops_add() {
+    let a = Float2x2::new(Float2::new(1.0, 2.0), Float2::new(3.0, 4.0));
+    let b = Float2x2::new(Float2::new(0.5, 0.5), Float2::new(1.0, -1.0));
+    let result = a + b;
+    assert_eq!(
+        result,
+        Float2x2::new(Float2::new(1.5, 2.5), Float2::new(4.0, 3.0))
+    );
+    assert_eq!(
+        a + 1.0,
+        Float2x2::new(Float2::new(2.0, 3.0), Float2::new(4.0, 5.0))
+    );
+}
+
+#[test]
+fn float2x2_ops_mul() {
+    let a = Float2x2::new(Float2::new(1.0, 2.0), Float2::new(3.0, 4.0));
+    let b = Float2x2::new(Float2::new(2.0, 0.5), Float2::new(1.0, -1.0));
+    let result = a * b;
+    assert_eq!(
+        result,
+        Float2x2::new(Float2::new(2.0, 1.0), Float2::new(3.0, -4.0))
+    );
+    let mut result = a;
+    result *= 2.0;
+    assert_eq!(
+        result,
+        Float2x2::new(Float2::new(2.0, 4.0), Float2::new(6.0, 8.0))
+    );
+}
+
+#[test]
+fn float2x2_ops_neg() {
+    let a = Float2x2::new(Float2::new(1.0, -2.0), Float2::new(3.0, 0.5));
+    let result = -a;
+    assert_eq!(
+        result,
+        Float2x2::new(Float2::new(-1.0, 2.0), Float2::new(-3.0, -0.5))
+    );
+}
+
+#[test]
+fn float2x2_identity() {
+    let a = Float2x2::new(Float2::new(1.0, 2.0), Float2::new(3.0, 4.0));
+    assert_eq!(mul(a, Float2x2::identity()), a);
+    assert_eq!(mul(Float2x2::identity(), a), a);
+}
+
+#[test]
+fn float2x2_mul() {
+    let a = Float2x2::new(Float2::new(1.0, 2.0), Float2::new(3.0, 4.0));
+    let b = Float2x2::new(Float2::new(5.0, 6.0), Float2::new(7.0, 8.0));
+    assert_eq!(
+        mul(a, b),
+        Float2x2::new(Float2::new(19.0, 22.0), Float2::new(43.0, 50.0))
+    );
+
+    let v = Float2::new(1.0, -1.0);
+    assert_eq!(mul(a, v), Float2::new(-1.0, -1.0));
+    assert_eq!(mul(v, a), Float2::new(-2.0, -2.0));
+    assert_eq!(mul(v, v), 2.0);
+}
+
+#[test]
+fn float2x2_columns() {
+    let a = Float2x2::new(Float2::new(1.0, 2.0), Float2::new(3.0, 4.0));
+    assert_eq!(a.c0(), Float2::new(1.0, 3.0));
+    assert_eq!(a.c1(), Float2::new(2.0, 4.0));
+}
+
+#[test]
+fn float2x2_transpose() {
+    let a = Float2x2::new(Float2::new(1.0, 2.0), Float2::new(3.0, 4.0));
+    assert_eq!(
+        a.transpose(),
+        Float2x2::new(Float2::new(1.0, 3.0), Float2::new(2.0, 4.0))
+    );
+}
+
+#[test]
+fn float2x2_determinant() {
+    let a = Float2x2::new(Float2::new(1.0, 2.0), Float2::new(3.0, 4.0));
+    assert_eq!(a.determinant(), -2.0);
+}
+
+#[test]
+fn float2x2_inverse() {
+    let a = Float2x2::new(Float2::new(1.0, 2.0), Float2::new(3.0, 4.0));
+    let result = a.inverse();
+    assert_eq!(
+        result,
+        Float2x2::new(Float2::new(-2.0, 1.0), Float2::new(1.5, -0.5))
+    );
+    assert_eq!(mul(a, result), Float2x2::identity());
+}
@@ -1,3 +1,5 @@
+use crate::math::Bool3;
+use crate::math::Discard;
 use crate::math::Float2;
 use crate::math::Float3;
 use crate::math::Float4;
@@ -271,6 +273,14 @@ fn float3_clamp() {
     assert_eq!(result.z, 0.5);
 }
 
+#[test]
+fn float3_clip() {
+    assert_eq!(Float3::from(0.0).clip(), Ok(()));
+    assert_eq!(Float3::new(1.0, 2.0, 3.0).clip(), Ok(()));
+    assert_eq!(Float3::new(1.0, -0.5, 3.0).clip(), Err(Discard));
+    assert_eq!(Float3::from(f32::NAN).clip(), Ok(()));
+}
+
 #[test]
 fn float3_cos() {
     let a = Float3::new(PI, PI * 2.0, PI * 4.0);
@@ -289,6 +299,19 @@ fn float3_cosh() {
     assert_eq!(result.z, 1.127626);
 }
 
+#[test]
+fn float3_cross() {
+    // expected values follow DXC's lowering of cross: a.yzx * b.zxy - a.zxy * b.yzx; every product is exact.
+    let a = Float3::new(1.0, 0.0, 0.0);
+    let b = Float3::new(0.0, 1.0, 0.0);
+    assert_eq!(a.cross(&b), Float3::new(0.0, 0.0, 1.0));
+    assert_eq!(b.cross(&a), Float3::new(0.0, 0.0, -1.0));
+    let a = Float3::new(1.0, 2.0, 3.0);
+    let b = Float3::new(4.0, 5.0, 6.0);
+    assert_eq!(a.cross(&b), Float3::new(-3.0, 6.0, -3.0));
+    assert_eq!(a.cross(&a), Float3::from(0.0));
+}
+
 #[test]
 fn float3_degrees() {
     let a = Float3::new(PI, PI * 0.5, PI * 0.25);
@@ -314,6 +337,13 @@ fn float3_dot() {
     assert_eq!(result, 10.25);
 }
 
+#[test]
+fn float3_eq() {
+    let a = Float3::new(1.0, 2.0, 3.0);
+    let b = Float3::new(2.0, 2.0, 1.0);
+    assert_eq!(a.eq(&b), Bool3::new(false, true, false));
+}
+
 #[test]
 fn float3_exp() {
     let a = Float3::new(2.0, 4.0, 8.0);
@@ -332,6 +362,20 @@ fn float3_exp2() {
     assert_eq!(result.z, 256.0);
 }
 
+#[test]
+fn float3_faceforward() {
+    // expected values follow DXC's lowering `dot(i, ng) < 0 ? n : -n` with an ordered compare.
+    let n = Float3::new(0.0, 1.0, 0.0);
+    let ng = Float3::new(0.0, 1.0, 0.0);
+    assert_eq!(n.faceforward(&Float3::new(0.0, -1.0, 0.0), &ng), n);
+    assert_eq!(
+        n.faceforward(&Float3::new(1.0, 1.0, 0.0), &ng),
+        Float3::new(-0.0, -1.0, -0.0)
+    );
+    // a NaN dot product fails the compare, so the normal is flipped.
+    assert_eq!(n.faceforward(&Float3::new(f32::NAN, 1.0, 0.0), &ng), -n);
+}
+
 #[test]
 fn float3_floor() {
     let a = Float3::new(0.9, -0.2, 1.2);
@@ -360,6 +404,50 @@ fn float3_frac() {
     assert_eq!(result.z, 0.75);
 }
 
+#[test]
+fn float3_frexp() {
+    let a = Float3::new(8.0, -0.375, 1.0);
+    let (mantissa, exponent) = a.frexp();
+    assert_eq!(mantissa, Float3::new(0.5, -0.75, 0.5));
+    assert_eq!(exponent, Float3::new(4.0, -1.0, 1.0));
+    assert_eq!(mantissa.ldexp(&exponent), a);
+    let (mantissa, exponent) = Float3::from(f32::MIN_POSITIVE / 4.0).frexp();
+    assert_eq!(mantissa, Float3::from(0.5));
+    assert_eq!(exponent, Float3::from(-127.0));
+}
+
+#[test]
+fn float3_ge() {
+    let a = Float3::new(1.0, 2.0, 3.0);
+    let b = Float3::new(2.0, 2.0, 1.0);
+    assert_eq!(a.ge(&b), Bool3::new(false, true, true));
+}
+
+#[test]
+fn float3_gt() {
+    let a = Float3::new(1.0, 2.0, 3.0);
+    let b = Float3::new(2.0, 2.0, 1.0);
+    assert_eq!(a.gt(&b), Bool3::new(false, false, true));
+}
+
+#[test]
+fn float3_isfinite() {
+    let a = Float3::new(f32::NAN, f32::INFINITY, 1.0);
+    assert_eq!(a.isfinite(), Bool3::new(false, false, true));
+}
+
+#[test]
+fn float3_isinf() {
+    let a = Float3::new(f32::NAN, f32::INFINITY, 1.0);
+    assert_eq!(a.isinf(), Bool3::new(false, true, false));
+}
+
+#[test]
+fn float3_isnan() {
+    let a = Float3::new(f32::NAN, f32::INFINITY, 1.0);
+    assert_eq!(a.isnan(), Bool3::new(true, false, false));
+}
+
 #[test]
 fn float3_ldexp() {
     let value = Float3::new(1.5, 2.5, 1.0);
@@ -370,6 +458,13 @@ fn float3_ldexp() {
     assert_eq!(result.z, 2.0);
 }
 
+#[test]
+fn float3_le() {
+    let a = Float3::new(1.0, 2.0, 3.0);
+    let b = Float3::new(2.0, 2.0, 1.0);
+    assert_eq!(a.le(&b), Bool3::new(true, true, false));
+}
+
 #[test]
 fn float3_length() {
     let a = Float3::new(1.0, 1.0, 1.0);
@@ -403,6 +498,13 @@ fn float3_log2() {
     assert_eq!(a.log2(), Float3::new(0.0, 1.0, 2.0));
 }
 
+#[test]
+fn float3_lt() {
+    let a = Float3::new(1.0, 2.0, 3.0);
+    let b = Float3::new(2.0, 2.0, 1.0);
+    assert_eq!(a.lt(&b), Bool3::new(true, false, false));
+}
+
 #[test]
 fn float3_mad() {
     let a = Float3::new(2.0, 2.0, 5.0);
@@ -425,6 +527,21 @@ fn float3_min() {
     assert_eq!(a.min(&b), Float3::new(2.0, 1.0, 2.0));
 }
 
+#[test]
+fn float3_modf() {
+    let a = Float3::new(-2.75, 2.5, 0.0);
+    let (fractional, integer) = a.modf();
+    assert_eq!(fractional, Float3::new(-0.75, 0.5, 0.0));
+    assert_eq!(integer, Float3::new(-2.0, 2.0, 0.0));
+}
+
+#[test]
+fn float3_ne() {
+    let a = Float3::new(1.0, 2.0, 3.0);
+    let b = Float3::new(2.0, 2.0, 1.0);
+    assert_eq!(a.ne(&b), Bool3::new(true, false, true));
+}
+
 #[test]
 fn float3_normalize() {
     let a = Float3::new(2.0, 1.0, 3.0);
@@ -526,6 +643,14 @@ fn float3_sin() {
     assert_eq!(result.z, 0.84147096);
 }
 
+#[test]
+fn float3_sincos() {
+    let a = Float3::new(0.0, 1.0, 2.0);
+    let (sin, cos) = a.sincos();
+    assert_eq!(sin, a.sin());
+    assert_eq!(cos, a.cos());
+}
+
 #[test]
 fn float3_sinh() {
     let a = Float3::new(0.9, -0.2, 1.0);
@@ -0,0 +1,102 @@
+use crate::math::Float2;
+use crate::math::Float2x2;
+use crate::math::Float2x3;
+use crate::math::Float2x4;
+use crate::math::Float3;
+use crate::math::Float3x2;
+use crate::math::Float3x3;
+use crate::math::Float3x4;
+use crate::math::Float4;
+use crate::math::mul;
+
+#[test]
+fn float3x2_from() {
+    let result = Float3x2::new(
+        Float2::new(-2.0, 1.0),
+        Float2::new(4.0, 0.0),
+        Float2::new(3.0, -1.0),
+    );
+    assert_eq!(result.r0, Float2::new(-2.0, 1.0));
+    assert_eq!(result.r1, Float2::new(4.0, 0.0));
+    assert_eq!(result.r2, Float2::new(3.0, -1.0));
+}
+
+#[test]
+fn float3x2_ops() {
+    let a = Float3x2::new(
+        Float2::new(-2.0, 1.0),
+        Float2::new(4.0, 0.0),
+        Float2::new(3.0, -1.0),
+    );
+    let result = a * 2.0 - a;
+    assert_eq!(result, a);
+    let result = -a + a;
+    assert_eq!(result, Float3x2::from(0.0));
+}
+
+#[test]
+fn float3x2_mul_vector() {
+    let a = Float3x2::new(
+        Float2::new(-2.0, 1.0),
+        Float2::new(4.0, 0.0),
+        Float2::new(3.0, -1.0),
+    );
+    assert_eq!(mul(a, Float2::new(1.0, -1.0)), Float3::new(-3.0, 4.0, 4.0));
+    assert_eq!(mul(Float3::new(2.0, 1.0, -1.0), a), Float2::new(-3.0, 3.0));
+}
+
+#[test]
+fn float3x2_mul_matrix() {
+    let a = Float3x2::new(
+        Float2::new(-2.0, 1.0),
+        Float2::new(4.0, 0.0),
+        Float2::new(3.0, -1.0),
+    );
+    let b = Float2x2::new(Float2::new(-2.0, 3.0), Float2::new(1.0, -1.0));
+    assert_eq!(
+        mul(a, b),
+        Float3x2::new(
+            Float2::new(5.0, -7.0),
+            Float2::new(-8.0, 12.0),
+            Float2::new(-7.0, 10.0),
+        )
+    );
+    let b = Float2x3::new(Float3::new(-2.0, 3.0, 1.0), Float3::new(-1.0, 4.0, 2.0));
+    assert_eq!(
+        mul(a, b),
+        Float3x3::new(
+            Float3::new(3.0, -2.0, 0.0),
+            Float3::new(-8.0, 12.0, 4.0),
+            Float3::new(-5.0, 5.0, 1.0),
+        )
+    );
+    let b = Float2x4::new(
+        Float4::new(-2.0, 3.0, 1.0, -1.0),
+        Float4::new(4.0, 2.0, 0.0, -2.0),
+    );
+    assert_eq!(
+        mul(a, b),
+        Float3x4::new(
+            Float4::new(8.0, -4.0, -2.0, 0.0),
+            Float4::new(-8.0, 12.0, 4.0, -4.0),
+            Float4::new(-10.0, 7.0, 3.0, -1.0),
+        )
+    );
+}
+
+#[test]
+fn float3x2_transpose() {
+    let a = Float3x2::new(
+        Float2::new(-2.0, 1.0),
+        Float2::new(4.0, 0.0),
+        Float2::new(3.0, -1.0),
+    );
+    let result = a.transpose();
+    assert_eq!(
+        result,
+        Float2x3::new(Float3::new(-2.0, 4.0, 3.0), Float3::new(1.0, 0.0, -1.0),)
+    );
+    assert_eq!(result.transpose(), a);
+    assert_eq!(a.c0(), result.r0);
+    assert_eq!(a.c1(), result.r1);
+}
@@ -0,0 +1,119 @@
+use crate::math::Float3;
+use crate::math::Float3x3;
+use crate::math::mul;
+
+#[test]
+fn float3x3_from() {
+    let result = Float3x3::new(
+        Float3::new(1.0, 2.0, 3.0),
+        Float3::new(4.0, 5.0, 6.0),
+        Float3::new(7.0, 8.0, 9.0),
+    );
+    assert_eq!(result.r0, Float3::new(1.0, 2.0, 3.0));
+    assert_eq!(result.r1, Float3::new(4.0, 5.0, 6.0));
+    assert_eq!(result.r2, Float3::new(7.0, 8.0, 9.0));
+}
+
+#[test]
+fn float3x3_ops_sub() {
+    let a = Float3x3::new(
+        Float3::new(1.0, 2.0, 3.0),
+        Float3::new(4.0, 5.0, 6.0),
+        Float3::new(7.0, 8.0, 9.0),
+    );
+    let mut result = a - 1.0;
+    assert_eq!(result.r0, Float3::new(0.0, 1.0, 2.0));
+    result -= a;
+    assert_eq!(result, Float3x3::from(-1.0));
+}
+
+#[test]
+fn float3x3_identity() {
+    let a = Float3x3::new(
+        Float3::new(1.0, 2.0, 3.0),
+        Float3::new(4.0, 5.0, 6.0),
+        Float3::new(7.0, 8.0, 9.0),
+    );
+    assert_eq!(mul(a, Float3x3::identity()), a);
+    assert_eq!(mul(Float3x3::identity(), a), a);
+}
+
+#[test]
+fn float3x3_mul() {
+    let a = Float3x3::new(
+        Float3::new(1.0, 2.0, 3.0),
+        Float3::new(4.0, 5.0, 6.0),
+        Float3::new(7.0, 8.0, 9.0),
+    );
+    let b = Float3x3::new(
+        Float3::new(1.0, 0.0, 1.0),
+        Float3::new(0.0, 2.0, 0.0),
+        Float3::new(1.0, 0.0, -1.0),
+    );
+    assert_eq!(
+        mul(a, b),
+        Float3x3::new(
+            Float3::new(4.0, 4.0, -2.0),
+            Float3::new(10.0, 10.0, -2.0),
+            Float3::new(16.0, 16.0, -2.0),
+        )
+    );
+
+    let v = Float3::new(1.0, 0.0, -1.0);
+    assert_eq!(mul(a, v), Float3::new(-2.0, -2.0, -2.0));
+    assert_eq!(mul(v, a), Float3::new(-6.0, -6.0, -6.0));
+}
+
+#[test]
+fn float3x3_transpose() {
+    let a = Float3x3::new(
+        Float3::new(1.0, 2.0, 3.0),
+        Float3::new(4.0, 5.0, 6.0),
+        Float3::new(7.0, 8.0, 9.0),
+    );
+    assert_eq!(
+        a.transpose(),
+        Float3x3::new(
+            Float3::new(1.0, 4.0, 7.0),
+            Float3::new(2.0, 5.0, 8.0),
+            Float3::new(3.0, 6.0, 9.0),
+        )
+    );
+    assert_eq!(a.c2(), Float3::new(3.0, 6.0, 9.0));
+}
+
+#[test]
+fn float3x3_determinant() {
+    let a = Float3x3::new(
+        Float3::new(1.0, 2.0, 3.0),
+        Float3::new(4.0, 5.0, 6.0),
+        Float3::new(7.0, 8.0, 9.0),
+    );
+    assert_eq!(a.determinant(), 0.0);
+
+    let a = Float3x3::new(
+        Float3::new(1.0, 2.0, 3.0),
+        Float3::new(0.0, 1.0, 4.0),
+        Float3::new(5.0, 6.0, 0.0),
+    );
+    assert_eq!(a.determinant(), 1.0);
+}
+
+#[test]
+fn float3x3_inverse() {
+    let a = Float3x3::new(
+        Float3::new(1.0, 2.0, 3.0),
+        Float3::new(0.0, 1.0, 4.0),
+        Float3::new(5.0, 6.0, 0.0),
+    );
+    let result = a.inverse();
+    assert_eq!(
+        result,
+        Float3x3::new(
+            Float3::new(-24.0, 18.0, 5.0),
+            Float3::new(20.0, -15.0, -4.0),
+            Float3::new(-5.0, 4.0, 1.0),
+        )
+    );
+    assert_eq!(mul(a, result), Float3x3::identity());
+}
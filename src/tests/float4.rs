@@ -1,3 +1,5 @@
+use crate::math::Bool4;
+use crate::math::Discard;
 use crate::math::Float2;
 use crate::math::Float3;
 use crate::math::Float4;
@@ -303,6 +305,14 @@ fn float4_clamp() {
     assert_eq!(result.w, 0.0);
 }
 
+#[test]
+fn float4_clip() {
+    assert_eq!(Float4::from(0.0).clip(), Ok(()));
+    assert_eq!(Float4::new(1.0, 2.0, 3.0, 4.0).clip(), Ok(()));
+    assert_eq!(Float4::new(1.0, -0.5, 3.0, 4.0).clip(), Err(Discard));
+    assert_eq!(Float4::from(f32::NAN).clip(), Ok(()));
+}
+
 #[test]
 fn float4_cos() {
     let a = Float4::new(PI, PI * 2.0, PI * 4.0, -PI);
@@ -341,6 +351,18 @@ fn float4_distance() {
     assert_eq!(result, 8.0);
 }
 
+#[test]
+fn float4_dst() {
+    // expected values follow DXC's lowering of dst: (1, a.y * b.y, a.z, b.w); every product is exact.
+    let d = 4.0;
+    let a = Float4::new(0.0, d * d, d * d, 0.0);
+    let b = Float4::new(0.0, 1.0 / d, 0.0, 1.0 / d);
+    assert_eq!(a.dst(&b), Float4::new(1.0, 4.0, 16.0, 0.25));
+    let a = Float4::new(9.0, 2.0, 3.0, 9.0);
+    let b = Float4::new(9.0, 5.0, 9.0, 7.0);
+    assert_eq!(a.dst(&b), Float4::new(1.0, 10.0, 3.0, 7.0));
+}
+
 #[test]
 fn float4_dot() {
     let a = Float4::new(1.0, 1.0, 0.5, 0.25);
@@ -349,6 +371,13 @@ fn float4_dot() {
     assert_eq!(result, 10.3125);
 }
 
+#[test]
+fn float4_eq() {
+    let a = Float4::new(1.0, 2.0, 3.0, 4.0);
+    let b = Float4::new(2.0, 2.0, 1.0, 4.0);
+    assert_eq!(a.eq(&b), Bool4::new(false, true, false, true));
+}
+
 #[test]
 fn float4_exp() {
     let a = Float4::new(2.0, 4.0, 8.0, 16.0);
@@ -369,6 +398,23 @@ fn float4_exp2() {
     assert_eq!(result.w, 65536.0);
 }
 
+#[test]
+fn float4_faceforward() {
+    // expected values follow DXC's lowering `dot(i, ng) < 0 ? n : -n` with an ordered compare.
+    let n = Float4::new(0.0, 1.0, 0.0, 0.0);
+    let ng = Float4::new(0.0, 1.0, 0.0, 0.0);
+    assert_eq!(n.faceforward(&Float4::new(0.0, -1.0, 0.0, 0.0), &ng), n);
+    assert_eq!(
+        n.faceforward(&Float4::new(1.0, 1.0, 0.0, 0.0), &ng),
+        Float4::new(-0.0, -1.0, -0.0, -0.0)
+    );
+    // a NaN dot product fails the compare, so the normal is flipped.
+    assert_eq!(
+        n.faceforward(&Float4::new(f32::NAN, 1.0, 0.0, 0.0), &ng),
+        -n
+    );
+}
+
 #[test]
 fn float4_floor() {
     let a = Float4::new(0.9, -0.2, 1.2, 0.1);
@@ -400,6 +446,50 @@ fn float4_frac() {
     assert_eq!(result.w, 0.8);
 }
 
+#[test]
+fn float4_frexp() {
+    let a = Float4::new(8.0, -0.375, 1.0, 0.0);
+    let (mantissa, exponent) = a.frexp();
+    assert_eq!(mantissa, Float4::new(0.5, -0.75, 0.5, 0.0));
+    assert_eq!(exponent, Float4::new(4.0, -1.0, 1.0, 0.0));
+    assert_eq!(mantissa.ldexp(&exponent), a);
+    let (mantissa, exponent) = Float4::from(f32::MIN_POSITIVE / 4.0).frexp();
+    assert_eq!(mantissa, Float4::from(0.5));
+    assert_eq!(exponent, Float4::from(-127.0));
+}
+
+#[test]
+fn float4_ge() {
+    let a = Float4::new(1.0, 2.0, 3.0, 4.0);
+    let b = Float4::new(2.0, 2.0, 1.0, 4.0);
+    assert_eq!(a.ge(&b), Bool4::new(false, true, true, true));
+}
+
+#[test]
+fn float4_gt() {
+    let a = Float4::new(1.0, 2.0, 3.0, 4.0);
+    let b = Float4::new(2.0, 2.0, 1.0, 4.0);
+    assert_eq!(a.gt(&b), Bool4::new(false, false, true, false));
+}
+
+#[test]
+fn float4_isfinite() {
+    let a = Float4::new(f32::NAN, f32::INFINITY, 1.0, f32::NEG_INFINITY);
+    assert_eq!(a.isfinite(), Bool4::new(false, false, true, false));
+}
+
+#[test]
+fn float4_isinf() {
+    let a = Float4::new(f32::NAN, f32::INFINITY, 1.0, f32::NEG_INFINITY);
+    assert_eq!(a.isinf(), Bool4::new(false, true, false, true));
+}
+
+#[test]
+fn float4_isnan() {
+    let a = Float4::new(f32::NAN, f32::INFINITY, 1.0, f32::NEG_INFINITY);
+    assert_eq!(a.isnan(), Bool4::new(true, false, false, false));
+}
+
 #[test]
 fn float4_ldexp() {
     let value = Float4::new(1.5, 2.5, 1.0, 0.5);
@@ -411,6 +501,13 @@ fn float4_ldexp() {
     assert_eq!(result.w, 0.5);
 }
 
+#[test]
+fn float4_le() {
+    let a = Float4::new(1.0, 2.0, 3.0, 4.0);
+    let b = Float4::new(2.0, 2.0, 1.0, 4.0);
+    assert_eq!(a.le(&b), Bool4::new(true, true, false, true));
+}
+
 #[test]
 fn float4_length() {
     let a = Float4::new(1.0, 1.0, 1.0, 1.0);
@@ -426,6 +523,17 @@ fn float4_lerp() {
     assert_eq!(a.lerp(&b, 1.5), Float4::new(3.0, 6.1, 1.25, 2.5));
 }
 
+#[test]
+fn float4_lit() {
+    // expected values follow DXC's lowering of lit: (1, max(n_dot_l, 0), n_dot_l < 0 || n_dot_h < 0 ? 0 :
+    // pow(n_dot_h, m), 1); reproduce with `o[0] = lit(0.5, 0.5, 2.0);` in a cs_6_0 shader.
+    assert_eq!(Float4::lit(0.5, 0.5, 2.0), Float4::new(1.0, 0.5, 0.25, 1.0));
+    // pow(0, 0) is 1 with powf, unlike the NaN of exp2(0 * log2(0)) on most GPUs.
+    assert_eq!(Float4::lit(0.8, 0.0, 0.0), Float4::new(1.0, 0.8, 1.0, 1.0));
+    assert_eq!(Float4::lit(-0.5, 0.5, 2.0), Float4::new(1.0, 0.0, 0.0, 1.0));
+    assert_eq!(Float4::lit(0.5, -0.5, 2.0), Float4::new(1.0, 0.5, 0.0, 1.0));
+}
+
 #[test]
 fn float4_log() {
     let a = Float4::new(1.0, 2.0, 4.0, 8.0);
@@ -444,6 +552,13 @@ fn float4_log2() {
     assert_eq!(a.log2(), Float4::new(0.0, 1.0, 2.0, 3.0));
 }
 
+#[test]
+fn float4_lt() {
+    let a = Float4::new(1.0, 2.0, 3.0, 4.0);
+    let b = Float4::new(2.0, 2.0, 1.0, 4.0);
+    assert_eq!(a.lt(&b), Bool4::new(true, false, false, false));
+}
+
 #[test]
 fn float4_mad() {
     let a = Float4::new(2.0, 2.0, 5.0, 2.0);
@@ -466,6 +581,21 @@ fn float4_min() {
     assert_eq!(a.min(&b), Float4::new(2.0, 1.0, 2.0, 1.0));
 }
 
+#[test]
+fn float4_modf() {
+    let a = Float4::new(-2.75, 2.5, 0.0, 8.125);
+    let (fractional, integer) = a.modf();
+    assert_eq!(fractional, Float4::new(-0.75, 0.5, 0.0, 0.125));
+    assert_eq!(integer, Float4::new(-2.0, 2.0, 0.0, 8.0));
+}
+
+#[test]
+fn float4_ne() {
+    let a = Float4::new(1.0, 2.0, 3.0, 4.0);
+    let b = Float4::new(2.0, 2.0, 1.0, 4.0);
+    assert_eq!(a.ne(&b), Bool4::new(true, false, true, false));
+}
+
 #[test]
 fn float4_normalize() {
     let a = Float4::new(2.0, 1.0, 3.0, 5.0);
@@ -579,6 +709,14 @@ fn float4_sin() {
     assert_eq!(result.w, 0.89399666);
 }
 
+#[test]
+fn float4_sincos() {
+    let a = Float4::new(0.0, 1.0, 2.0, 3.0);
+    let (sin, cos) = a.sincos();
+    assert_eq!(sin, a.sin());
+    assert_eq!(cos, a.cos());
+}
+
 #[test]
 fn float4_sinh() {
     let a = Float4::new(0.9, -0.2, 1.0, 0.5);
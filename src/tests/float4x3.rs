@@ -0,0 +1,131 @@
+use crate::math::Float2;
+use crate::math::Float3;
+use crate::math::Float3x2;
+use crate::math::Float3x3;
+use crate::math::Float3x4;
+use crate::math::Float4;
+use crate::math::Float4x2;
+use crate::math::Float4x3;
+use crate::math::Float4x4;
+use crate::math::mul;
+
+#[test]
+fn float4x3_from() {
+    let result = Float4x3::new(
+        Float3::new(-2.0, 1.0, 4.0),
+        Float3::new(0.0, 3.0, -1.0),
+        Float3::new(2.0, -2.0, 1.0),
+        Float3::new(4.0, 0.0, 3.0),
+    );
+    assert_eq!(result.r0, Float3::new(-2.0, 1.0, 4.0));
+    assert_eq!(result.r1, Float3::new(0.0, 3.0, -1.0));
+    assert_eq!(result.r2, Float3::new(2.0, -2.0, 1.0));
+    assert_eq!(result.r3, Float3::new(4.0, 0.0, 3.0));
+}
+
+#[test]
+fn float4x3_ops() {
+    let a = Float4x3::new(
+        Float3::new(-2.0, 1.0, 4.0),
+        Float3::new(0.0, 3.0, -1.0),
+        Float3::new(2.0, -2.0, 1.0),
+        Float3::new(4.0, 0.0, 3.0),
+    );
+    let result = a * 2.0 - a;
+    assert_eq!(result, a);
+    let result = -a + a;
+    assert_eq!(result, Float4x3::from(0.0));
+}
+
+#[test]
+fn float4x3_mul_vector() {
+    let a = Float4x3::new(
+        Float3::new(-2.0, 1.0, 4.0),
+        Float3::new(0.0, 3.0, -1.0),
+        Float3::new(2.0, -2.0, 1.0),
+        Float3::new(4.0, 0.0, 3.0),
+    );
+    assert_eq!(
+        mul(a, Float3::new(1.0, -1.0, 2.0)),
+        Float4::new(5.0, -5.0, 6.0, 10.0)
+    );
+    assert_eq!(
+        mul(Float4::new(2.0, 1.0, -1.0, 3.0), a),
+        Float3::new(6.0, 7.0, 15.0)
+    );
+}
+
+#[test]
+fn float4x3_mul_matrix() {
+    let a = Float4x3::new(
+        Float3::new(-2.0, 1.0, 4.0),
+        Float3::new(0.0, 3.0, -1.0),
+        Float3::new(2.0, -2.0, 1.0),
+        Float3::new(4.0, 0.0, 3.0),
+    );
+    let b = Float3x2::new(
+        Float2::new(-2.0, 3.0),
+        Float2::new(1.0, -1.0),
+        Float2::new(4.0, 2.0),
+    );
+    assert_eq!(
+        mul(a, b),
+        Float4x2::new(
+            Float2::new(21.0, 1.0),
+            Float2::new(-1.0, -5.0),
+            Float2::new(-2.0, 10.0),
+            Float2::new(4.0, 18.0),
+        )
+    );
+    let b = Float3x3::new(
+        Float3::new(-2.0, 3.0, 1.0),
+        Float3::new(-1.0, 4.0, 2.0),
+        Float3::new(0.0, -2.0, 3.0),
+    );
+    assert_eq!(
+        mul(a, b),
+        Float4x3::new(
+            Float3::new(3.0, -10.0, 12.0),
+            Float3::new(-3.0, 14.0, 3.0),
+            Float3::new(-2.0, -4.0, 1.0),
+            Float3::new(-8.0, 6.0, 13.0),
+        )
+    );
+    let b = Float3x4::new(
+        Float4::new(-2.0, 3.0, 1.0, -1.0),
+        Float4::new(4.0, 2.0, 0.0, -2.0),
+        Float4::new(3.0, 1.0, -1.0, 4.0),
+    );
+    assert_eq!(
+        mul(a, b),
+        Float4x4::new(
+            Float4::new(20.0, 0.0, -6.0, 16.0),
+            Float4::new(9.0, 5.0, 1.0, -10.0),
+            Float4::new(-9.0, 3.0, 1.0, 6.0),
+            Float4::new(1.0, 15.0, 1.0, 8.0),
+        )
+    );
+}
+
+#[test]
+fn float4x3_transpose() {
+    let a = Float4x3::new(
+        Float3::new(-2.0, 1.0, 4.0),
+        Float3::new(0.0, 3.0, -1.0),
+        Float3::new(2.0, -2.0, 1.0),
+        Float3::new(4.0, 0.0, 3.0),
+    );
+    let result = a.transpose();
+    assert_eq!(
+        result,
+        Float3x4::new(
+            Float4::new(-2.0, 0.0, 2.0, 4.0),
+            Float4::new(1.0, 3.0, -2.0, 0.0),
+            Float4::new(4.0, -1.0, 1.0, 3.0),
+        )
+    );
+    assert_eq!(result.transpose(), a);
+    assert_eq!(a.c0(), result.r0);
+    assert_eq!(a.c1(), result.r1);
+    assert_eq!(a.c2(), result.r2);
+}
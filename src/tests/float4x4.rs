@@ -0,0 +1,298 @@
+use crate::math::EulerOrder;
+use crate::math::Float3;
+use crate::math::Float4;
+use crate::math::Float4x4;
+use crate::math::Quaternion;
+use crate::math::mul;
+use crate::tests::helpers::assert_near;
+use core::f32::consts::FRAC_PI_2;
+use core::f32::consts::PI;
+
+fn sample() -> Float4x4 {
+    Float4x4::new(
+        Float4::new(1.0, 0.0, 2.0, 0.0),
+        Float4::new(0.0, 1.0, 0.0, 3.0),
+        Float4::new(0.0, 0.0, 1.0, 0.0),
+        Float4::new(4.0, 0.0, 0.0, 1.0),
+    )
+}
+
+#[test]
+fn float4x4_from() {
+    let result = Float4x4::from((
+        Float4::new(1.0, 2.0, 3.0, 4.0),
+        Float4::new(5.0, 6.0, 7.0, 8.0),
+        Float4::new(9.0, 10.0, 11.0, 12.0),
+        Float4::new(13.0, 14.0, 15.0, 16.0),
+    ));
+    assert_eq!(result.r0, Float4::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(result.r1, Float4::new(5.0, 6.0, 7.0, 8.0));
+    assert_eq!(result.r2, Float4::new(9.0, 10.0, 11.0, 12.0));
+    assert_eq!(result.r3, Float4::new(13.0, 14.0, 15.0, 16.0));
+}
+
+#[test]
+fn float4x4_ops_div() {
+    let a = Float4x4::from(8.0);
+    let mut result = a / 2.0;
+    assert_eq!(result, Float4x4::from(4.0));
+    result /= Float4x4::from(4.0);
+    assert_eq!(result, Float4x4::from(1.0));
+}
+
+#[test]
+fn float4x4_identity() {
+    let a = sample();
+    assert_eq!(mul(a, Float4x4::identity()), a);
+    assert_eq!(mul(Float4x4::identity(), a), a);
+}
+
+#[test]
+fn float4x4_mul() {
+    let a = sample();
+    let b = Float4x4::new(
+        Float4::new(1.0, 2.0, 3.0, 4.0),
+        Float4::new(5.0, 6.0, 7.0, 8.0),
+        Float4::new(9.0, 10.0, 11.0, 12.0),
+        Float4::new(13.0, 14.0, 15.0, 16.0),
+    );
+    assert_eq!(
+        mul(a, b),
+        Float4x4::new(
+            Float4::new(19.0, 22.0, 25.0, 28.0),
+            Float4::new(44.0, 48.0, 52.0, 56.0),
+            Float4::new(9.0, 10.0, 11.0, 12.0),
+            Float4::new(17.0, 22.0, 27.0, 32.0),
+        )
+    );
+
+    // column vector on the right-hand side.
+    let v = Float4::new(1.0, 2.0, 3.0, 1.0);
+    assert_eq!(mul(a, v), Float4::new(7.0, 5.0, 3.0, 5.0));
+
+    // row vector on the left-hand side.
+    assert_eq!(mul(v, a), Float4::new(5.0, 2.0, 5.0, 7.0));
+}
+
+#[test]
+fn float4x4_transpose() {
+    let a = sample();
+    let result = a.transpose();
+    assert_eq!(result.r0, Float4::new(1.0, 0.0, 0.0, 4.0));
+    assert_eq!(result.r1, Float4::new(0.0, 1.0, 0.0, 0.0));
+    assert_eq!(result.r2, Float4::new(2.0, 0.0, 1.0, 0.0));
+    assert_eq!(result.r3, Float4::new(0.0, 3.0, 0.0, 1.0));
+    assert_eq!(result.transpose(), a);
+}
+
+#[test]
+fn float4x4_determinant() {
+    assert_eq!(Float4x4::identity().determinant(), 1.0);
+    assert_eq!(sample().determinant(), 1.0);
+    let a = Float4x4::new(
+        Float4::new(2.0, 0.0, 0.0, 0.0),
+        Float4::new(0.0, 4.0, 0.0, 0.0),
+        Float4::new(1.0, 0.0, 0.5, 0.0),
+        Float4::new(1.0, 2.0, 3.0, -1.0),
+    );
+    assert_eq!(a.determinant(), -4.0);
+    assert_eq!(Float4x4::from(1.0).determinant(), 0.0);
+}
+
+#[test]
+fn float4x4_inverse() {
+    let a = Float4x4::new(
+        Float4::new(2.0, 0.0, 0.0, 0.0),
+        Float4::new(0.0, 4.0, 0.0, 0.0),
+        Float4::new(0.0, 0.0, 0.5, 0.0),
+        Float4::new(1.0, 2.0, 3.0, 1.0),
+    );
+    let result = a.inverse();
+    assert_eq!(
+        result,
+        Float4x4::new(
+            Float4::new(0.5, 0.0, 0.0, 0.0),
+            Float4::new(0.0, 0.25, 0.0, 0.0),
+            Float4::new(0.0, 0.0, 2.0, 0.0),
+            Float4::new(-0.5, -0.5, -6.0, 1.0),
+        )
+    );
+    assert_eq!(mul(a, result), Float4x4::identity());
+    assert_eq!(mul(sample(), sample().inverse()), Float4x4::identity());
+}
+
+fn project(p: Float3, m: Float4x4) -> Float3 {
+    let clip = mul(Float4::from((p, 1.0)), m);
+    clip.xyz() / clip.w
+}
+
+#[test]
+fn float4x4_look_at() {
+    let eye = Float3::new(1.0, 2.0, 3.0);
+    let target = Float3::new(1.0, 2.0, 8.0);
+    let up = Float3::new(0.0, 1.0, 0.0);
+    let view = Float4x4::look_at_lh(&eye, &target, &up);
+    assert_near(project(eye, view), Float3::from(0.0));
+    assert_near(project(target, view), Float3::new(0.0, 0.0, 5.0));
+    assert_near(
+        project(Float3::new(2.0, 3.0, 3.0), view),
+        Float3::new(1.0, 1.0, 0.0),
+    );
+    let view = Float4x4::look_at_rh(&eye, &target, &up);
+    assert_near(project(target, view), Float3::new(0.0, 0.0, -5.0));
+    assert_near(
+        project(Float3::new(2.0, 3.0, 3.0), view),
+        Float3::new(-1.0, 1.0, 0.0),
+    );
+}
+
+#[test]
+fn float4x4_perspective_fov() {
+    let fov = FRAC_PI_2;
+    let lh = Float4x4::perspective_fov_lh(fov, 2.0, 1.0, 100.0);
+    assert_near(
+        project(Float3::new(0.0, 0.0, 1.0), lh),
+        Float3::new(0.0, 0.0, 0.0),
+    );
+    assert_near(
+        project(Float3::new(20.0, 10.0, 10.0), lh),
+        Float3::new(1.0, 1.0, 0.90909094),
+    );
+    assert_near(
+        project(Float3::new(0.0, 0.0, 100.0), lh),
+        Float3::new(0.0, 0.0, 1.0),
+    );
+    let rh = Float4x4::perspective_fov_rh(fov, 2.0, 1.0, 100.0);
+    assert_near(
+        project(Float3::new(0.0, 0.0, -1.0), rh),
+        Float3::new(0.0, 0.0, 0.0),
+    );
+    assert_near(
+        project(Float3::new(20.0, 10.0, -10.0), rh),
+        Float3::new(1.0, 1.0, 0.90909094),
+    );
+    assert_near(
+        project(Float3::new(0.0, 0.0, -100.0), rh),
+        Float3::new(0.0, 0.0, 1.0),
+    );
+    let lh = Float4x4::perspective_fov_reversed_lh(fov, 2.0, 1.0, 100.0);
+    assert_near(
+        project(Float3::new(0.0, 0.0, 1.0), lh),
+        Float3::new(0.0, 0.0, 1.0),
+    );
+    assert_near(
+        project(Float3::new(0.0, 0.0, 100.0), lh),
+        Float3::new(0.0, 0.0, 0.0),
+    );
+    let rh = Float4x4::perspective_fov_reversed_rh(fov, 2.0, 1.0, 100.0);
+    assert_near(
+        project(Float3::new(0.0, 0.0, -1.0), rh),
+        Float3::new(0.0, 0.0, 1.0),
+    );
+    assert_near(
+        project(Float3::new(0.0, 0.0, -100.0), rh),
+        Float3::new(0.0, 0.0, 0.0),
+    );
+}
+
+#[test]
+fn float4x4_perspective_fov_infinite() {
+    let fov = FRAC_PI_2;
+    let far = Float3::new(0.0, 0.0, 1.0e7);
+    let lh = Float4x4::perspective_fov_infinite_lh(fov, 1.0, 0.5);
+    assert_near(
+        project(Float3::new(0.0, 0.0, 0.5), lh),
+        Float3::new(0.0, 0.0, 0.0),
+    );
+    assert_near(
+        project(Float3::new(2.0, 1.0, 2.0), lh),
+        Float3::new(1.0, 0.5, 0.75),
+    );
+    assert_near(project(far, lh), Float3::new(0.0, 0.0, 1.0));
+    let rh = Float4x4::perspective_fov_infinite_rh(fov, 1.0, 0.5);
+    assert_near(
+        project(Float3::new(0.0, 0.0, -0.5), rh),
+        Float3::new(0.0, 0.0, 0.0),
+    );
+    assert_near(
+        project(Float3::new(2.0, 1.0, -2.0), rh),
+        Float3::new(1.0, 0.5, 0.75),
+    );
+    assert_near(project(-far, rh), Float3::new(0.0, 0.0, 1.0));
+    let lh = Float4x4::perspective_fov_infinite_reversed_lh(fov, 1.0, 0.5);
+    assert_near(
+        project(Float3::new(0.0, 0.0, 0.5), lh),
+        Float3::new(0.0, 0.0, 1.0),
+    );
+    assert_near(
+        project(Float3::new(0.0, 0.0, 2.0), lh),
+        Float3::new(0.0, 0.0, 0.25),
+    );
+    assert_near(project(far, lh), Float3::new(0.0, 0.0, 0.0));
+    let rh = Float4x4::perspective_fov_infinite_reversed_rh(fov, 1.0, 0.5);
+    assert_near(
+        project(Float3::new(0.0, 0.0, -0.5), rh),
+        Float3::new(0.0, 0.0, 1.0),
+    );
+    assert_near(
+        project(Float3::new(0.0, 0.0, -2.0), rh),
+        Float3::new(0.0, 0.0, 0.25),
+    );
+    assert_near(project(-far, rh), Float3::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn float4x4_orthographic() {
+    let lh = Float4x4::orthographic_lh(4.0, 2.0, 1.0, 11.0);
+    assert_near(
+        project(Float3::new(2.0, -1.0, 1.0), lh),
+        Float3::new(1.0, -1.0, 0.0),
+    );
+    assert_near(
+        project(Float3::new(-1.0, 0.5, 6.0), lh),
+        Float3::new(-0.5, 0.5, 0.5),
+    );
+    let rh = Float4x4::orthographic_rh(4.0, 2.0, 1.0, 11.0);
+    assert_near(
+        project(Float3::new(2.0, -1.0, -1.0), rh),
+        Float3::new(1.0, -1.0, 0.0),
+    );
+    assert_near(
+        project(Float3::new(-1.0, 0.5, -11.0), rh),
+        Float3::new(-0.5, 0.5, 1.0),
+    );
+}
+
+#[test]
+fn float4x4_transforms() {
+    let p = Float3::new(1.0, 2.0, 3.0);
+    let t = Float4x4::translation(&Float3::new(10.0, 20.0, 30.0));
+    assert_eq!(project(p, t), Float3::new(11.0, 22.0, 33.0));
+    let s = Float4x4::scale(&Float3::new(2.0, 3.0, 4.0));
+    assert_eq!(project(p, s), Float3::new(2.0, 6.0, 12.0));
+    assert_near(
+        project(p, Float4x4::rotation_x(FRAC_PI_2)),
+        Float3::new(1.0, -3.0, 2.0),
+    );
+    assert_near(
+        project(p, Float4x4::rotation_y(FRAC_PI_2)),
+        Float3::new(3.0, 2.0, -1.0),
+    );
+    assert_near(
+        project(p, Float4x4::rotation_z(FRAC_PI_2)),
+        Float3::new(-2.0, 1.0, 3.0),
+    );
+    let axis = Float3::new(1.0, 1.0, 1.0);
+    assert_near(
+        project(p, Float4x4::rotation_axis(&axis, 2.0 * PI / 3.0)),
+        Float3::new(3.0, 1.0, 2.0),
+    );
+    let angles = Float3::new(0.3, -1.1, 2.0);
+    let q = Quaternion::from_euler(&angles, EulerOrder::Zxy);
+    assert_near(
+        project(p, Float4x4::rotation_euler(&angles, EulerOrder::Zxy)),
+        q.rotate(&p),
+    );
+    let srt = mul(mul(s, Float4x4::rotation_z(FRAC_PI_2)), t);
+    assert_near(project(p, srt), Float3::new(4.0, 22.0, 42.0));
+}
@@ -0,0 +1,245 @@
+use crate::math::Aabb;
+use crate::math::Float2;
+use crate::math::Float3;
+use crate::math::Float4;
+use crate::math::Float4x4;
+use crate::math::Frustum;
+use crate::math::Obb;
+use crate::math::Plane;
+use crate::math::Quaternion;
+use crate::math::Ray;
+use crate::math::Sphere;
+use crate::math::Triangle;
+use crate::math::mul;
+use crate::tests::helpers::assert_near;
+use core::f32::consts::FRAC_PI_2;
+use core::f32::consts::FRAC_PI_4;
+
+fn unit_box() -> Aabb {
+    Aabb::new(Float3::from(-1.0), Float3::from(1.0))
+}
+
+#[test]
+fn geometry_ray_aabb() {
+    let aabb = unit_box();
+    let ray = Ray::new(Float3::new(-5.0, 0.5, 0.0), Float3::new(2.0, 0.0, 0.0));
+    assert_eq!(ray.intersect_aabb(&aabb), Some(2.0));
+    assert_eq!(ray.at(2.0), Float3::new(-1.0, 0.5, 0.0));
+    let inside = Ray::new(Float3::from(0.0), Float3::new(0.0, 1.0, 0.0));
+    assert_eq!(inside.intersect_aabb(&aabb), Some(0.0));
+    let miss = Ray::new(Float3::new(-5.0, 1.5, 0.0), Float3::new(1.0, 0.0, 0.0));
+    assert_eq!(miss.intersect_aabb(&aabb), None);
+    let behind = Ray::new(Float3::new(5.0, 0.0, 0.0), Float3::new(1.0, 0.0, 0.0));
+    assert_eq!(behind.intersect_aabb(&aabb), None);
+    let diagonal = Ray::new(Float3::from(-3.0), Float3::from(1.0));
+    assert_eq!(diagonal.intersect_aabb(&aabb), Some(2.0));
+    assert_eq!(ray.intersect_aabb_interval(&aabb), Some((2.0, 3.0)));
+}
+
+#[test]
+fn geometry_ray_aabb_axis_aligned() {
+    // the origin lies on the bounds planes of the axes the ray is parallel to.
+    let flat = Aabb::new(Float3::from(0.0), Float3::new(1.0, 1.0, 0.0));
+    let ray = Ray::new(Float3::new(0.0, 0.5, 2.0), Float3::new(0.0, 0.0, -1.0));
+    assert_eq!(ray.intersect_aabb(&flat), Some(2.0));
+    assert_eq!(ray.intersect_aabb_interval(&flat), Some((2.0, 2.0)));
+    let edge = Ray::new(Float3::new(1.0, 1.0, -3.0), Float3::new(0.0, 0.0, 1.0));
+    assert_eq!(edge.intersect_aabb(&unit_box()), Some(2.0));
+    let outside = Ray::new(Float3::new(1.5, 0.5, 2.0), Float3::new(0.0, 0.0, -1.0));
+    assert_eq!(outside.intersect_aabb(&flat), None);
+}
+
+#[test]
+fn geometry_ray_obb() {
+    let rotation = Quaternion::from_axis_angle(&Float3::new(0.0, 0.0, 1.0), FRAC_PI_4);
+    let obb = Obb::new(Float3::new(10.0, 0.0, 0.0), Float3::from(1.0), rotation);
+    let ray = Ray::new(Float3::new(0.0, 0.0, 0.0), Float3::new(1.0, 0.0, 0.0));
+    let t = ray.intersect_obb(&obb).unwrap();
+    assert!((t - (10.0 - 2.0f32.sqrt())).abs() < 1e-5);
+    let miss = Ray::new(Float3::new(0.0, 1.5, 0.0), Float3::new(1.0, 0.0, 0.0));
+    assert_eq!(miss.intersect_obb(&obb), None);
+    assert!(obb.contains(&Float3::new(11.2, 0.0, 0.0)));
+    assert!(!obb.contains(&Float3::new(10.9, 0.9, 0.0)));
+    assert_near(
+        obb.closest_point(&Float3::new(13.0, 0.0, 0.0)),
+        Float3::new(10.0 + 2.0f32.sqrt(), 0.0, 0.0),
+    );
+    assert!((obb.distance(&Float3::new(10.0, 0.0, 3.0)) - 2.0).abs() < 1e-5);
+}
+
+#[test]
+fn geometry_ray_plane() {
+    let plane = Plane::from_point_normal(&Float3::new(0.0, 2.0, 0.0), &Float3::new(0.0, 3.0, 0.0));
+    assert_eq!(plane, Plane::new(Float3::new(0.0, 1.0, 0.0), -2.0));
+    let ray = Ray::new(Float3::new(1.0, 5.0, 1.0), Float3::new(0.0, -1.0, 0.0));
+    assert_eq!(ray.intersect_plane(&plane), Some(3.0));
+    let away = Ray::new(Float3::new(1.0, 5.0, 1.0), Float3::new(0.0, 1.0, 0.0));
+    assert_eq!(away.intersect_plane(&plane), None);
+    let parallel = Ray::new(Float3::new(1.0, 5.0, 1.0), Float3::new(1.0, 0.0, 0.0));
+    assert_eq!(parallel.intersect_plane(&plane), None);
+}
+
+#[test]
+fn geometry_ray_sphere() {
+    let sphere = Sphere::new(Float3::new(0.0, 0.0, 10.0), 2.0);
+    let ray = Ray::new(Float3::from(0.0), Float3::new(0.0, 0.0, 1.0));
+    assert_eq!(ray.intersect_sphere(&sphere), Some(8.0));
+    let inside = Ray::new(Float3::new(0.0, 0.0, 9.0), Float3::new(0.0, 0.0, 1.0));
+    assert_eq!(inside.intersect_sphere(&sphere), Some(0.0));
+    let miss = Ray::new(Float3::new(0.0, 2.5, 0.0), Float3::new(0.0, 0.0, 1.0));
+    assert_eq!(miss.intersect_sphere(&sphere), None);
+    let behind = Ray::new(Float3::new(0.0, 0.0, 20.0), Float3::new(0.0, 0.0, 1.0));
+    assert_eq!(behind.intersect_sphere(&sphere), None);
+}
+
+#[test]
+fn geometry_ray_triangle() {
+    let triangle = Triangle::new(
+        Float3::new(0.0, 0.0, 0.0),
+        Float3::new(4.0, 0.0, 0.0),
+        Float3::new(0.0, 4.0, 0.0),
+    );
+    let ray = Ray::new(Float3::new(1.0, 2.0, -3.0), Float3::new(0.0, 0.0, 1.0));
+    assert_eq!(
+        ray.intersect_triangle(&triangle),
+        Some((3.0, Float2::new(0.25, 0.5)))
+    );
+    let back = Ray::new(Float3::new(1.0, 2.0, 3.0), Float3::new(0.0, 0.0, -1.0));
+    assert_eq!(
+        back.intersect_triangle(&triangle),
+        Some((3.0, Float2::new(0.25, 0.5)))
+    );
+    let miss = Ray::new(Float3::new(3.0, 3.0, -3.0), Float3::new(0.0, 0.0, 1.0));
+    assert_eq!(miss.intersect_triangle(&triangle), None);
+    let parallel = Ray::new(Float3::new(-1.0, 1.0, 0.0), Float3::new(1.0, 0.0, 0.0));
+    assert_eq!(parallel.intersect_triangle(&triangle), None);
+    assert_eq!(triangle.normal(), Float3::new(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn geometry_distance() {
+    let ray = Ray::new(Float3::from(0.0), Float3::new(2.0, 0.0, 0.0));
+    assert_eq!(ray.distance(&Float3::new(5.0, 3.0, 0.0)), 3.0);
+    assert_eq!(ray.distance(&Float3::new(-4.0, 3.0, 0.0)), 5.0);
+    let plane = Plane::from(Float4::new(0.0, 0.0, 2.0, -4.0));
+    assert_eq!(plane.signed_distance(&Float3::new(1.0, 1.0, 0.0)), -4.0);
+    assert_eq!(
+        plane
+            .normalize()
+            .signed_distance(&Float3::new(1.0, 1.0, 0.0)),
+        -2.0
+    );
+    assert_eq!(
+        plane.closest_point(&Float3::new(1.0, 1.0, 7.0)),
+        Float3::new(1.0, 1.0, 2.0)
+    );
+    let sphere = Sphere::new(Float3::from(1.0), 1.0);
+    assert_eq!(sphere.distance(&Float3::new(1.0, 1.0, 5.0)), 3.0);
+    assert_eq!(sphere.distance(&Float3::from(1.5)), 0.0);
+    assert_eq!(
+        sphere.closest_point(&Float3::new(1.0, 1.0, 5.0)),
+        Float3::new(1.0, 1.0, 2.0)
+    );
+    let aabb = unit_box();
+    assert_eq!(aabb.distance(&Float3::new(4.0, 5.0, 0.0)), 5.0);
+    assert_eq!(aabb.distance(&Float3::from(0.5)), 0.0);
+    let triangle = Triangle::new(
+        Float3::new(0.0, 0.0, 0.0),
+        Float3::new(4.0, 0.0, 0.0),
+        Float3::new(0.0, 4.0, 0.0),
+    );
+    assert_eq!(
+        triangle.closest_point(&Float3::new(1.0, 1.0, 5.0)),
+        Float3::new(1.0, 1.0, 0.0)
+    );
+    assert_eq!(
+        triangle.closest_point(&Float3::new(-2.0, -1.0, 0.0)),
+        Float3::new(0.0, 0.0, 0.0)
+    );
+    assert_eq!(
+        triangle.closest_point(&Float3::new(2.0, -3.0, 0.0)),
+        Float3::new(2.0, 0.0, 0.0)
+    );
+    assert_eq!(
+        triangle.closest_point(&Float3::new(4.0, 4.0, 0.0)),
+        Float3::new(2.0, 2.0, 0.0)
+    );
+    assert_eq!(
+        triangle.closest_point(&Float3::new(-1.0, 6.0, 0.0)),
+        Float3::new(0.0, 4.0, 0.0)
+    );
+    assert_eq!(triangle.distance(&Float3::new(2.0, -3.0, 4.0)), 5.0);
+}
+
+#[test]
+fn geometry_aabb() {
+    let points = [Float3::new(1.0, -2.0, 3.0), Float3::new(-1.0, 2.0, 0.0)];
+    let aabb = Aabb::from_points(&points);
+    assert_eq!(
+        aabb,
+        Aabb::new(Float3::new(-1.0, -2.0, 0.0), Float3::new(1.0, 2.0, 3.0))
+    );
+    assert_eq!(aabb.center(), Float3::new(0.0, 0.0, 1.5));
+    assert_eq!(aabb.extents(), Float3::new(1.0, 2.0, 1.5));
+    assert_eq!(
+        aabb.surface_area(),
+        2.0 * (2.0 * 4.0 + 4.0 * 3.0 + 3.0 * 2.0)
+    );
+    assert!(aabb.contains(&Float3::new(1.0, 0.0, 0.0)));
+    assert!(!aabb.contains(&Float3::new(1.5, 0.0, 0.0)));
+    assert!(Aabb::from_points(&[]).is_empty());
+    assert_eq!(Aabb::empty().surface_area(), 0.0);
+    assert_eq!(Aabb::empty().union(&aabb), aabb);
+    assert!(aabb.intersects_aabb(&unit_box()));
+    assert!(!aabb.intersects_aabb(&Aabb::new(Float3::from(2.0), Float3::from(3.0))));
+    let sphere = Sphere::new(Float3::new(3.0, 0.0, 1.0), 2.0);
+    assert!(sphere.intersects_aabb(&aabb));
+    assert!(!sphere.intersects_aabb(&Aabb::new(Float3::from(-3.0), Float3::from(-2.0))));
+    assert!(sphere.intersects_sphere(&Sphere::new(Float3::new(1.0, 0.0, 1.0), 1.0)));
+    assert!(!sphere.intersects_sphere(&Sphere::new(Float3::new(-1.0, 0.0, 1.0), 1.0)));
+}
+
+#[test]
+fn geometry_frustum() {
+    let view = Float4x4::look_at_lh(
+        &Float3::from(0.0),
+        &Float3::new(0.0, 0.0, 1.0),
+        &Float3::new(0.0, 1.0, 0.0),
+    );
+    let projection = Float4x4::perspective_fov_lh(FRAC_PI_2, 1.0, 1.0, 100.0);
+    let frustum = Frustum::from_view_projection(&mul(view, projection));
+    assert!(frustum.contains(&Float3::new(0.0, 0.0, 50.0)));
+    assert!(frustum.contains(&Float3::new(9.0, -9.0, 10.0)));
+    assert!(!frustum.contains(&Float3::new(11.0, 0.0, 10.0)));
+    assert!(!frustum.contains(&Float3::new(0.0, 0.0, 0.5)));
+    assert!(!frustum.contains(&Float3::new(0.0, 0.0, 101.0)));
+    let left = &frustum.planes[0];
+    assert!((left.normal.length() - 1.0).abs() < 1e-6);
+    assert!(frustum.intersects_aabb(&Aabb::new(
+        Float3::new(-1.0, -1.0, 9.0),
+        Float3::new(1.0, 1.0, 11.0)
+    )));
+    assert!(frustum.intersects_aabb(&Aabb::new(
+        Float3::new(9.0, -1.0, 9.0),
+        Float3::new(12.0, 1.0, 11.0)
+    )));
+    assert!(!frustum.intersects_aabb(&Aabb::new(
+        Float3::new(12.0, -1.0, 9.0),
+        Float3::new(13.0, 1.0, 11.0)
+    )));
+    assert!(!frustum.intersects_aabb(&Aabb::new(
+        Float3::new(-1.0, -1.0, -5.0),
+        Float3::new(1.0, 1.0, -1.0)
+    )));
+    assert!(!frustum.intersects_aabb(&Aabb::new(
+        Float3::new(-1.0, -1.0, 101.0),
+        Float3::new(1.0, 1.0, 102.0)
+    )));
+    assert!(frustum.intersects_sphere(&Sphere::new(Float3::new(11.0, 0.0, 10.0), 1.0)));
+    assert!(!frustum.intersects_sphere(&Sphere::new(Float3::new(13.0, 0.0, 10.0), 1.0)));
+    let infinite = Float4x4::perspective_fov_infinite_reversed_rh(FRAC_PI_2, 1.0, 1.0);
+    let frustum = Frustum::from_view_projection(&infinite);
+    assert!(frustum.contains(&Float3::new(0.0, 0.0, -1.0e6)));
+    assert!(!frustum.contains(&Float3::new(0.0, 0.0, 1.0e6)));
+    assert!(!frustum.contains(&Float3::new(0.0, 0.0, -0.5)));
+}
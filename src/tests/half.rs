@@ -0,0 +1,138 @@
+use crate::math::Half;
+use crate::math::f16tof32;
+use crate::math::f32tof16;
+
+#[test]
+fn half_from() {
+    let result = Half::from(1.5);
+    assert_eq!(result.to_bits(), 0x3e00);
+    assert_eq!(result.to_f32(), 1.5);
+    assert_eq!(Half::from_bits(0xc000).to_f32(), -2.0);
+    assert_eq!(f32::from(Half::from_bits(0x3800)), 0.5);
+}
+
+#[test]
+fn half_equality() {
+    assert_eq!(Half::from_bits(0x0000), Half::from_bits(0x8000));
+    assert_ne!(Half::from_bits(0x7e00), Half::from_bits(0x7e00));
+    assert_ne!(Half::from(1.0), Half::from(2.0));
+}
+
+#[test]
+fn half_arithmetic() {
+    let a = Half::from(1.5);
+    let b = Half::from(0.25);
+    assert_eq!(a + b, Half::from(1.75));
+    assert_eq!(a - b, Half::from(1.25));
+    assert_eq!(a * b, Half::from(0.375));
+    assert_eq!(a / b, Half::from(6.0));
+    assert_eq!(a % b, Half::from(0.0));
+    assert_eq!((-a).to_bits(), 0xbe00);
+    // results are rounded back to half precision.
+    assert_eq!(Half::from(2048.0) + Half::from(1.0), Half::from(2048.0));
+    let mut c = a;
+    c += b;
+    c *= Half::from(2.0);
+    assert_eq!(c, Half::from(3.5));
+}
+
+#[test]
+fn half_ordering() {
+    assert!(Half::from(1.0) < Half::from(2.0));
+    assert!(Half::from(-1.0) <= Half::from(-1.0));
+    assert!(Half::from(-0.0) >= Half::from(0.0));
+    assert!(
+        Half::from_bits(0x7e00)
+            .partial_cmp(&Half::from(1.0))
+            .is_none()
+    );
+}
+
+#[test]
+fn half_classification() {
+    assert!(Half::from(f32::NAN).is_nan());
+    assert!(!Half::from(f32::INFINITY).is_nan());
+    assert!(Half::from(f32::NEG_INFINITY).is_infinite());
+    assert!(!Half::from(65504.0).is_infinite());
+    assert!(Half::from(65504.0).is_finite());
+    assert!(!Half::from(f32::NAN).is_finite());
+}
+
+#[test]
+fn half_math() {
+    assert_eq!(Half::from(-1.5).abs(), Half::from(1.5));
+    assert_eq!(Half::from(-1.5).floor(), Half::from(-2.0));
+    assert_eq!(Half::from(2.25).sqrt(), Half::from(1.5));
+    assert_eq!(Half::from(3.0).powf(Half::from(2.0)), Half::from(9.0));
+    assert_eq!(
+        Half::from(5.0).clamp(Half::from(0.0), Half::from(1.0)),
+        Half::from(1.0)
+    );
+    assert_eq!(Half::from(1.0).sin(), Half::from(1.0f32.sin()));
+}
+
+#[test]
+fn half_f32tof16() {
+    assert_eq!(f32tof16(0.0), 0x0000);
+    assert_eq!(f32tof16(-0.0), 0x8000);
+    assert_eq!(f32tof16(1.0), 0x3c00);
+    assert_eq!(f32tof16(-2.0), 0xc000);
+    assert_eq!(f32tof16(0.1), 0x2e66);
+    assert_eq!(f32tof16(65504.0), 0x7bff);
+    assert_eq!(f32tof16(f32::INFINITY), 0x7c00);
+    assert_eq!(f32tof16(f32::NEG_INFINITY), 0xfc00);
+    assert_eq!(f32tof16(f32::NAN), 0x7e00);
+}
+
+#[test]
+fn half_f32tof16_rounding() {
+    // ties round to the even mantissa.
+    assert_eq!(f32tof16(1.0 + 2.0f32.powi(-11)), 0x3c00);
+    assert_eq!(f32tof16(1.0 + 3.0 * 2.0f32.powi(-11)), 0x3c02);
+    assert_eq!(f32tof16(1.0 + 2.0f32.powi(-11) + 2.0f32.powi(-20)), 0x3c01);
+
+    // overflow rounds to infinity only past the halfway point.
+    assert_eq!(f32tof16(65519.0), 0x7bff);
+    assert_eq!(f32tof16(65520.0), 0x7c00);
+    assert_eq!(f32tof16(1.0e10), 0x7c00);
+}
+
+#[test]
+fn half_f32tof16_subnormal() {
+    assert_eq!(f32tof16(2.0f32.powi(-14)), 0x0400);
+    assert_eq!(f32tof16(2.0f32.powi(-15)), 0x0200);
+    assert_eq!(f32tof16(2.0f32.powi(-24)), 0x0001);
+    assert_eq!(f32tof16(-2.0f32.powi(-24)), 0x8001);
+    assert_eq!(f32tof16(2.0f32.powi(-25)), 0x0000);
+    assert_eq!(f32tof16(1.5 * 2.0f32.powi(-25)), 0x0001);
+    assert_eq!(f32tof16(3.0 * 2.0f32.powi(-25)), 0x0002);
+    assert_eq!(f32tof16(2.0f32.powi(-26)), 0x0000);
+    assert_eq!(f32tof16(-1.0e-30), 0x8000);
+}
+
+#[test]
+fn half_f16tof32() {
+    assert_eq!(f16tof32(0x3c00), 1.0);
+    assert_eq!(f16tof32(0x3555), 0.33325195);
+    assert_eq!(f16tof32(0x7bff), 65504.0);
+    assert_eq!(f16tof32(0x0001), 2.0f32.powi(-24));
+    assert_eq!(f16tof32(0x03ff), 1023.0 * 2.0f32.powi(-24));
+    assert_eq!(f16tof32(0x8000).to_bits(), 0x80000000);
+    assert_eq!(f16tof32(0xfc00), f32::NEG_INFINITY);
+    assert!(f16tof32(0x7e00).is_nan());
+
+    // the upper 16 bits are ignored.
+    assert_eq!(f16tof32(0xffff3c00), 1.0);
+}
+
+#[test]
+fn half_round_trip() {
+    for bits in 0..=0xffffu32 {
+        let value = f16tof32(bits);
+        if value.is_nan() {
+            assert!(f16tof32(f32tof16(value)).is_nan());
+        } else {
+            assert_eq!(f32tof16(value), bits);
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::math::Float2;
+use crate::math::Half;
+use crate::math::Half2;
+use crate::math::Half4;
+use crate::math::UInt2;
+
+#[test]
+fn half2_from() {
+    let result = Half2::new(Half::from(1.0), Half::from(-2.0));
+    assert_eq!(result.x.to_bits(), 0x3c00);
+    assert_eq!(result.y.to_bits(), 0xc000);
+    assert_eq!(Half2::from(Half::from(1.0)), Half2::from(Float2::from(1.0)));
+}
+
+#[test]
+fn half2_float2() {
+    let a = Float2::new(1.0, -2.0);
+    let result = Half2::from(a);
+    assert_eq!(Float2::from(result), a);
+    let a = Float2::from(0.1);
+    assert_eq!(Float2::from(Half2::from(a)), Float2::from(0.099975586));
+}
+
+#[test]
+fn half2_f32tof16() {
+    let a = Float2::new(1.0, -2.0);
+    let result = a.f32tof16();
+    assert_eq!(result, UInt2::new(0x3c00, 0xc000));
+    assert_eq!(result.f16tof32(), a);
+}
+
+#[test]
+fn half2_arithmetic() {
+    let a = Half2::from(Float2::new(1.0, -2.0));
+    let b = Half2::from(Float2::new(0.5, 0.25));
+    assert_eq!(
+        Float2::from(a + b),
+        Float2::new(1.0, -2.0) + Float2::new(0.5, 0.25)
+    );
+    assert_eq!(
+        Float2::from(a - b),
+        Float2::new(1.0, -2.0) - Float2::new(0.5, 0.25)
+    );
+    assert_eq!(
+        Float2::from(a * b),
+        Float2::new(1.0, -2.0) * Float2::new(0.5, 0.25)
+    );
+    assert_eq!(
+        Float2::from(a / b),
+        Float2::new(1.0, -2.0) / Float2::new(0.5, 0.25)
+    );
+    assert_eq!(Float2::from(-a), -Float2::new(1.0, -2.0));
+    assert_eq!(a * Half::from(2.0), a + a);
+}
+
+#[test]
+fn half2_methods() {
+    let a = Float2::new(1.0, -2.0);
+    let b = Float2::new(0.5, 0.25);
+    let h = Half2::from(a);
+    assert_eq!(h.abs(), Half2::from(a.abs()));
+    assert_eq!(h.dot(&Half2::from(b)), Half::from(a.dot(&b)));
+    assert_eq!(h.max(&Half2::from(b)), Half2::from(a.max(&b)));
+    assert_eq!(h.saturate(), Half2::from(a.saturate()));
+    assert_eq!(h.step(&Half2::from(b)), Half2::from(a.step(&b)));
+    assert_eq!(h.lt(&Half2::from(b)), a.lt(&b));
+    assert_eq!(
+        Half2::from(Half::from(0.0)).normalize(),
+        Half2::from(Half::from(0.0))
+    );
+    assert!(h.all());
+}
+
+#[test]
+fn half2_swizzle() {
+    let h = Half2::from(Float2::new(1.0, -2.0));
+    assert_eq!(h.yx(), Half2::new(h.y, h.x));
+    assert_eq!(h.xxxx(), Half4::from(h.x));
+}
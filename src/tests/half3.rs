@@ -0,0 +1,80 @@
+use crate::math::Float3;
+use crate::math::Half;
+use crate::math::Half3;
+use crate::math::Half4;
+use crate::math::UInt3;
+
+#[test]
+fn half3_from() {
+    let result = Half3::new(Half::from(1.0), Half::from(-2.0), Half::from(0.5));
+    assert_eq!(result.x.to_bits(), 0x3c00);
+    assert_eq!(result.y.to_bits(), 0xc000);
+    assert_eq!(result.z.to_bits(), 0x3800);
+    assert_eq!(Half3::from(Half::from(1.0)), Half3::from(Float3::from(1.0)));
+}
+
+#[test]
+fn half3_float3() {
+    let a = Float3::new(1.0, -2.0, 0.5);
+    let result = Half3::from(a);
+    assert_eq!(Float3::from(result), a);
+    let a = Float3::from(0.1);
+    assert_eq!(Float3::from(Half3::from(a)), Float3::from(0.099975586));
+}
+
+#[test]
+fn half3_f32tof16() {
+    let a = Float3::new(1.0, -2.0, 0.5);
+    let result = a.f32tof16();
+    assert_eq!(result, UInt3::new(0x3c00, 0xc000, 0x3800));
+    assert_eq!(result.f16tof32(), a);
+}
+
+#[test]
+fn half3_arithmetic() {
+    let a = Half3::from(Float3::new(1.0, -2.0, 0.5));
+    let b = Half3::from(Float3::new(0.5, 0.25, -1.0));
+    assert_eq!(
+        Float3::from(a + b),
+        Float3::new(1.0, -2.0, 0.5) + Float3::new(0.5, 0.25, -1.0)
+    );
+    assert_eq!(
+        Float3::from(a - b),
+        Float3::new(1.0, -2.0, 0.5) - Float3::new(0.5, 0.25, -1.0)
+    );
+    assert_eq!(
+        Float3::from(a * b),
+        Float3::new(1.0, -2.0, 0.5) * Float3::new(0.5, 0.25, -1.0)
+    );
+    assert_eq!(
+        Float3::from(a / b),
+        Float3::new(1.0, -2.0, 0.5) / Float3::new(0.5, 0.25, -1.0)
+    );
+    assert_eq!(Float3::from(-a), -Float3::new(1.0, -2.0, 0.5));
+    assert_eq!(a * Half::from(2.0), a + a);
+}
+
+#[test]
+fn half3_methods() {
+    let a = Float3::new(1.0, -2.0, 0.5);
+    let b = Float3::new(0.5, 0.25, -1.0);
+    let h = Half3::from(a);
+    assert_eq!(h.abs(), Half3::from(a.abs()));
+    assert_eq!(h.dot(&Half3::from(b)), Half::from(a.dot(&b)));
+    assert_eq!(h.max(&Half3::from(b)), Half3::from(a.max(&b)));
+    assert_eq!(h.saturate(), Half3::from(a.saturate()));
+    assert_eq!(h.step(&Half3::from(b)), Half3::from(a.step(&b)));
+    assert_eq!(h.lt(&Half3::from(b)), a.lt(&b));
+    assert_eq!(
+        Half3::from(Half::from(0.0)).normalize(),
+        Half3::from(Half::from(0.0))
+    );
+    assert!(h.all());
+}
+
+#[test]
+fn half3_swizzle() {
+    let h = Half3::from(Float3::new(1.0, -2.0, 0.5));
+    assert_eq!(h.yzx(), Half3::new(h.y, h.z, h.x));
+    assert_eq!(h.xxxx(), Half4::from(h.x));
+}
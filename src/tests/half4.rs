@@ -0,0 +1,86 @@
+use crate::math::Float4;
+use crate::math::Half;
+use crate::math::Half3;
+use crate::math::Half4;
+use crate::math::UInt4;
+
+#[test]
+fn half4_from() {
+    let result = Half4::new(
+        Half::from(1.0),
+        Half::from(-2.0),
+        Half::from(0.5),
+        Half::from(65504.0),
+    );
+    assert_eq!(result.x.to_bits(), 0x3c00);
+    assert_eq!(result.y.to_bits(), 0xc000);
+    assert_eq!(result.z.to_bits(), 0x3800);
+    assert_eq!(result.w.to_bits(), 0x7bff);
+    assert_eq!(Half4::from(Half::from(1.0)), Half4::from(Float4::from(1.0)));
+}
+
+#[test]
+fn half4_float4() {
+    let a = Float4::new(1.0, -2.0, 0.5, 65504.0);
+    let result = Half4::from(a);
+    assert_eq!(Float4::from(result), a);
+    let a = Float4::from(0.1);
+    assert_eq!(Float4::from(Half4::from(a)), Float4::from(0.099975586));
+}
+
+#[test]
+fn half4_f32tof16() {
+    let a = Float4::new(1.0, -2.0, 0.5, 65504.0);
+    let result = a.f32tof16();
+    assert_eq!(result, UInt4::new(0x3c00, 0xc000, 0x3800, 0x7bff));
+    assert_eq!(result.f16tof32(), a);
+}
+
+#[test]
+fn half4_arithmetic() {
+    let a = Half4::from(Float4::new(1.0, -2.0, 0.5, 4.0));
+    let b = Half4::from(Float4::new(0.5, 0.25, -1.0, 2.0));
+    assert_eq!(
+        Float4::from(a + b),
+        Float4::new(1.0, -2.0, 0.5, 4.0) + Float4::new(0.5, 0.25, -1.0, 2.0)
+    );
+    assert_eq!(
+        Float4::from(a - b),
+        Float4::new(1.0, -2.0, 0.5, 4.0) - Float4::new(0.5, 0.25, -1.0, 2.0)
+    );
+    assert_eq!(
+        Float4::from(a * b),
+        Float4::new(1.0, -2.0, 0.5, 4.0) * Float4::new(0.5, 0.25, -1.0, 2.0)
+    );
+    assert_eq!(
+        Float4::from(a / b),
+        Float4::new(1.0, -2.0, 0.5, 4.0) / Float4::new(0.5, 0.25, -1.0, 2.0)
+    );
+    assert_eq!(Float4::from(-a), -Float4::new(1.0, -2.0, 0.5, 4.0));
+    assert_eq!(a * Half::from(2.0), a + a);
+}
+
+#[test]
+fn half4_methods() {
+    let a = Float4::new(1.0, -2.0, 0.5, 4.0);
+    let b = Float4::new(0.5, 0.25, -1.0, 2.0);
+    let h = Half4::from(a);
+    assert_eq!(h.abs(), Half4::from(a.abs()));
+    assert_eq!(h.dot(&Half4::from(b)), Half::from(a.dot(&b)));
+    assert_eq!(h.max(&Half4::from(b)), Half4::from(a.max(&b)));
+    assert_eq!(h.saturate(), Half4::from(a.saturate()));
+    assert_eq!(h.step(&Half4::from(b)), Half4::from(a.step(&b)));
+    assert_eq!(h.lt(&Half4::from(b)), a.lt(&b));
+    assert_eq!(
+        Half4::from(Half::from(0.0)).normalize(),
+        Half4::from(Half::from(0.0))
+    );
+    assert!(h.all());
+}
+
+#[test]
+fn half4_swizzle() {
+    let h = Half4::from(Float4::new(1.0, -2.0, 0.5, 4.0));
+    assert_eq!(h.ywx(), Half3::new(h.y, h.w, h.x));
+    assert_eq!(h.xxxx(), Half4::from(h.x));
+}
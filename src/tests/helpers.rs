@@ -0,0 +1,14 @@
+// Assertions shared by the test modules that compare results of floating point math.
+
+use crate::math::Float3;
+use crate::math::Quaternion;
+
+/// Asserts that two vectors are equal up to rounding errors.
+pub(super) fn assert_near(a: Float3, b: Float3) {
+    assert!((a - b).length() < 1e-5, "{a} != {b}");
+}
+
+/// Asserts that two quaternions represent the same rotation up to rounding errors.
+pub(super) fn assert_near_quaternion(a: Quaternion, b: Quaternion) {
+    assert!(a.dot(&b).abs() > 1.0 - 1e-5, "{a} != {b}");
+}
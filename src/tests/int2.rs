@@ -0,0 +1,283 @@
+use crate::math::Int2;
+use crate::math::Int3;
+use crate::math::Int4;
+
+#[test]
+fn int2_from() {
+    let result = Int2::new(7, 12);
+    assert_eq!(result.x, 7);
+    assert_eq!(result.y, 12);
+    assert_eq!(Int2::from(3), Int2::new(3, 3));
+}
+
+#[test]
+fn int2_ops_add() {
+    let a = Int2::new(7, 12);
+    let b = Int2::new(2, 5);
+    assert_eq!(a + b, Int2::new(9, 17));
+    assert_eq!(a + 2, Int2::new(9, 14));
+    let mut result = a;
+    result += b;
+    assert_eq!(result, Int2::new(9, 17));
+    result += 2;
+    assert_eq!(result, Int2::new(11, 19));
+}
+
+#[test]
+fn int2_ops_sub() {
+    let a = Int2::new(7, 12);
+    let b = Int2::new(2, 5);
+    assert_eq!(a - b, Int2::new(5, 7));
+    assert_eq!(a - 2, Int2::new(5, 10));
+    let mut result = a;
+    result -= b;
+    assert_eq!(result, Int2::new(5, 7));
+    result -= 2;
+    assert_eq!(result, Int2::new(3, 5));
+}
+
+#[test]
+fn int2_ops_mul() {
+    let a = Int2::new(7, 12);
+    let b = Int2::new(2, 5);
+    assert_eq!(a * b, Int2::new(14, 60));
+    assert_eq!(a * 2, Int2::new(14, 24));
+    let mut result = a;
+    result *= b;
+    assert_eq!(result, Int2::new(14, 60));
+    result *= 2;
+    assert_eq!(result, Int2::new(28, 120));
+}
+
+#[test]
+fn int2_ops_div() {
+    let a = Int2::new(7, 12);
+    let b = Int2::new(2, 5);
+    assert_eq!(a / b, Int2::new(3, 2));
+    assert_eq!(a / 2, Int2::new(3, 6));
+    let mut result = a;
+    result /= b;
+    assert_eq!(result, Int2::new(3, 2));
+    result /= 2;
+    assert_eq!(result, Int2::new(1, 1));
+}
+
+#[test]
+fn int2_ops_rem() {
+    let a = Int2::new(7, 12);
+    let b = Int2::new(2, 5);
+    assert_eq!(a % b, Int2::new(1, 2));
+    assert_eq!(a % 2, Int2::new(1, 0));
+    let mut result = a;
+    result %= b;
+    assert_eq!(result, Int2::new(1, 2));
+    result %= 2;
+    assert_eq!(result, Int2::new(1, 0));
+}
+
+#[test]
+fn int2_ops_wrapping() {
+    let a = Int2::from(i32::MAX);
+    assert_eq!(a + 1, Int2::from(i32::MIN));
+    assert_eq!(Int2::from(i32::MIN) - 1, a);
+    assert_eq!(a * 2, Int2::from(-2));
+    assert_eq!(Int2::from(i32::MIN) / -1, Int2::from(i32::MIN));
+    assert_eq!(-Int2::from(i32::MIN), Int2::from(i32::MIN));
+}
+
+#[test]
+fn int2_ops_div_zero() {
+    let a = Int2::new(7, 12);
+    assert_eq!(a / 0, Int2::from(-1));
+    assert_eq!(a % 0, Int2::from(-1));
+}
+
+#[test]
+fn int2_ops_neg() {
+    let result = -Int2::new(7, 12);
+    assert_eq!(result, Int2::new(-7, -12));
+}
+
+#[test]
+fn int2_ops_bitand() {
+    let a = Int2::new(7, 12);
+    let b = Int2::new(2, 5);
+    assert_eq!(a & b, Int2::new(2, 4));
+    assert_eq!(a & 3, Int2::new(3, 0));
+    let mut result = a;
+    result &= b;
+    assert_eq!(result, Int2::new(2, 4));
+    result &= 3;
+    assert_eq!(result, Int2::new(2, 0));
+}
+
+#[test]
+fn int2_ops_bitor() {
+    let a = Int2::new(7, 12);
+    let b = Int2::new(2, 5);
+    assert_eq!(a | b, Int2::new(7, 13));
+    assert_eq!(a | 3, Int2::new(7, 15));
+    let mut result = a;
+    result |= b;
+    assert_eq!(result, Int2::new(7, 13));
+    result |= 3;
+    assert_eq!(result, Int2::new(7, 15));
+}
+
+#[test]
+fn int2_ops_bitxor() {
+    let a = Int2::new(7, 12);
+    let b = Int2::new(2, 5);
+    assert_eq!(a ^ b, Int2::new(5, 9));
+    assert_eq!(a ^ 3, Int2::new(4, 15));
+    let mut result = a;
+    result ^= b;
+    assert_eq!(result, Int2::new(5, 9));
+    result ^= 3;
+    assert_eq!(result, Int2::new(6, 10));
+}
+
+#[test]
+fn int2_ops_shl() {
+    let a = Int2::new(7, 12);
+    let b = Int2::new(2, 5);
+    assert_eq!(a << b, Int2::new(28, 384));
+    assert_eq!(a << 3, Int2::new(56, 96));
+    let mut result = a;
+    result <<= b;
+    assert_eq!(result, Int2::new(28, 384));
+    result <<= 3;
+    assert_eq!(result, Int2::new(224, 3072));
+}
+
+#[test]
+fn int2_ops_shr() {
+    let a = Int2::new(7, 12);
+    let b = Int2::new(2, 5);
+    assert_eq!(a >> b, Int2::new(1, 0));
+    assert_eq!(a >> 3, Int2::new(0, 1));
+    let mut result = a;
+    result >>= b;
+    assert_eq!(result, Int2::new(1, 0));
+    result >>= 3;
+    assert_eq!(result, Int2::new(0, 0));
+}
+
+#[test]
+fn int2_ops_shift_masked() {
+    let a = Int2::from(1);
+    assert_eq!(a << 33, Int2::from(2));
+    assert_eq!(Int2::from(8) >> 35, Int2::from(1));
+    assert_eq!(Int2::from(-8) >> 1, Int2::from(-4));
+}
+
+#[test]
+fn int2_ops_not() {
+    let result = !Int2::new(7, 12);
+    assert_eq!(result, Int2::new(-8, -13));
+}
+
+#[test]
+fn int2_equality() {
+    let a = Int2::new(7, 12);
+    let b = Int2::new(2, 5);
+    assert_ne!(a, b);
+    assert_eq!(a, Int2::new(7, 12));
+}
+
+#[test]
+fn int2_abs() {
+    let a = Int2::new(7, -12);
+    assert_eq!(a.abs(), Int2::new(7, 12));
+    assert_eq!(Int2::from(i32::MIN).abs(), Int2::from(i32::MIN));
+}
+
+#[test]
+fn int2_all() {
+    assert!(Int2::new(7, 12).all());
+    assert!(!Int2::new(0, 12).all());
+    assert!(!Int2::from(0).all());
+}
+
+#[test]
+fn int2_any() {
+    assert!(Int2::new(7, 12).any());
+    assert!(Int2::new(0, 1).any());
+    assert!(!Int2::from(0).any());
+}
+
+#[test]
+fn int2_clamp() {
+    let a = Int2::new(7, 12);
+    assert_eq!(a.clamp(6, 9), Int2::new(7, 9));
+}
+
+#[test]
+fn int2_dot() {
+    let a = Int2::new(7, 12);
+    let b = Int2::new(2, 5);
+    assert_eq!(a.dot(&b), 74);
+}
+
+#[test]
+fn int2_mad() {
+    let a = Int2::new(7, 12);
+    let b = Int2::new(2, 5);
+    let c = Int2::from(1);
+    assert_eq!(a.mad(&b, &c), Int2::new(15, 61));
+}
+
+#[test]
+fn int2_max() {
+    let a = Int2::new(7, 12);
+    let b = Int2::new(8, 8);
+    assert_eq!(a.max(&b), Int2::new(8, 12));
+}
+
+#[test]
+fn int2_min() {
+    let a = Int2::new(7, 12);
+    let b = Int2::new(8, 8);
+    assert_eq!(a.min(&b), Int2::new(7, 8));
+}
+
+#[test]
+fn int2_sign() {
+    let a = Int2::new(7, -12);
+    assert_eq!(a.sign(), Int2::new(1, -1));
+}
+
+#[test]
+fn int2_swizzle() {
+    let a = Int2::new(1, 2);
+    let x = 1;
+    let y = 2;
+    assert_eq!(a.xx(), Int2::new(x, x));
+    assert_eq!(a.xy(), Int2::new(x, y));
+    assert_eq!(a.yx(), Int2::new(y, x));
+    assert_eq!(a.yy(), Int2::new(y, y));
+    assert_eq!(a.xxx(), Int3::new(x, x, x));
+    assert_eq!(a.xxy(), Int3::new(x, x, y));
+    assert_eq!(a.xyx(), Int3::new(x, y, x));
+    assert_eq!(a.xyy(), Int3::new(x, y, y));
+    assert_eq!(a.yxx(), Int3::new(y, x, x));
+    assert_eq!(a.yxy(), Int3::new(y, x, y));
+    assert_eq!(a.yyx(), Int3::new(y, y, x));
+    assert_eq!(a.yyy(), Int3::new(y, y, y));
+    assert_eq!(a.xxxx(), Int4::new(x, x, x, x));
+    assert_eq!(a.xxxy(), Int4::new(x, x, x, y));
+    assert_eq!(a.xxyx(), Int4::new(x, x, y, x));
+    assert_eq!(a.xxyy(), Int4::new(x, x, y, y));
+    assert_eq!(a.xyxx(), Int4::new(x, y, x, x));
+    assert_eq!(a.xyxy(), Int4::new(x, y, x, y));
+    assert_eq!(a.xyyx(), Int4::new(x, y, y, x));
+    assert_eq!(a.xyyy(), Int4::new(x, y, y, y));
+    assert_eq!(a.yxxx(), Int4::new(y, x, x, x));
+    assert_eq!(a.yxxy(), Int4::new(y, x, x, y));
+    assert_eq!(a.yxyx(), Int4::new(y, x, y, x));
+    assert_eq!(a.yxyy(), Int4::new(y, x, y, y));
+    assert_eq!(a.yyxx(), Int4::new(y, y, x, x));
+    assert_eq!(a.yyxy(), Int4::new(y, y, x, y));
+    assert_eq!(a.yyyx(), Int4::new(y, y, y, x));
+    assert_eq!(a.yyyy(), Int4::new(y, y, y, y));
+}
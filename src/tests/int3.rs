@@ -0,0 +1,374 @@
+use crate::math::Int2;
+use crate::math::Int3;
+use crate::math::Int4;
+
+#[test]
+fn int3_from() {
+    let result = Int3::new(7, 12, 5);
+    assert_eq!(result.x, 7);
+    assert_eq!(result.y, 12);
+    assert_eq!(result.z, 5);
+    assert_eq!(Int3::from(3), Int3::new(3, 3, 3));
+}
+
+#[test]
+fn int3_ops_add() {
+    let a = Int3::new(7, 12, 5);
+    let b = Int3::new(2, 5, 3);
+    assert_eq!(a + b, Int3::new(9, 17, 8));
+    assert_eq!(a + 2, Int3::new(9, 14, 7));
+    let mut result = a;
+    result += b;
+    assert_eq!(result, Int3::new(9, 17, 8));
+    result += 2;
+    assert_eq!(result, Int3::new(11, 19, 10));
+}
+
+#[test]
+fn int3_ops_sub() {
+    let a = Int3::new(7, 12, 5);
+    let b = Int3::new(2, 5, 3);
+    assert_eq!(a - b, Int3::new(5, 7, 2));
+    assert_eq!(a - 2, Int3::new(5, 10, 3));
+    let mut result = a;
+    result -= b;
+    assert_eq!(result, Int3::new(5, 7, 2));
+    result -= 2;
+    assert_eq!(result, Int3::new(3, 5, 0));
+}
+
+#[test]
+fn int3_ops_mul() {
+    let a = Int3::new(7, 12, 5);
+    let b = Int3::new(2, 5, 3);
+    assert_eq!(a * b, Int3::new(14, 60, 15));
+    assert_eq!(a * 2, Int3::new(14, 24, 10));
+    let mut result = a;
+    result *= b;
+    assert_eq!(result, Int3::new(14, 60, 15));
+    result *= 2;
+    assert_eq!(result, Int3::new(28, 120, 30));
+}
+
+#[test]
+fn int3_ops_div() {
+    let a = Int3::new(7, 12, 5);
+    let b = Int3::new(2, 5, 3);
+    assert_eq!(a / b, Int3::new(3, 2, 1));
+    assert_eq!(a / 2, Int3::new(3, 6, 2));
+    let mut result = a;
+    result /= b;
+    assert_eq!(result, Int3::new(3, 2, 1));
+    result /= 2;
+    assert_eq!(result, Int3::new(1, 1, 0));
+}
+
+#[test]
+fn int3_ops_rem() {
+    let a = Int3::new(7, 12, 5);
+    let b = Int3::new(2, 5, 3);
+    assert_eq!(a % b, Int3::new(1, 2, 2));
+    assert_eq!(a % 2, Int3::new(1, 0, 1));
+    let mut result = a;
+    result %= b;
+    assert_eq!(result, Int3::new(1, 2, 2));
+    result %= 2;
+    assert_eq!(result, Int3::new(1, 0, 0));
+}
+
+#[test]
+fn int3_ops_wrapping() {
+    let a = Int3::from(i32::MAX);
+    assert_eq!(a + 1, Int3::from(i32::MIN));
+    assert_eq!(Int3::from(i32::MIN) - 1, a);
+    assert_eq!(a * 2, Int3::from(-2));
+    assert_eq!(Int3::from(i32::MIN) / -1, Int3::from(i32::MIN));
+    assert_eq!(-Int3::from(i32::MIN), Int3::from(i32::MIN));
+}
+
+#[test]
+fn int3_ops_div_zero() {
+    let a = Int3::new(7, 12, 5);
+    assert_eq!(a / 0, Int3::from(-1));
+    assert_eq!(a % 0, Int3::from(-1));
+}
+
+#[test]
+fn int3_ops_neg() {
+    let result = -Int3::new(7, 12, 5);
+    assert_eq!(result, Int3::new(-7, -12, -5));
+}
+
+#[test]
+fn int3_ops_bitand() {
+    let a = Int3::new(7, 12, 5);
+    let b = Int3::new(2, 5, 3);
+    assert_eq!(a & b, Int3::new(2, 4, 1));
+    assert_eq!(a & 3, Int3::new(3, 0, 1));
+    let mut result = a;
+    result &= b;
+    assert_eq!(result, Int3::new(2, 4, 1));
+    result &= 3;
+    assert_eq!(result, Int3::new(2, 0, 1));
+}
+
+#[test]
+fn int3_ops_bitor() {
+    let a = Int3::new(7, 12, 5);
+    let b = Int3::new(2, 5, 3);
+    assert_eq!(a | b, Int3::new(7, 13, 7));
+    assert_eq!(a | 3, Int3::new(7, 15, 7));
+    let mut result = a;
+    result |= b;
+    assert_eq!(result, Int3::new(7, 13, 7));
+    result |= 3;
+    assert_eq!(result, Int3::new(7, 15, 7));
+}
+
+#[test]
+fn int3_ops_bitxor() {
+    let a = Int3::new(7, 12, 5);
+    let b = Int3::new(2, 5, 3);
+    assert_eq!(a ^ b, Int3::new(5, 9, 6));
+    assert_eq!(a ^ 3, Int3::new(4, 15, 6));
+    let mut result = a;
+    result ^= b;
+    assert_eq!(result, Int3::new(5, 9, 6));
+    result ^= 3;
+    assert_eq!(result, Int3::new(6, 10, 5));
+}
+
+#[test]
+fn int3_ops_shl() {
+    let a = Int3::new(7, 12, 5);
+    let b = Int3::new(2, 5, 3);
+    assert_eq!(a << b, Int3::new(28, 384, 40));
+    assert_eq!(a << 3, Int3::new(56, 96, 40));
+    let mut result = a;
+    result <<= b;
+    assert_eq!(result, Int3::new(28, 384, 40));
+    result <<= 3;
+    assert_eq!(result, Int3::new(224, 3072, 320));
+}
+
+#[test]
+fn int3_ops_shr() {
+    let a = Int3::new(7, 12, 5);
+    let b = Int3::new(2, 5, 3);
+    assert_eq!(a >> b, Int3::new(1, 0, 0));
+    assert_eq!(a >> 3, Int3::new(0, 1, 0));
+    let mut result = a;
+    result >>= b;
+    assert_eq!(result, Int3::new(1, 0, 0));
+    result >>= 3;
+    assert_eq!(result, Int3::new(0, 0, 0));
+}
+
+#[test]
+fn int3_ops_shift_masked() {
+    let a = Int3::from(1);
+    assert_eq!(a << 33, Int3::from(2));
+    assert_eq!(Int3::from(8) >> 35, Int3::from(1));
+    assert_eq!(Int3::from(-8) >> 1, Int3::from(-4));
+}
+
+#[test]
+fn int3_ops_not() {
+    let result = !Int3::new(7, 12, 5);
+    assert_eq!(result, Int3::new(-8, -13, -6));
+}
+
+#[test]
+fn int3_equality() {
+    let a = Int3::new(7, 12, 5);
+    let b = Int3::new(2, 5, 3);
+    assert_ne!(a, b);
+    assert_eq!(a, Int3::new(7, 12, 5));
+}
+
+#[test]
+fn int3_abs() {
+    let a = Int3::new(7, -12, 0);
+    assert_eq!(a.abs(), Int3::new(7, 12, 0));
+    assert_eq!(Int3::from(i32::MIN).abs(), Int3::from(i32::MIN));
+}
+
+#[test]
+fn int3_all() {
+    assert!(Int3::new(7, 12, 5).all());
+    assert!(!Int3::new(0, 12, 5).all());
+    assert!(!Int3::from(0).all());
+}
+
+#[test]
+fn int3_any() {
+    assert!(Int3::new(7, 12, 5).any());
+    assert!(Int3::new(0, 0, 1).any());
+    assert!(!Int3::from(0).any());
+}
+
+#[test]
+fn int3_clamp() {
+    let a = Int3::new(7, 12, 5);
+    assert_eq!(a.clamp(6, 9), Int3::new(7, 9, 6));
+}
+
+#[test]
+fn int3_dot() {
+    let a = Int3::new(7, 12, 5);
+    let b = Int3::new(2, 5, 3);
+    assert_eq!(a.dot(&b), 89);
+}
+
+#[test]
+fn int3_mad() {
+    let a = Int3::new(7, 12, 5);
+    let b = Int3::new(2, 5, 3);
+    let c = Int3::from(1);
+    assert_eq!(a.mad(&b, &c), Int3::new(15, 61, 16));
+}
+
+#[test]
+fn int3_max() {
+    let a = Int3::new(7, 12, 5);
+    let b = Int3::new(8, 8, 8);
+    assert_eq!(a.max(&b), Int3::new(8, 12, 8));
+}
+
+#[test]
+fn int3_min() {
+    let a = Int3::new(7, 12, 5);
+    let b = Int3::new(8, 8, 8);
+    assert_eq!(a.min(&b), Int3::new(7, 8, 5));
+}
+
+#[test]
+fn int3_sign() {
+    let a = Int3::new(7, -12, 0);
+    assert_eq!(a.sign(), Int3::new(1, -1, 0));
+}
+
+#[test]
+fn int3_swizzle() {
+    let a = Int3::new(1, 2, 3);
+    let x = 1;
+    let y = 2;
+    let z = 3;
+    assert_eq!(a.xx(), Int2::new(x, x));
+    assert_eq!(a.xy(), Int2::new(x, y));
+    assert_eq!(a.xz(), Int2::new(x, z));
+    assert_eq!(a.yx(), Int2::new(y, x));
+    assert_eq!(a.yy(), Int2::new(y, y));
+    assert_eq!(a.yz(), Int2::new(y, z));
+    assert_eq!(a.zx(), Int2::new(z, x));
+    assert_eq!(a.zy(), Int2::new(z, y));
+    assert_eq!(a.zz(), Int2::new(z, z));
+    assert_eq!(a.xxx(), Int3::new(x, x, x));
+    assert_eq!(a.xxy(), Int3::new(x, x, y));
+    assert_eq!(a.xxz(), Int3::new(x, x, z));
+    assert_eq!(a.xyx(), Int3::new(x, y, x));
+    assert_eq!(a.xyy(), Int3::new(x, y, y));
+    assert_eq!(a.xyz(), Int3::new(x, y, z));
+    assert_eq!(a.xzx(), Int3::new(x, z, x));
+    assert_eq!(a.xzy(), Int3::new(x, z, y));
+    assert_eq!(a.xzz(), Int3::new(x, z, z));
+    assert_eq!(a.yxx(), Int3::new(y, x, x));
+    assert_eq!(a.yxy(), Int3::new(y, x, y));
+    assert_eq!(a.yxz(), Int3::new(y, x, z));
+    assert_eq!(a.yyx(), Int3::new(y, y, x));
+    assert_eq!(a.yyy(), Int3::new(y, y, y));
+    assert_eq!(a.yyz(), Int3::new(y, y, z));
+    assert_eq!(a.yzx(), Int3::new(y, z, x));
+    assert_eq!(a.yzy(), Int3::new(y, z, y));
+    assert_eq!(a.yzz(), Int3::new(y, z, z));
+    assert_eq!(a.zxx(), Int3::new(z, x, x));
+    assert_eq!(a.zxy(), Int3::new(z, x, y));
+    assert_eq!(a.zxz(), Int3::new(z, x, z));
+    assert_eq!(a.zyx(), Int3::new(z, y, x));
+    assert_eq!(a.zyy(), Int3::new(z, y, y));
+    assert_eq!(a.zyz(), Int3::new(z, y, z));
+    assert_eq!(a.zzx(), Int3::new(z, z, x));
+    assert_eq!(a.zzy(), Int3::new(z, z, y));
+    assert_eq!(a.zzz(), Int3::new(z, z, z));
+    assert_eq!(a.xxxx(), Int4::new(x, x, x, x));
+    assert_eq!(a.xxxy(), Int4::new(x, x, x, y));
+    assert_eq!(a.xxxz(), Int4::new(x, x, x, z));
+    assert_eq!(a.xxyx(), Int4::new(x, x, y, x));
+    assert_eq!(a.xxyy(), Int4::new(x, x, y, y));
+    assert_eq!(a.xxyz(), Int4::new(x, x, y, z));
+    assert_eq!(a.xxzx(), Int4::new(x, x, z, x));
+    assert_eq!(a.xxzy(), Int4::new(x, x, z, y));
+    assert_eq!(a.xxzz(), Int4::new(x, x, z, z));
+    assert_eq!(a.xyxx(), Int4::new(x, y, x, x));
+    assert_eq!(a.xyxy(), Int4::new(x, y, x, y));
+    assert_eq!(a.xyxz(), Int4::new(x, y, x, z));
+    assert_eq!(a.xyyx(), Int4::new(x, y, y, x));
+    assert_eq!(a.xyyy(), Int4::new(x, y, y, y));
+    assert_eq!(a.xyyz(), Int4::new(x, y, y, z));
+    assert_eq!(a.xyzx(), Int4::new(x, y, z, x));
+    assert_eq!(a.xyzy(), Int4::new(x, y, z, y));
+    assert_eq!(a.xyzz(), Int4::new(x, y, z, z));
+    assert_eq!(a.xzxx(), Int4::new(x, z, x, x));
+    assert_eq!(a.xzxy(), Int4::new(x, z, x, y));
+    assert_eq!(a.xzxz(), Int4::new(x, z, x, z));
+    assert_eq!(a.xzyx(), Int4::new(x, z, y, x));
+    assert_eq!(a.xzyy(), Int4::new(x, z, y, y));
+    assert_eq!(a.xzyz(), Int4::new(x, z, y, z));
+    assert_eq!(a.xzzx(), Int4::new(x, z, z, x));
+    assert_eq!(a.xzzy(), Int4::new(x, z, z, y));
+    assert_eq!(a.xzzz(), Int4::new(x, z, z, z));
+    assert_eq!(a.yxxx(), Int4::new(y, x, x, x));
+    assert_eq!(a.yxxy(), Int4::new(y, x, x, y));
+    assert_eq!(a.yxxz(), Int4::new(y, x, x, z));
+    assert_eq!(a.yxyx(), Int4::new(y, x, y, x));
+    assert_eq!(a.yxyy(), Int4::new(y, x, y, y));
+    assert_eq!(a.yxyz(), Int4::new(y, x, y, z));
+    assert_eq!(a.yxzx(), Int4::new(y, x, z, x));
+    assert_eq!(a.yxzy(), Int4::new(y, x, z, y));
+    assert_eq!(a.yxzz(), Int4::new(y, x, z, z));
+    assert_eq!(a.yyxx(), Int4::new(y, y, x, x));
+    assert_eq!(a.yyxy(), Int4::new(y, y, x, y));
+    assert_eq!(a.yyxz(), Int4::new(y, y, x, z));
+    assert_eq!(a.yyyx(), Int4::new(y, y, y, x));
+    assert_eq!(a.yyyy(), Int4::new(y, y, y, y));
+    assert_eq!(a.yyyz(), Int4::new(y, y, y, z));
+    assert_eq!(a.yyzx(), Int4::new(y, y, z, x));
+    assert_eq!(a.yyzy(), Int4::new(y, y, z, y));
+    assert_eq!(a.yyzz(), Int4::new(y, y, z, z));
+    assert_eq!(a.yzxx(), Int4::new(y, z, x, x));
+    assert_eq!(a.yzxy(), Int4::new(y, z, x, y));
+    assert_eq!(a.yzxz(), Int4::new(y, z, x, z));
+    assert_eq!(a.yzyx(), Int4::new(y, z, y, x));
+    assert_eq!(a.yzyy(), Int4::new(y, z, y, y));
+    assert_eq!(a.yzyz(), Int4::new(y, z, y, z));
+    assert_eq!(a.yzzx(), Int4::new(y, z, z, x));
+    assert_eq!(a.yzzy(), Int4::new(y, z, z, y));
+    assert_eq!(a.yzzz(), Int4::new(y, z, z, z));
+    assert_eq!(a.zxxx(), Int4::new(z, x, x, x));
+    assert_eq!(a.zxxy(), Int4::new(z, x, x, y));
+    assert_eq!(a.zxxz(), Int4::new(z, x, x, z));
+    assert_eq!(a.zxyx(), Int4::new(z, x, y, x));
+    assert_eq!(a.zxyy(), Int4::new(z, x, y, y));
+    assert_eq!(a.zxyz(), Int4::new(z, x, y, z));
+    assert_eq!(a.zxzx(), Int4::new(z, x, z, x));
+    assert_eq!(a.zxzy(), Int4::new(z, x, z, y));
+    assert_eq!(a.zxzz(), Int4::new(z, x, z, z));
+    assert_eq!(a.zyxx(), Int4::new(z, y, x, x));
+    assert_eq!(a.zyxy(), Int4::new(z, y, x, y));
+    assert_eq!(a.zyxz(), Int4::new(z, y, x, z));
+    assert_eq!(a.zyyx(), Int4::new(z, y, y, x));
+    assert_eq!(a.zyyy(), Int4::new(z, y, y, y));
+    assert_eq!(a.zyyz(), Int4::new(z, y, y, z));
+    assert_eq!(a.zyzx(), Int4::new(z, y, z, x));
+    assert_eq!(a.zyzy(), Int4::new(z, y, z, y));
+    assert_eq!(a.zyzz(), Int4::new(z, y, z, z));
+    assert_eq!(a.zzxx(), Int4::new(z, z, x, x));
+    assert_eq!(a.zzxy(), Int4::new(z, z, x, y));
+    assert_eq!(a.zzxz(), Int4::new(z, z, x, z));
+    assert_eq!(a.zzyx(), Int4::new(z, z, y, x));
+    assert_eq!(a.zzyy(), Int4::new(z, z, y, y));
+    assert_eq!(a.zzyz(), Int4::new(z, z, y, z));
+    assert_eq!(a.zzzx(), Int4::new(z, z, z, x));
+    assert_eq!(a.zzzy(), Int4::new(z, z, z, y));
+    assert_eq!(a.zzzz(), Int4::new(z, z, z, z));
+}
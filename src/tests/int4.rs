@@ -0,0 +1,595 @@
+use crate::math::Int2;
+use crate::math::Int3;
+use crate::math::Int4;
+
+#[test]
+fn int4_from() {
+    let result = Int4::new(7, 12, 5, 9);
+    assert_eq!(result.x, 7);
+    assert_eq!(result.y, 12);
+    assert_eq!(result.z, 5);
+    assert_eq!(result.w, 9);
+    assert_eq!(Int4::from(3), Int4::new(3, 3, 3, 3));
+}
+
+#[test]
+fn int4_ops_add() {
+    let a = Int4::new(7, 12, 5, 9);
+    let b = Int4::new(2, 5, 3, 4);
+    assert_eq!(a + b, Int4::new(9, 17, 8, 13));
+    assert_eq!(a + 2, Int4::new(9, 14, 7, 11));
+    let mut result = a;
+    result += b;
+    assert_eq!(result, Int4::new(9, 17, 8, 13));
+    result += 2;
+    assert_eq!(result, Int4::new(11, 19, 10, 15));
+}
+
+#[test]
+fn int4_ops_sub() {
+    let a = Int4::new(7, 12, 5, 9);
+    let b = Int4::new(2, 5, 3, 4);
+    assert_eq!(a - b, Int4::new(5, 7, 2, 5));
+    assert_eq!(a - 2, Int4::new(5, 10, 3, 7));
+    let mut result = a;
+    result -= b;
+    assert_eq!(result, Int4::new(5, 7, 2, 5));
+    result -= 2;
+    assert_eq!(result, Int4::new(3, 5, 0, 3));
+}
+
+#[test]
+fn int4_ops_mul() {
+    let a = Int4::new(7, 12, 5, 9);
+    let b = Int4::new(2, 5, 3, 4);
+    assert_eq!(a * b, Int4::new(14, 60, 15, 36));
+    assert_eq!(a * 2, Int4::new(14, 24, 10, 18));
+    let mut result = a;
+    result *= b;
+    assert_eq!(result, Int4::new(14, 60, 15, 36));
+    result *= 2;
+    assert_eq!(result, Int4::new(28, 120, 30, 72));
+}
+
+#[test]
+fn int4_ops_div() {
+    let a = Int4::new(7, 12, 5, 9);
+    let b = Int4::new(2, 5, 3, 4);
+    assert_eq!(a / b, Int4::new(3, 2, 1, 2));
+    assert_eq!(a / 2, Int4::new(3, 6, 2, 4));
+    let mut result = a;
+    result /= b;
+    assert_eq!(result, Int4::new(3, 2, 1, 2));
+    result /= 2;
+    assert_eq!(result, Int4::new(1, 1, 0, 1));
+}
+
+#[test]
+fn int4_ops_rem() {
+    let a = Int4::new(7, 12, 5, 9);
+    let b = Int4::new(2, 5, 3, 4);
+    assert_eq!(a % b, Int4::new(1, 2, 2, 1));
+    assert_eq!(a % 2, Int4::new(1, 0, 1, 1));
+    let mut result = a;
+    result %= b;
+    assert_eq!(result, Int4::new(1, 2, 2, 1));
+    result %= 2;
+    assert_eq!(result, Int4::new(1, 0, 0, 1));
+}
+
+#[test]
+fn int4_ops_wrapping() {
+    let a = Int4::from(i32::MAX);
+    assert_eq!(a + 1, Int4::from(i32::MIN));
+    assert_eq!(Int4::from(i32::MIN) - 1, a);
+    assert_eq!(a * 2, Int4::from(-2));
+    assert_eq!(Int4::from(i32::MIN) / -1, Int4::from(i32::MIN));
+    assert_eq!(-Int4::from(i32::MIN), Int4::from(i32::MIN));
+}
+
+#[test]
+fn int4_ops_div_zero() {
+    let a = Int4::new(7, 12, 5, 9);
+    assert_eq!(a / 0, Int4::from(-1));
+    assert_eq!(a % 0, Int4::from(-1));
+}
+
+#[test]
+fn int4_ops_neg() {
+    let result = -Int4::new(7, 12, 5, 9);
+    assert_eq!(result, Int4::new(-7, -12, -5, -9));
+}
+
+#[test]
+fn int4_ops_bitand() {
+    let a = Int4::new(7, 12, 5, 9);
+    let b = Int4::new(2, 5, 3, 4);
+    assert_eq!(a & b, Int4::new(2, 4, 1, 0));
+    assert_eq!(a & 3, Int4::new(3, 0, 1, 1));
+    let mut result = a;
+    result &= b;
+    assert_eq!(result, Int4::new(2, 4, 1, 0));
+    result &= 3;
+    assert_eq!(result, Int4::new(2, 0, 1, 0));
+}
+
+#[test]
+fn int4_ops_bitor() {
+    let a = Int4::new(7, 12, 5, 9);
+    let b = Int4::new(2, 5, 3, 4);
+    assert_eq!(a | b, Int4::new(7, 13, 7, 13));
+    assert_eq!(a | 3, Int4::new(7, 15, 7, 11));
+    let mut result = a;
+    result |= b;
+    assert_eq!(result, Int4::new(7, 13, 7, 13));
+    result |= 3;
+    assert_eq!(result, Int4::new(7, 15, 7, 15));
+}
+
+#[test]
+fn int4_ops_bitxor() {
+    let a = Int4::new(7, 12, 5, 9);
+    let b = Int4::new(2, 5, 3, 4);
+    assert_eq!(a ^ b, Int4::new(5, 9, 6, 13));
+    assert_eq!(a ^ 3, Int4::new(4, 15, 6, 10));
+    let mut result = a;
+    result ^= b;
+    assert_eq!(result, Int4::new(5, 9, 6, 13));
+    result ^= 3;
+    assert_eq!(result, Int4::new(6, 10, 5, 14));
+}
+
+#[test]
+fn int4_ops_shl() {
+    let a = Int4::new(7, 12, 5, 9);
+    let b = Int4::new(2, 5, 3, 4);
+    assert_eq!(a << b, Int4::new(28, 384, 40, 144));
+    assert_eq!(a << 3, Int4::new(56, 96, 40, 72));
+    let mut result = a;
+    result <<= b;
+    assert_eq!(result, Int4::new(28, 384, 40, 144));
+    result <<= 3;
+    assert_eq!(result, Int4::new(224, 3072, 320, 1152));
+}
+
+#[test]
+fn int4_ops_shr() {
+    let a = Int4::new(7, 12, 5, 9);
+    let b = Int4::new(2, 5, 3, 4);
+    assert_eq!(a >> b, Int4::new(1, 0, 0, 0));
+    assert_eq!(a >> 3, Int4::new(0, 1, 0, 1));
+    let mut result = a;
+    result >>= b;
+    assert_eq!(result, Int4::new(1, 0, 0, 0));
+    result >>= 3;
+    assert_eq!(result, Int4::new(0, 0, 0, 0));
+}
+
+#[test]
+fn int4_ops_shift_masked() {
+    let a = Int4::from(1);
+    assert_eq!(a << 33, Int4::from(2));
+    assert_eq!(Int4::from(8) >> 35, Int4::from(1));
+    assert_eq!(Int4::from(-8) >> 1, Int4::from(-4));
+}
+
+#[test]
+fn int4_ops_not() {
+    let result = !Int4::new(7, 12, 5, 9);
+    assert_eq!(result, Int4::new(-8, -13, -6, -10));
+}
+
+#[test]
+fn int4_equality() {
+    let a = Int4::new(7, 12, 5, 9);
+    let b = Int4::new(2, 5, 3, 4);
+    assert_ne!(a, b);
+    assert_eq!(a, Int4::new(7, 12, 5, 9));
+}
+
+#[test]
+fn int4_abs() {
+    let a = Int4::new(7, -12, 0, -9);
+    assert_eq!(a.abs(), Int4::new(7, 12, 0, 9));
+    assert_eq!(Int4::from(i32::MIN).abs(), Int4::from(i32::MIN));
+}
+
+#[test]
+fn int4_all() {
+    assert!(Int4::new(7, 12, 5, 9).all());
+    assert!(!Int4::new(0, 12, 5, 9).all());
+    assert!(!Int4::from(0).all());
+}
+
+#[test]
+fn int4_any() {
+    assert!(Int4::new(7, 12, 5, 9).any());
+    assert!(Int4::new(0, 0, 0, 1).any());
+    assert!(!Int4::from(0).any());
+}
+
+#[test]
+fn int4_clamp() {
+    let a = Int4::new(7, 12, 5, 9);
+    assert_eq!(a.clamp(6, 9), Int4::new(7, 9, 6, 9));
+}
+
+#[test]
+fn int4_dot() {
+    let a = Int4::new(7, 12, 5, 9);
+    let b = Int4::new(2, 5, 3, 4);
+    assert_eq!(a.dot(&b), 125);
+}
+
+#[test]
+fn int4_mad() {
+    let a = Int4::new(7, 12, 5, 9);
+    let b = Int4::new(2, 5, 3, 4);
+    let c = Int4::from(1);
+    assert_eq!(a.mad(&b, &c), Int4::new(15, 61, 16, 37));
+}
+
+#[test]
+fn int4_max() {
+    let a = Int4::new(7, 12, 5, 9);
+    let b = Int4::new(8, 8, 8, 8);
+    assert_eq!(a.max(&b), Int4::new(8, 12, 8, 9));
+}
+
+#[test]
+fn int4_min() {
+    let a = Int4::new(7, 12, 5, 9);
+    let b = Int4::new(8, 8, 8, 8);
+    assert_eq!(a.min(&b), Int4::new(7, 8, 5, 8));
+}
+
+#[test]
+fn int4_sign() {
+    let a = Int4::new(7, -12, 0, -9);
+    assert_eq!(a.sign(), Int4::new(1, -1, 0, -1));
+}
+
+#[test]
+fn int4_swizzle() {
+    let a = Int4::new(1, 2, 3, 4);
+    let x = 1;
+    let y = 2;
+    let z = 3;
+    let w = 4;
+    assert_eq!(a.xx(), Int2::new(x, x));
+    assert_eq!(a.xy(), Int2::new(x, y));
+    assert_eq!(a.xz(), Int2::new(x, z));
+    assert_eq!(a.xw(), Int2::new(x, w));
+    assert_eq!(a.yx(), Int2::new(y, x));
+    assert_eq!(a.yy(), Int2::new(y, y));
+    assert_eq!(a.yz(), Int2::new(y, z));
+    assert_eq!(a.yw(), Int2::new(y, w));
+    assert_eq!(a.zx(), Int2::new(z, x));
+    assert_eq!(a.zy(), Int2::new(z, y));
+    assert_eq!(a.zz(), Int2::new(z, z));
+    assert_eq!(a.zw(), Int2::new(z, w));
+    assert_eq!(a.wx(), Int2::new(w, x));
+    assert_eq!(a.wy(), Int2::new(w, y));
+    assert_eq!(a.wz(), Int2::new(w, z));
+    assert_eq!(a.ww(), Int2::new(w, w));
+    assert_eq!(a.xxx(), Int3::new(x, x, x));
+    assert_eq!(a.xxy(), Int3::new(x, x, y));
+    assert_eq!(a.xxz(), Int3::new(x, x, z));
+    assert_eq!(a.xxw(), Int3::new(x, x, w));
+    assert_eq!(a.xyx(), Int3::new(x, y, x));
+    assert_eq!(a.xyy(), Int3::new(x, y, y));
+    assert_eq!(a.xyz(), Int3::new(x, y, z));
+    assert_eq!(a.xyw(), Int3::new(x, y, w));
+    assert_eq!(a.xzx(), Int3::new(x, z, x));
+    assert_eq!(a.xzy(), Int3::new(x, z, y));
+    assert_eq!(a.xzz(), Int3::new(x, z, z));
+    assert_eq!(a.xzw(), Int3::new(x, z, w));
+    assert_eq!(a.xwx(), Int3::new(x, w, x));
+    assert_eq!(a.xwy(), Int3::new(x, w, y));
+    assert_eq!(a.xwz(), Int3::new(x, w, z));
+    assert_eq!(a.xww(), Int3::new(x, w, w));
+    assert_eq!(a.yxx(), Int3::new(y, x, x));
+    assert_eq!(a.yxy(), Int3::new(y, x, y));
+    assert_eq!(a.yxz(), Int3::new(y, x, z));
+    assert_eq!(a.yxw(), Int3::new(y, x, w));
+    assert_eq!(a.yyx(), Int3::new(y, y, x));
+    assert_eq!(a.yyy(), Int3::new(y, y, y));
+    assert_eq!(a.yyz(), Int3::new(y, y, z));
+    assert_eq!(a.yyw(), Int3::new(y, y, w));
+    assert_eq!(a.yzx(), Int3::new(y, z, x));
+    assert_eq!(a.yzy(), Int3::new(y, z, y));
+    assert_eq!(a.yzz(), Int3::new(y, z, z));
+    assert_eq!(a.yzw(), Int3::new(y, z, w));
+    assert_eq!(a.ywx(), Int3::new(y, w, x));
+    assert_eq!(a.ywy(), Int3::new(y, w, y));
+    assert_eq!(a.ywz(), Int3::new(y, w, z));
+    assert_eq!(a.yww(), Int3::new(y, w, w));
+    assert_eq!(a.zxx(), Int3::new(z, x, x));
+    assert_eq!(a.zxy(), Int3::new(z, x, y));
+    assert_eq!(a.zxz(), Int3::new(z, x, z));
+    assert_eq!(a.zxw(), Int3::new(z, x, w));
+    assert_eq!(a.zyx(), Int3::new(z, y, x));
+    assert_eq!(a.zyy(), Int3::new(z, y, y));
+    assert_eq!(a.zyz(), Int3::new(z, y, z));
+    assert_eq!(a.zyw(), Int3::new(z, y, w));
+    assert_eq!(a.zzx(), Int3::new(z, z, x));
+    assert_eq!(a.zzy(), Int3::new(z, z, y));
+    assert_eq!(a.zzz(), Int3::new(z, z, z));
+    assert_eq!(a.zzw(), Int3::new(z, z, w));
+    assert_eq!(a.zwx(), Int3::new(z, w, x));
+    assert_eq!(a.zwy(), Int3::new(z, w, y));
+    assert_eq!(a.zwz(), Int3::new(z, w, z));
+    assert_eq!(a.zww(), Int3::new(z, w, w));
+    assert_eq!(a.wxx(), Int3::new(w, x, x));
+    assert_eq!(a.wxy(), Int3::new(w, x, y));
+    assert_eq!(a.wxz(), Int3::new(w, x, z));
+    assert_eq!(a.wxw(), Int3::new(w, x, w));
+    assert_eq!(a.wyx(), Int3::new(w, y, x));
+    assert_eq!(a.wyy(), Int3::new(w, y, y));
+    assert_eq!(a.wyz(), Int3::new(w, y, z));
+    assert_eq!(a.wyw(), Int3::new(w, y, w));
+    assert_eq!(a.wzx(), Int3::new(w, z, x));
+    assert_eq!(a.wzy(), Int3::new(w, z, y));
+    assert_eq!(a.wzz(), Int3::new(w, z, z));
+    assert_eq!(a.wzw(), Int3::new(w, z, w));
+    assert_eq!(a.wwx(), Int3::new(w, w, x));
+    assert_eq!(a.wwy(), Int3::new(w, w, y));
+    assert_eq!(a.wwz(), Int3::new(w, w, z));
+    assert_eq!(a.www(), Int3::new(w, w, w));
+    assert_eq!(a.xxxx(), Int4::new(x, x, x, x));
+    assert_eq!(a.xxxy(), Int4::new(x, x, x, y));
+    assert_eq!(a.xxxz(), Int4::new(x, x, x, z));
+    assert_eq!(a.xxxw(), Int4::new(x, x, x, w));
+    assert_eq!(a.xxyx(), Int4::new(x, x, y, x));
+    assert_eq!(a.xxyy(), Int4::new(x, x, y, y));
+    assert_eq!(a.xxyz(), Int4::new(x, x, y, z));
+    assert_eq!(a.xxyw(), Int4::new(x, x, y, w));
+    assert_eq!(a.xxzx(), Int4::new(x, x, z, x));
+    assert_eq!(a.xxzy(), Int4::new(x, x, z, y));
+    assert_eq!(a.xxzz(), Int4::new(x, x, z, z));
+    assert_eq!(a.xxzw(), Int4::new(x, x, z, w));
+    assert_eq!(a.xxwx(), Int4::new(x, x, w, x));
+    assert_eq!(a.xxwy(), Int4::new(x, x, w, y));
+    assert_eq!(a.xxwz(), Int4::new(x, x, w, z));
+    assert_eq!(a.xxww(), Int4::new(x, x, w, w));
+    assert_eq!(a.xyxx(), Int4::new(x, y, x, x));
+    assert_eq!(a.xyxy(), Int4::new(x, y, x, y));
+    assert_eq!(a.xyxz(), Int4::new(x, y, x, z));
+    assert_eq!(a.xyxw(), Int4::new(x, y, x, w));
+    assert_eq!(a.xyyx(), Int4::new(x, y, y, x));
+    assert_eq!(a.xyyy(), Int4::new(x, y, y, y));
+    assert_eq!(a.xyyz(), Int4::new(x, y, y, z));
+    assert_eq!(a.xyyw(), Int4::new(x, y, y, w));
+    assert_eq!(a.xyzx(), Int4::new(x, y, z, x));
+    assert_eq!(a.xyzy(), Int4::new(x, y, z, y));
+    assert_eq!(a.xyzz(), Int4::new(x, y, z, z));
+    assert_eq!(a.xyzw(), Int4::new(x, y, z, w));
+    assert_eq!(a.xywx(), Int4::new(x, y, w, x));
+    assert_eq!(a.xywy(), Int4::new(x, y, w, y));
+    assert_eq!(a.xywz(), Int4::new(x, y, w, z));
+    assert_eq!(a.xyww(), Int4::new(x, y, w, w));
+    assert_eq!(a.xzxx(), Int4::new(x, z, x, x));
+    assert_eq!(a.xzxy(), Int4::new(x, z, x, y));
+    assert_eq!(a.xzxz(), Int4::new(x, z, x, z));
+    assert_eq!(a.xzxw(), Int4::new(x, z, x, w));
+    assert_eq!(a.xzyx(), Int4::new(x, z, y, x));
+    assert_eq!(a.xzyy(), Int4::new(x, z, y, y));
+    assert_eq!(a.xzyz(), Int4::new(x, z, y, z));
+    assert_eq!(a.xzyw(), Int4::new(x, z, y, w));
+    assert_eq!(a.xzzx(), Int4::new(x, z, z, x));
+    assert_eq!(a.xzzy(), Int4::new(x, z, z, y));
+    assert_eq!(a.xzzz(), Int4::new(x, z, z, z));
+    assert_eq!(a.xzzw(), Int4::new(x, z, z, w));
+    assert_eq!(a.xzwx(), Int4::new(x, z, w, x));
+    assert_eq!(a.xzwy(), Int4::new(x, z, w, y));
+    assert_eq!(a.xzwz(), Int4::new(x, z, w, z));
+    assert_eq!(a.xzww(), Int4::new(x, z, w, w));
+    assert_eq!(a.xwxx(), Int4::new(x, w, x, x));
+    assert_eq!(a.xwxy(), Int4::new(x, w, x, y));
+    assert_eq!(a.xwxz(), Int4::new(x, w, x, z));
+    assert_eq!(a.xwxw(), Int4::new(x, w, x, w));
+    assert_eq!(a.xwyx(), Int4::new(x, w, y, x));
+    assert_eq!(a.xwyy(), Int4::new(x, w, y, y));
+    assert_eq!(a.xwyz(), Int4::new(x, w, y, z));
+    assert_eq!(a.xwyw(), Int4::new(x, w, y, w));
+    assert_eq!(a.xwzx(), Int4::new(x, w, z, x));
+    assert_eq!(a.xwzy(), Int4::new(x, w, z, y));
+    assert_eq!(a.xwzz(), Int4::new(x, w, z, z));
+    assert_eq!(a.xwzw(), Int4::new(x, w, z, w));
+    assert_eq!(a.xwwx(), Int4::new(x, w, w, x));
+    assert_eq!(a.xwwy(), Int4::new(x, w, w, y));
+    assert_eq!(a.xwwz(), Int4::new(x, w, w, z));
+    assert_eq!(a.xwww(), Int4::new(x, w, w, w));
+    assert_eq!(a.yxxx(), Int4::new(y, x, x, x));
+    assert_eq!(a.yxxy(), Int4::new(y, x, x, y));
+    assert_eq!(a.yxxz(), Int4::new(y, x, x, z));
+    assert_eq!(a.yxxw(), Int4::new(y, x, x, w));
+    assert_eq!(a.yxyx(), Int4::new(y, x, y, x));
+    assert_eq!(a.yxyy(), Int4::new(y, x, y, y));
+    assert_eq!(a.yxyz(), Int4::new(y, x, y, z));
+    assert_eq!(a.yxyw(), Int4::new(y, x, y, w));
+    assert_eq!(a.yxzx(), Int4::new(y, x, z, x));
+    assert_eq!(a.yxzy(), Int4::new(y, x, z, y));
+    assert_eq!(a.yxzz(), Int4::new(y, x, z, z));
+    assert_eq!(a.yxzw(), Int4::new(y, x, z, w));
+    assert_eq!(a.yxwx(), Int4::new(y, x, w, x));
+    assert_eq!(a.yxwy(), Int4::new(y, x, w, y));
+    assert_eq!(a.yxwz(), Int4::new(y, x, w, z));
+    assert_eq!(a.yxww(), Int4::new(y, x, w, w));
+    assert_eq!(a.yyxx(), Int4::new(y, y, x, x));
+    assert_eq!(a.yyxy(), Int4::new(y, y, x, y));
+    assert_eq!(a.yyxz(), Int4::new(y, y, x, z));
+    assert_eq!(a.yyxw(), Int4::new(y, y, x, w));
+    assert_eq!(a.yyyx(), Int4::new(y, y, y, x));
+    assert_eq!(a.yyyy(), Int4::new(y, y, y, y));
+    assert_eq!(a.yyyz(), Int4::new(y, y, y, z));
+    assert_eq!(a.yyyw(), Int4::new(y, y, y, w));
+    assert_eq!(a.yyzx(), Int4::new(y, y, z, x));
+    assert_eq!(a.yyzy(), Int4::new(y, y, z, y));
+    assert_eq!(a.yyzz(), Int4::new(y, y, z, z));
+    assert_eq!(a.yyzw(), Int4::new(y, y, z, w));
+    assert_eq!(a.yywx(), Int4::new(y, y, w, x));
+    assert_eq!(a.yywy(), Int4::new(y, y, w, y));
+    assert_eq!(a.yywz(), Int4::new(y, y, w, z));
+    assert_eq!(a.yyww(), Int4::new(y, y, w, w));
+    assert_eq!(a.yzxx(), Int4::new(y, z, x, x));
+    assert_eq!(a.yzxy(), Int4::new(y, z, x, y));
+    assert_eq!(a.yzxz(), Int4::new(y, z, x, z));
+    assert_eq!(a.yzxw(), Int4::new(y, z, x, w));
+    assert_eq!(a.yzyx(), Int4::new(y, z, y, x));
+    assert_eq!(a.yzyy(), Int4::new(y, z, y, y));
+    assert_eq!(a.yzyz(), Int4::new(y, z, y, z));
+    assert_eq!(a.yzyw(), Int4::new(y, z, y, w));
+    assert_eq!(a.yzzx(), Int4::new(y, z, z, x));
+    assert_eq!(a.yzzy(), Int4::new(y, z, z, y));
+    assert_eq!(a.yzzz(), Int4::new(y, z, z, z));
+    assert_eq!(a.yzzw(), Int4::new(y, z, z, w));
+    assert_eq!(a.yzwx(), Int4::new(y, z, w, x));
+    assert_eq!(a.yzwy(), Int4::new(y, z, w, y));
+    assert_eq!(a.yzwz(), Int4::new(y, z, w, z));
+    assert_eq!(a.yzww(), Int4::new(y, z, w, w));
+    assert_eq!(a.ywxx(), Int4::new(y, w, x, x));
+    assert_eq!(a.ywxy(), Int4::new(y, w, x, y));
+    assert_eq!(a.ywxz(), Int4::new(y, w, x, z));
+    assert_eq!(a.ywxw(), Int4::new(y, w, x, w));
+    assert_eq!(a.ywyx(), Int4::new(y, w, y, x));
+    assert_eq!(a.ywyy(), Int4::new(y, w, y, y));
+    assert_eq!(a.ywyz(), Int4::new(y, w, y, z));
+    assert_eq!(a.ywyw(), Int4::new(y, w, y, w));
+    assert_eq!(a.ywzx(), Int4::new(y, w, z, x));
+    assert_eq!(a.ywzy(), Int4::new(y, w, z, y));
+    assert_eq!(a.ywzz(), Int4::new(y, w, z, z));
+    assert_eq!(a.ywzw(), Int4::new(y, w, z, w));
+    assert_eq!(a.ywwx(), Int4::new(y, w, w, x));
+    assert_eq!(a.ywwy(), Int4::new(y, w, w, y));
+    assert_eq!(a.ywwz(), Int4::new(y, w, w, z));
+    assert_eq!(a.ywww(), Int4::new(y, w, w, w));
+    assert_eq!(a.zxxx(), Int4::new(z, x, x, x));
+    assert_eq!(a.zxxy(), Int4::new(z, x, x, y));
+    assert_eq!(a.zxxz(), Int4::new(z, x, x, z));
+    assert_eq!(a.zxxw(), Int4::new(z, x, x, w));
+    assert_eq!(a.zxyx(), Int4::new(z, x, y, x));
+    assert_eq!(a.zxyy(), Int4::new(z, x, y, y));
+    assert_eq!(a.zxyz(), Int4::new(z, x, y, z));
+    assert_eq!(a.zxyw(), Int4::new(z, x, y, w));
+    assert_eq!(a.zxzx(), Int4::new(z, x, z, x));
+    assert_eq!(a.zxzy(), Int4::new(z, x, z, y));
+    assert_eq!(a.zxzz(), Int4::new(z, x, z, z));
+    assert_eq!(a.zxzw(), Int4::new(z, x, z, w));
+    assert_eq!(a.zxwx(), Int4::new(z, x, w, x));
+    assert_eq!(a.zxwy(), Int4::new(z, x, w, y));
+    assert_eq!(a.zxwz(), Int4::new(z, x, w, z));
+    assert_eq!(a.zxww(), Int4::new(z, x, w, w));
+    assert_eq!(a.zyxx(), Int4::new(z, y, x, x));
+    assert_eq!(a.zyxy(), Int4::new(z, y, x, y));
+    assert_eq!(a.zyxz(), Int4::new(z, y, x, z));
+    assert_eq!(a.zyxw(), Int4::new(z, y, x, w));
+    assert_eq!(a.zyyx(), Int4::new(z, y, y, x));
+    assert_eq!(a.zyyy(), Int4::new(z, y, y, y));
+    assert_eq!(a.zyyz(), Int4::new(z, y, y, z));
+    assert_eq!(a.zyyw(), Int4::new(z, y, y, w));
+    assert_eq!(a.zyzx(), Int4::new(z, y, z, x));
+    assert_eq!(a.zyzy(), Int4::new(z, y, z, y));
+    assert_eq!(a.zyzz(), Int4::new(z, y, z, z));
+    assert_eq!(a.zyzw(), Int4::new(z, y, z, w));
+    assert_eq!(a.zywx(), Int4::new(z, y, w, x));
+    assert_eq!(a.zywy(), Int4::new(z, y, w, y));
+    assert_eq!(a.zywz(), Int4::new(z, y, w, z));
+    assert_eq!(a.zyww(), Int4::new(z, y, w, w));
+    assert_eq!(a.zzxx(), Int4::new(z, z, x, x));
+    assert_eq!(a.zzxy(), Int4::new(z, z, x, y));
+    assert_eq!(a.zzxz(), Int4::new(z, z, x, z));
+    assert_eq!(a.zzxw(), Int4::new(z, z, x, w));
+    assert_eq!(a.zzyx(), Int4::new(z, z, y, x));
+    assert_eq!(a.zzyy(), Int4::new(z, z, y, y));
+    assert_eq!(a.zzyz(), Int4::new(z, z, y, z));
+    assert_eq!(a.zzyw(), Int4::new(z, z, y, w));
+    assert_eq!(a.zzzx(), Int4::new(z, z, z, x));
+    assert_eq!(a.zzzy(), Int4::new(z, z, z, y));
+    assert_eq!(a.zzzz(), Int4::new(z, z, z, z));
+    assert_eq!(a.zzzw(), Int4::new(z, z, z, w));
+    assert_eq!(a.zzwx(), Int4::new(z, z, w, x));
+    assert_eq!(a.zzwy(), Int4::new(z, z, w, y));
+    assert_eq!(a.zzwz(), Int4::new(z, z, w, z));
+    assert_eq!(a.zzww(), Int4::new(z, z, w, w));
+    assert_eq!(a.zwxx(), Int4::new(z, w, x, x));
+    assert_eq!(a.zwxy(), Int4::new(z, w, x, y));
+    assert_eq!(a.zwxz(), Int4::new(z, w, x, z));
+    assert_eq!(a.zwxw(), Int4::new(z, w, x, w));
+    assert_eq!(a.zwyx(), Int4::new(z, w, y, x));
+    assert_eq!(a.zwyy(), Int4::new(z, w, y, y));
+    assert_eq!(a.zwyz(), Int4::new(z, w, y, z));
+    assert_eq!(a.zwyw(), Int4::new(z, w, y, w));
+    assert_eq!(a.zwzx(), Int4::new(z, w, z, x));
+    assert_eq!(a.zwzy(), Int4::new(z, w, z, y));
+    assert_eq!(a.zwzz(), Int4::new(z, w, z, z));
+    assert_eq!(a.zwzw(), Int4::new(z, w, z, w));
+    assert_eq!(a.zwwx(), Int4::new(z, w, w, x));
+    assert_eq!(a.zwwy(), Int4::new(z, w, w, y));
+    assert_eq!(a.zwwz(), Int4::new(z, w, w, z));
+    assert_eq!(a.zwww(), Int4::new(z, w, w, w));
+    assert_eq!(a.wxxx(), Int4::new(w, x, x, x));
+    assert_eq!(a.wxxy(), Int4::new(w, x, x, y));
+    assert_eq!(a.wxxz(), Int4::new(w, x, x, z));
+    assert_eq!(a.wxxw(), Int4::new(w, x, x, w));
+    assert_eq!(a.wxyx(), Int4::new(w, x, y, x));
+    assert_eq!(a.wxyy(), Int4::new(w, x, y, y));
+    assert_eq!(a.wxyz(), Int4::new(w, x, y, z));
+    assert_eq!(a.wxyw(), Int4::new(w, x, y, w));
+    assert_eq!(a.wxzx(), Int4::new(w, x, z, x));
+    assert_eq!(a.wxzy(), Int4::new(w, x, z, y));
+    assert_eq!(a.wxzz(), Int4::new(w, x, z, z));
+    assert_eq!(a.wxzw(), Int4::new(w, x, z, w));
+    assert_eq!(a.wxwx(), Int4::new(w, x, w, x));
+    assert_eq!(a.wxwy(), Int4::new(w, x, w, y));
+    assert_eq!(a.wxwz(), Int4::new(w, x, w, z));
+    assert_eq!(a.wxww(), Int4::new(w, x, w, w));
+    assert_eq!(a.wyxx(), Int4::new(w, y, x, x));
+    assert_eq!(a.wyxy(), Int4::new(w, y, x, y));
+    assert_eq!(a.wyxz(), Int4::new(w, y, x, z));
+    assert_eq!(a.wyxw(), Int4::new(w, y, x, w));
+    assert_eq!(a.wyyx(), Int4::new(w, y, y, x));
+    assert_eq!(a.wyyy(), Int4::new(w, y, y, y));
+    assert_eq!(a.wyyz(), Int4::new(w, y, y, z));
+    assert_eq!(a.wyyw(), Int4::new(w, y, y, w));
+    assert_eq!(a.wyzx(), Int4::new(w, y, z, x));
+    assert_eq!(a.wyzy(), Int4::new(w, y, z, y));
+    assert_eq!(a.wyzz(), Int4::new(w, y, z, z));
+    assert_eq!(a.wyzw(), Int4::new(w, y, z, w));
+    assert_eq!(a.wywx(), Int4::new(w, y, w, x));
+    assert_eq!(a.wywy(), Int4::new(w, y, w, y));
+    assert_eq!(a.wywz(), Int4::new(w, y, w, z));
+    assert_eq!(a.wyww(), Int4::new(w, y, w, w));
+    assert_eq!(a.wzxx(), Int4::new(w, z, x, x));
+    assert_eq!(a.wzxy(), Int4::new(w, z, x, y));
+    assert_eq!(a.wzxz(), Int4::new(w, z, x, z));
+    assert_eq!(a.wzxw(), Int4::new(w, z, x, w));
+    assert_eq!(a.wzyx(), Int4::new(w, z, y, x));
+    assert_eq!(a.wzyy(), Int4::new(w, z, y, y));
+    assert_eq!(a.wzyz(), Int4::new(w, z, y, z));
+    assert_eq!(a.wzyw(), Int4::new(w, z, y, w));
+    assert_eq!(a.wzzx(), Int4::new(w, z, z, x));
+    assert_eq!(a.wzzy(), Int4::new(w, z, z, y));
+    assert_eq!(a.wzzz(), Int4::new(w, z, z, z));
+    assert_eq!(a.wzzw(), Int4::new(w, z, z, w));
+    assert_eq!(a.wzwx(), Int4::new(w, z, w, x));
+    assert_eq!(a.wzwy(), Int4::new(w, z, w, y));
+    assert_eq!(a.wzwz(), Int4::new(w, z, w, z));
+    assert_eq!(a.wzww(), Int4::new(w, z, w, w));
+    assert_eq!(a.wwxx(), Int4::new(w, w, x, x));
+    assert_eq!(a.wwxy(), Int4::new(w, w, x, y));
+    assert_eq!(a.wwxz(), Int4::new(w, w, x, z));
+    assert_eq!(a.wwxw(), Int4::new(w, w, x, w));
+    assert_eq!(a.wwyx(), Int4::new(w, w, y, x));
+    assert_eq!(a.wwyy(), Int4::new(w, w, y, y));
+    assert_eq!(a.wwyz(), Int4::new(w, w, y, z));
+    assert_eq!(a.wwyw(), Int4::new(w, w, y, w));
+    assert_eq!(a.wwzx(), Int4::new(w, w, z, x));
+    assert_eq!(a.wwzy(), Int4::new(w, w, z, y));
+    assert_eq!(a.wwzz(), Int4::new(w, w, z, z));
+    assert_eq!(a.wwzw(), Int4::new(w, w, z, w));
+    assert_eq!(a.wwwx(), Int4::new(w, w, w, x));
+    assert_eq!(a.wwwy(), Int4::new(w, w, w, y));
+    assert_eq!(a.wwwz(), Int4::new(w, w, w, z));
+    assert_eq!(a.wwww(), Int4::new(w, w, w, w));
+}
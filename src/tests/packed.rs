@@ -0,0 +1,75 @@
+use crate::math::Float4;
+use crate::math::Int4;
+use crate::math::UInt4;
+use crate::math::dot4add_i8packed;
+use crate::math::dot4add_u8packed;
+use crate::math::unpack_s8s32;
+use crate::math::unpack_u8u32;
+
+#[test]
+fn packed_pack_u8() {
+    assert_eq!(UInt4::new(0x01, 0x02, 0x03, 0x04).pack_u8(), 0x04030201);
+    assert_eq!(UInt4::new(0x1ff, 0x100, 0xabcd, 0x80).pack_u8(), 0x80cd00ff);
+}
+
+#[test]
+fn packed_pack_s8() {
+    assert_eq!(Int4::new(-1, 1, -128, 127).pack_s8(), 0x7f8001ff);
+    assert_eq!(Int4::new(-129, 128, 256, -256).pack_s8(), 0x0000807f);
+}
+
+#[test]
+fn packed_pack_clamp_u8() {
+    assert_eq!(Int4::new(-1, 0, 255, 256).pack_clamp_u8(), 0xffff0000);
+    assert_eq!(
+        Int4::new(i32::MIN, 17, i32::MAX, 128).pack_clamp_u8(),
+        0x80ff1100
+    );
+}
+
+#[test]
+fn packed_pack_clamp_s8() {
+    assert_eq!(Int4::new(-129, -128, 127, 128).pack_clamp_s8(), 0x7f7f8080);
+    assert_eq!(
+        Int4::new(i32::MIN, -1, i32::MAX, 5).pack_clamp_s8(),
+        0x057fff80
+    );
+}
+
+#[test]
+fn packed_unpack() {
+    assert_eq!(unpack_u8u32(0x80cd00ff), UInt4::new(0xff, 0x00, 0xcd, 0x80));
+    assert_eq!(unpack_s8s32(0x80cd00ff), Int4::new(-1, 0, -51, -128));
+    let a = Int4::new(-128, -1, 0, 127);
+    assert_eq!(unpack_s8s32(a.pack_s8()), a);
+    let a = UInt4::new(0, 1, 128, 255);
+    assert_eq!(unpack_u8u32(a.pack_u8()), a);
+}
+
+#[test]
+fn packed_dot4add_u8packed() {
+    let a = UInt4::new(1, 2, 3, 4).pack_u8();
+    let b = UInt4::new(5, 6, 7, 8).pack_u8();
+    assert_eq!(dot4add_u8packed(a, b, 10), 80);
+    let a = UInt4::from(255).pack_u8();
+    assert_eq!(dot4add_u8packed(a, a, 0), 4 * 255 * 255);
+    assert_eq!(dot4add_u8packed(a, a, u32::MAX), 4 * 255 * 255 - 1);
+}
+
+#[test]
+fn packed_dot4add_i8packed() {
+    let a = Int4::new(-128, 127, -1, 2);
+    let b = Int4::new(-128, -128, 5, 100);
+    let result = dot4add_i8packed(a.pack_s8(), b.pack_s8(), -7);
+    // compare against the floating point reference.
+    let reference =
+        Float4::new(-128.0, 127.0, -1.0, 2.0).dot(&Float4::new(-128.0, -128.0, 5.0, 100.0)) - 7.0;
+    assert_eq!(result, reference as i32);
+    assert_eq!(result, 16384 - 16256 - 5 + 200 - 7);
+    assert_eq!(dot4add_i8packed(0, 0, i32::MAX), i32::MAX);
+    let a = Int4::from(-128).pack_s8();
+    assert_eq!(
+        dot4add_i8packed(a, a, i32::MAX),
+        i32::MAX.wrapping_add(4 * 16384)
+    );
+}
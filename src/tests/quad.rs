@@ -0,0 +1,92 @@
+use crate::math::Float2;
+use crate::math::Float3;
+use crate::math::Quad;
+
+#[test]
+fn quad_position() {
+    let result = Quad::run(Float2::new(10.5, 20.5), |quad| {
+        (quad.lane(), quad.position())
+    });
+    assert_eq!(result[0], (0, Float2::new(10.5, 20.5)));
+    assert_eq!(result[1], (1, Float2::new(11.5, 20.5)));
+    assert_eq!(result[2], (2, Float2::new(10.5, 21.5)));
+    assert_eq!(result[3], (3, Float2::new(11.5, 21.5)));
+}
+
+#[test]
+fn quad_ddx_ddy() {
+    let result = Quad::run(Float2::new(0.5, 0.5), |quad| {
+        let uv = quad.position() * Float2::new(0.25, 0.5);
+        (quad.ddx(uv), quad.ddy(uv))
+    });
+    for (ddx, ddy) in result {
+        assert_eq!(ddx, Float2::new(0.25, 0.0));
+        assert_eq!(ddy, Float2::new(0.0, 0.5));
+    }
+}
+
+#[test]
+fn quad_coarse_fine() {
+    // f = x * y has a different x-derivative in each row and y-derivative in each column.
+    let result = Quad::run(Float2::new(2.0, 3.0), |quad| {
+        let p = quad.position();
+        let f = p.x * p.y;
+        [
+            quad.ddx_coarse(f),
+            quad.ddx_fine(f),
+            quad.ddy_coarse(f),
+            quad.ddy_fine(f),
+        ]
+    });
+    assert_eq!(result[0], [3.0, 3.0, 2.0, 2.0]);
+    assert_eq!(result[1], [3.0, 3.0, 2.0, 3.0]);
+    assert_eq!(result[2], [3.0, 4.0, 2.0, 2.0]);
+    assert_eq!(result[3], [3.0, 4.0, 2.0, 3.0]);
+}
+
+#[test]
+fn quad_fwidth() {
+    let result = Quad::run(Float2::new(0.0, 0.0), |quad| {
+        let p = quad.position();
+        let value = Float3::new(-2.0 * p.x, 3.0 * p.y, p.x - p.y);
+        quad.fwidth(value)
+    });
+    for fwidth in result {
+        assert_eq!(fwidth, Float3::new(2.0, 3.0, 2.0));
+    }
+}
+
+#[test]
+fn quad_sdf_antialiasing() {
+    // a typical antialiased edge: the transition width follows the screen-space gradient.
+    let result = Quad::run(Float2::new(7.5, 0.5), |quad| {
+        let distance = quad.position().x - 8.0;
+        let width = quad.fwidth(distance);
+        (distance / width + 0.5).clamp(0.0, 1.0)
+    });
+    assert_eq!(result, [0.0, 1.0, 0.0, 1.0]);
+}
+
+#[test]
+#[should_panic(expected = "pixel 2 failed")]
+fn quad_panic() {
+    // the other pixels stop waiting for the panicking pixel instead of deadlocking.
+    Quad::run(Float2::new(0.0, 0.0), |quad| {
+        if quad.lane() == 2 {
+            panic!("pixel 2 failed");
+        }
+        quad.ddx(1.0)
+    });
+}
+
+#[test]
+#[should_panic(expected = "a pixel of the quad returned before the others computed a derivative")]
+fn quad_early_return() {
+    // the other pixels panic instead of waiting forever for the returned pixel.
+    Quad::run(Float2::new(0.0, 0.0), |quad| {
+        if quad.lane() == 3 {
+            return 0.0;
+        }
+        quad.ddx(1.0)
+    });
+}
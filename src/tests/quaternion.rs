@@ -0,0 +1,127 @@
+use crate::math::EulerOrder;
+use crate::math::Float3;
+use crate::math::Float3x3;
+use crate::math::Float4;
+use crate::math::Quaternion;
+use crate::math::mul;
+use crate::tests::helpers::assert_near;
+use crate::tests::helpers::assert_near_quaternion;
+use core::f32::consts::FRAC_PI_2;
+use core::f32::consts::PI;
+
+#[test]
+fn quaternion_from() {
+    let q = Quaternion::from(Float4::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(q, Quaternion::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(Float4::from(q), Float4::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(q.to_string(), "Quaternion (1, 2, 3, 4)");
+}
+
+#[test]
+fn quaternion_from_axis_angle() {
+    let q = Quaternion::from_axis_angle(&Float3::new(0.0, 0.0, 2.0), FRAC_PI_2);
+    assert_near(
+        q.rotate(&Float3::new(1.0, 0.0, 0.0)),
+        Float3::new(0.0, 1.0, 0.0),
+    );
+    assert_near(
+        q.rotate(&Float3::new(0.0, 1.0, 0.0)),
+        Float3::new(-1.0, 0.0, 0.0),
+    );
+    let q = Quaternion::from_axis_angle(&Float3::new(1.0, 0.0, 0.0), FRAC_PI_2);
+    assert_near(
+        q.rotate(&Float3::new(0.0, 1.0, 0.0)),
+        Float3::new(0.0, 0.0, 1.0),
+    );
+    assert_near(
+        Quaternion::identity().rotate(&Float3::new(1.0, 2.0, 3.0)),
+        Float3::new(1.0, 2.0, 3.0),
+    );
+}
+
+#[test]
+fn quaternion_from_euler() {
+    let angles = Float3::new(0.3, -1.1, 2.0);
+    let v = Float3::new(1.0, -2.0, 0.5);
+    let rotate = |axis: char, v: Float3| match axis {
+        'x' => Quaternion::from_axis_angle(&Float3::new(1.0, 0.0, 0.0), angles.x).rotate(&v),
+        'y' => Quaternion::from_axis_angle(&Float3::new(0.0, 1.0, 0.0), angles.y).rotate(&v),
+        _ => Quaternion::from_axis_angle(&Float3::new(0.0, 0.0, 1.0), angles.z).rotate(&v),
+    };
+    for (order, axes) in [
+        (EulerOrder::Xyz, "xyz"),
+        (EulerOrder::Xzy, "xzy"),
+        (EulerOrder::Yxz, "yxz"),
+        (EulerOrder::Yzx, "yzx"),
+        (EulerOrder::Zxy, "zxy"),
+        (EulerOrder::Zyx, "zyx"),
+    ] {
+        let expected = axes.chars().fold(v, |v, axis| rotate(axis, v));
+        assert_near(Quaternion::from_euler(&angles, order).rotate(&v), expected);
+    }
+}
+
+#[test]
+fn quaternion_mul() {
+    let a = Quaternion::from_axis_angle(&Float3::new(0.0, 0.0, 1.0), FRAC_PI_2);
+    let b = Quaternion::from_axis_angle(&Float3::new(1.0, 0.0, 0.0), FRAC_PI_2);
+    let v = Float3::new(0.0, 1.0, 0.0);
+    assert_near((a * b).rotate(&v), a.rotate(&b.rotate(&v)));
+    let mut c = a;
+    c *= b;
+    assert_eq!(c, a * b);
+    assert_eq!(a * Quaternion::identity(), a);
+}
+
+#[test]
+fn quaternion_inverse() {
+    let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(q.conjugate(), Quaternion::new(-1.0, -2.0, -3.0, 4.0));
+    assert_near_quaternion(q * q.inverse(), Quaternion::identity());
+    assert!((q.length() - 30.0f32.sqrt()).abs() < 1e-6);
+    assert!((q.normalize().length() - 1.0).abs() < 1e-6);
+    let r = Quaternion::from_axis_angle(&Float3::new(1.0, 1.0, 0.0), 0.7);
+    let v = Float3::new(3.0, -1.0, 2.0);
+    assert_near(r.conjugate().rotate(&r.rotate(&v)), v);
+}
+
+#[test]
+fn quaternion_slerp() {
+    let axis = Float3::new(0.0, 1.0, 0.0);
+    let a = Quaternion::identity();
+    let b = Quaternion::from_axis_angle(&axis, FRAC_PI_2);
+    assert_near_quaternion(a.slerp(&b, 0.0), a);
+    assert_near_quaternion(a.slerp(&b, 1.0), b);
+    assert_near_quaternion(
+        a.slerp(&b, 0.25),
+        Quaternion::from_axis_angle(&axis, PI / 8.0),
+    );
+    assert_near_quaternion(
+        a.slerp(&-b, 0.5),
+        Quaternion::from_axis_angle(&axis, PI / 4.0),
+    );
+    assert_near_quaternion(
+        a.nlerp(&b, 0.5),
+        Quaternion::from_axis_angle(&axis, PI / 4.0),
+    );
+    assert!((a.nlerp(&b, 0.3).length() - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn quaternion_to_float3x3() {
+    let v = Float3::new(1.0, -2.0, 0.5);
+    let q = Quaternion::from_euler(&Float3::new(0.3, -1.1, 2.0), EulerOrder::Yxz);
+    assert_near(mul(v, q.to_float3x3()), q.rotate(&v));
+    assert_eq!(Quaternion::identity().to_float3x3(), Float3x3::identity());
+    for angle in [0.5, 2.0, 3.1, -3.1] {
+        for axis in [
+            Float3::new(1.0, 0.0, 0.0),
+            Float3::new(0.0, 1.0, 0.0),
+            Float3::new(0.0, 0.0, 1.0),
+            Float3::new(1.0, -2.0, 3.0),
+        ] {
+            let q = Quaternion::from_axis_angle(&axis, angle);
+            assert_near_quaternion(Quaternion::from_float3x3(&q.to_float3x3()), q);
+        }
+    }
+}
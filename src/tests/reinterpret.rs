@@ -0,0 +1,56 @@
+use crate::math::Double2;
+use crate::math::Float3;
+use crate::math::Float4;
+use crate::math::Int2;
+use crate::math::Int3;
+use crate::math::UInt2;
+use crate::math::UInt3;
+use crate::math::asdouble;
+use crate::math::asuint;
+
+#[test]
+fn reinterpret_float_asuint() {
+    let a = Float3::new(1.0, -2.0, 0.0);
+    assert_eq!(a.asuint(), UInt3::new(0x3f800000, 0xc0000000, 0));
+    assert_eq!(a.asint(), Int3::new(0x3f800000, -0x40000000, 0));
+    assert_eq!(a.asfloat(), a);
+    assert_eq!(Float4::from(-0.0).asuint().x, 0x80000000);
+}
+
+#[test]
+fn reinterpret_uint_asfloat() {
+    let a = UInt3::new(0x3f800000, 0xc0000000, 0x7f800000);
+    assert_eq!(a.asfloat(), Float3::new(1.0, -2.0, f32::INFINITY));
+    assert_eq!(a.asint(), Int3::new(0x3f800000, -0x40000000, 0x7f800000));
+    assert_eq!(a.asuint(), a);
+    assert_eq!(Int2::new(-1, 0).asuint(), UInt2::new(u32::MAX, 0));
+    assert_eq!(
+        Int3::new(0x3f800000, 0, -0x40000000).asfloat(),
+        Float3::new(1.0, 0.0, -2.0)
+    );
+}
+
+#[test]
+fn reinterpret_nan_bits() {
+    // the payload of NaN values survives the round trip.
+    let a = UInt3::new(0x7fc00001, 0xffbfffff, 0x7f800001);
+    assert_eq!(a.asfloat().asuint(), a);
+}
+
+#[test]
+fn reinterpret_asdouble() {
+    assert_eq!(asdouble(0, 0x3ff00000), 1.0);
+    assert_eq!(asdouble(0x54442d18, 0x400921fb), core::f64::consts::PI);
+    assert_eq!(asuint(1.0), (0, 0x3ff00000));
+    assert_eq!(asuint(-2.5), (0, 0xc0040000));
+    assert_eq!(asuint(core::f64::consts::PI), (0x54442d18, 0x400921fb));
+}
+
+#[test]
+fn reinterpret_double2_asuint() {
+    let a = Double2::new(1.0, core::f64::consts::PI);
+    let (lowbits, highbits) = a.asuint();
+    assert_eq!(lowbits, UInt2::new(0, 0x54442d18));
+    assert_eq!(highbits, UInt2::new(0x3ff00000, 0x400921fb));
+    assert_eq!(lowbits.asdouble(&highbits), a);
+}
@@ -0,0 +1,57 @@
+use crate::math::Bool2;
+use crate::math::Bool3;
+use crate::math::Bool4;
+use crate::math::Float2;
+use crate::math::Float3;
+use crate::math::Float4;
+use crate::math::select;
+
+#[test]
+fn select_bool() {
+    let a = Float3::new(1.0, 2.0, 3.0);
+    let b = Float3::new(4.0, 5.0, 6.0);
+    assert_eq!(select(true, a, b), a);
+    assert_eq!(select(false, a, b), b);
+    assert_eq!(select(false, 1.0, 2.0), 2.0);
+}
+
+#[test]
+fn select_float2() {
+    let cond = Bool2::new(true, false);
+    let a = Float2::new(1.0, 2.0);
+    let b = Float2::new(3.0, 4.0);
+    assert_eq!(select(cond, a, b), Float2::new(1.0, 4.0));
+    assert_eq!(select(cond, a, 0.0), Float2::new(1.0, 0.0));
+    assert_eq!(select(cond, 0.0, b), Float2::new(0.0, 4.0));
+    assert_eq!(select(cond, 1.0, 0.0), Float2::new(1.0, 0.0));
+}
+
+#[test]
+fn select_float3() {
+    let cond = Bool3::new(false, true, false);
+    let a = Float3::new(1.0, 2.0, 3.0);
+    let b = Float3::new(4.0, 5.0, 6.0);
+    assert_eq!(select(cond, a, b), Float3::new(4.0, 2.0, 6.0));
+    assert_eq!(select(cond, a, 0.0), Float3::new(0.0, 2.0, 0.0));
+    assert_eq!(select(cond, 0.0, b), Float3::new(4.0, 0.0, 6.0));
+    assert_eq!(select(cond, 1.0, 0.0), Float3::new(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn select_float4() {
+    let cond = Bool4::new(true, false, false, true);
+    let a = Float4::new(1.0, 2.0, 3.0, 4.0);
+    let b = Float4::new(5.0, 6.0, 7.0, 8.0);
+    assert_eq!(select(cond, a, b), Float4::new(1.0, 6.0, 7.0, 4.0));
+    assert_eq!(select(cond, a, 0.0), Float4::new(1.0, 0.0, 0.0, 4.0));
+    assert_eq!(select(cond, 0.0, b), Float4::new(0.0, 6.0, 7.0, 0.0));
+    assert_eq!(select(cond, 1.0, 0.0), Float4::new(1.0, 0.0, 0.0, 1.0));
+}
+
+#[test]
+fn select_comparison() {
+    // branchless equivalent of `a < b ? a : b`.
+    let a = Float3::new(1.0, 5.0, -2.0);
+    let b = Float3::new(3.0, 4.0, -2.0);
+    assert_eq!(select(a.lt(&b), a, b), a.min(&b));
+}
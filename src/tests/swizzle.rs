@@ -0,0 +1,83 @@
+use crate::math::Float2;
+use crate::math::Float3;
+use crate::math::Float4;
+use crate::math::Int3;
+
+#[test]
+fn swizzle_set_float2() {
+    let mut a = Float2::new(1.0, 2.0);
+    a.set_yx(Float2::new(3.0, 4.0));
+    assert_eq!(a, Float2::new(4.0, 3.0));
+    a.set_xy(Float2::new(5.0, 6.0));
+    assert_eq!(a, Float2::new(5.0, 6.0));
+}
+
+#[test]
+fn swizzle_set_float3() {
+    let mut a = Float3::new(1.0, 2.0, 3.0);
+    a.set_zx(Float2::new(7.0, 8.0));
+    assert_eq!(a, Float3::new(8.0, 2.0, 7.0));
+    a.set_zyx(Float3::new(4.0, 5.0, 6.0));
+    assert_eq!(a, Float3::new(6.0, 5.0, 4.0));
+}
+
+#[test]
+fn swizzle_set_float4() {
+    let mut a = Float4::new(1.0, 2.0, 3.0, 4.0);
+    a.set_wy(Float2::new(9.0, 8.0));
+    assert_eq!(a, Float4::new(1.0, 8.0, 3.0, 9.0));
+    a.set_xyz(Float3::from(0.0));
+    assert_eq!(a, Float4::new(0.0, 0.0, 0.0, 9.0));
+    a.set_wzyx(Float4::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(a, Float4::new(4.0, 3.0, 2.0, 1.0));
+}
+
+#[test]
+fn swizzle_proxy() {
+    let mut a = Float4::new(1.0, 2.0, 3.0, 4.0);
+    *a.xyz_mut() *= 2.0;
+    assert_eq!(a, Float4::new(2.0, 4.0, 6.0, 4.0));
+    *a.wx_mut() += Float2::new(1.0, 10.0);
+    assert_eq!(a, Float4::new(12.0, 4.0, 6.0, 5.0));
+    *a.zy_mut() = Float2::new(0.0, -1.0);
+    assert_eq!(a, Float4::new(12.0, -1.0, 0.0, 5.0));
+    a.yw_mut().x = 7.0;
+    assert_eq!(a, Float4::new(12.0, 7.0, 0.0, 5.0));
+
+    let mut b = Int3::new(1, 2, 3);
+    {
+        let mut proxy = b.zyx_mut();
+        assert_eq!(*proxy, Int3::new(3, 2, 1));
+        *proxy <<= 1;
+        proxy.z = 0;
+    }
+    assert_eq!(b, Int3::new(0, 4, 6));
+}
+
+#[test]
+fn swizzle_rgba_components() {
+    let mut a = Float4::new(0.1, 0.2, 0.3, 0.4);
+    assert_eq!(a.r(), 0.1);
+    assert_eq!(a.g(), 0.2);
+    assert_eq!(a.b(), 0.3);
+    assert_eq!(a.a(), 0.4);
+    a.set_r(1.0);
+    *a.a_mut() *= 2.0;
+    assert_eq!(a, Float4::new(1.0, 0.2, 0.3, 0.8));
+}
+
+#[test]
+fn swizzle_rgba_aliases() {
+    let a = Float4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(a.rgb(), a.xyz());
+    assert_eq!(a.bgra(), a.zyxw());
+    assert_eq!(a.aaa(), a.www());
+    assert_eq!(Float2::new(1.0, 2.0).gr(), Float2::new(2.0, 1.0));
+    assert_eq!(Float3::new(1.0, 2.0, 3.0).bgr(), Float3::new(3.0, 2.0, 1.0));
+
+    let mut b = Float4::new(1.0, 2.0, 3.0, 4.0);
+    *b.rgb_mut() *= 0.5;
+    assert_eq!(b, Float4::new(0.5, 1.0, 1.5, 4.0));
+    b.set_bgra(Float4::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(b, Float4::new(3.0, 2.0, 1.0, 4.0));
+}
@@ -0,0 +1,288 @@
+use crate::math::UInt2;
+use crate::math::UInt3;
+use crate::math::UInt4;
+
+#[test]
+fn uint2_from() {
+    let result = UInt2::new(7, 12);
+    assert_eq!(result.x, 7);
+    assert_eq!(result.y, 12);
+    assert_eq!(UInt2::from(3), UInt2::new(3, 3));
+}
+
+#[test]
+fn uint2_ops_add() {
+    let a = UInt2::new(7, 12);
+    let b = UInt2::new(2, 5);
+    assert_eq!(a + b, UInt2::new(9, 17));
+    assert_eq!(a + 2, UInt2::new(9, 14));
+    let mut result = a;
+    result += b;
+    assert_eq!(result, UInt2::new(9, 17));
+    result += 2;
+    assert_eq!(result, UInt2::new(11, 19));
+}
+
+#[test]
+fn uint2_ops_sub() {
+    let a = UInt2::new(7, 12);
+    let b = UInt2::new(2, 5);
+    assert_eq!(a - b, UInt2::new(5, 7));
+    assert_eq!(a - 2, UInt2::new(5, 10));
+    let mut result = a;
+    result -= b;
+    assert_eq!(result, UInt2::new(5, 7));
+    result -= 2;
+    assert_eq!(result, UInt2::new(3, 5));
+}
+
+#[test]
+fn uint2_ops_mul() {
+    let a = UInt2::new(7, 12);
+    let b = UInt2::new(2, 5);
+    assert_eq!(a * b, UInt2::new(14, 60));
+    assert_eq!(a * 2, UInt2::new(14, 24));
+    let mut result = a;
+    result *= b;
+    assert_eq!(result, UInt2::new(14, 60));
+    result *= 2;
+    assert_eq!(result, UInt2::new(28, 120));
+}
+
+#[test]
+fn uint2_ops_div() {
+    let a = UInt2::new(7, 12);
+    let b = UInt2::new(2, 5);
+    assert_eq!(a / b, UInt2::new(3, 2));
+    assert_eq!(a / 2, UInt2::new(3, 6));
+    let mut result = a;
+    result /= b;
+    assert_eq!(result, UInt2::new(3, 2));
+    result /= 2;
+    assert_eq!(result, UInt2::new(1, 1));
+}
+
+#[test]
+fn uint2_ops_rem() {
+    let a = UInt2::new(7, 12);
+    let b = UInt2::new(2, 5);
+    assert_eq!(a % b, UInt2::new(1, 2));
+    assert_eq!(a % 2, UInt2::new(1, 0));
+    let mut result = a;
+    result %= b;
+    assert_eq!(result, UInt2::new(1, 2));
+    result %= 2;
+    assert_eq!(result, UInt2::new(1, 0));
+}
+
+#[test]
+fn uint2_ops_wrapping() {
+    let a = UInt2::from(u32::MAX);
+    assert_eq!(a + 1, UInt2::from(0));
+    assert_eq!(UInt2::from(0) - 1, a);
+    assert_eq!(a * 2, UInt2::from(u32::MAX - 1));
+    assert_eq!(-UInt2::from(1), a);
+}
+
+#[test]
+fn uint2_ops_div_zero() {
+    let a = UInt2::new(7, 12);
+    assert_eq!(a / 0, UInt2::from(u32::MAX));
+    assert_eq!(a % 0, UInt2::from(u32::MAX));
+}
+
+#[test]
+fn uint2_ops_bitand() {
+    let a = UInt2::new(7, 12);
+    let b = UInt2::new(2, 5);
+    assert_eq!(a & b, UInt2::new(2, 4));
+    assert_eq!(a & 3, UInt2::new(3, 0));
+    let mut result = a;
+    result &= b;
+    assert_eq!(result, UInt2::new(2, 4));
+    result &= 3;
+    assert_eq!(result, UInt2::new(2, 0));
+}
+
+#[test]
+fn uint2_ops_bitor() {
+    let a = UInt2::new(7, 12);
+    let b = UInt2::new(2, 5);
+    assert_eq!(a | b, UInt2::new(7, 13));
+    assert_eq!(a | 3, UInt2::new(7, 15));
+    let mut result = a;
+    result |= b;
+    assert_eq!(result, UInt2::new(7, 13));
+    result |= 3;
+    assert_eq!(result, UInt2::new(7, 15));
+}
+
+#[test]
+fn uint2_ops_bitxor() {
+    let a = UInt2::new(7, 12);
+    let b = UInt2::new(2, 5);
+    assert_eq!(a ^ b, UInt2::new(5, 9));
+    assert_eq!(a ^ 3, UInt2::new(4, 15));
+    let mut result = a;
+    result ^= b;
+    assert_eq!(result, UInt2::new(5, 9));
+    result ^= 3;
+    assert_eq!(result, UInt2::new(6, 10));
+}
+
+#[test]
+fn uint2_ops_shl() {
+    let a = UInt2::new(7, 12);
+    let b = UInt2::new(2, 5);
+    assert_eq!(a << b, UInt2::new(28, 384));
+    assert_eq!(a << 3, UInt2::new(56, 96));
+    let mut result = a;
+    result <<= b;
+    assert_eq!(result, UInt2::new(28, 384));
+    result <<= 3;
+    assert_eq!(result, UInt2::new(224, 3072));
+}
+
+#[test]
+fn uint2_ops_shr() {
+    let a = UInt2::new(7, 12);
+    let b = UInt2::new(2, 5);
+    assert_eq!(a >> b, UInt2::new(1, 0));
+    assert_eq!(a >> 3, UInt2::new(0, 1));
+    let mut result = a;
+    result >>= b;
+    assert_eq!(result, UInt2::new(1, 0));
+    result >>= 3;
+    assert_eq!(result, UInt2::new(0, 0));
+}
+
+#[test]
+fn uint2_ops_shift_masked() {
+    let a = UInt2::from(1);
+    assert_eq!(a << 33, UInt2::from(2));
+    assert_eq!(UInt2::from(8) >> 35, UInt2::from(1));
+}
+
+#[test]
+fn uint2_ops_not() {
+    let result = !UInt2::new(7, 12);
+    assert_eq!(result, UInt2::new(4294967288, 4294967283));
+}
+
+#[test]
+fn uint2_equality() {
+    let a = UInt2::new(7, 12);
+    let b = UInt2::new(2, 5);
+    assert_ne!(a, b);
+    assert_eq!(a, UInt2::new(7, 12));
+}
+
+#[test]
+fn uint2_all() {
+    assert!(UInt2::new(7, 12).all());
+    assert!(!UInt2::new(0, 12).all());
+    assert!(!UInt2::from(0).all());
+}
+
+#[test]
+fn uint2_any() {
+    assert!(UInt2::new(7, 12).any());
+    assert!(UInt2::new(0, 1).any());
+    assert!(!UInt2::from(0).any());
+}
+
+#[test]
+fn uint2_clamp() {
+    let a = UInt2::new(7, 12);
+    assert_eq!(a.clamp(6, 9), UInt2::new(7, 9));
+}
+
+#[test]
+fn uint2_dot() {
+    let a = UInt2::new(7, 12);
+    let b = UInt2::new(2, 5);
+    assert_eq!(a.dot(&b), 74);
+}
+
+#[test]
+fn uint2_mad() {
+    let a = UInt2::new(7, 12);
+    let b = UInt2::new(2, 5);
+    let c = UInt2::from(1);
+    assert_eq!(a.mad(&b, &c), UInt2::new(15, 61));
+}
+
+#[test]
+fn uint2_max() {
+    let a = UInt2::new(7, 12);
+    let b = UInt2::new(8, 8);
+    assert_eq!(a.max(&b), UInt2::new(8, 12));
+}
+
+#[test]
+fn uint2_min() {
+    let a = UInt2::new(7, 12);
+    let b = UInt2::new(8, 8);
+    assert_eq!(a.min(&b), UInt2::new(7, 8));
+}
+
+#[test]
+fn uint2_countbits() {
+    let a = UInt2::new(0, 1);
+    assert_eq!(a.countbits(), UInt2::new(0, 1));
+    assert_eq!(UInt2::from(u32::MAX).countbits(), UInt2::from(32));
+}
+
+#[test]
+fn uint2_firstbithigh() {
+    let a = UInt2::new(0, 1);
+    assert_eq!(a.firstbithigh(), UInt2::new(u32::MAX, 0));
+}
+
+#[test]
+fn uint2_firstbitlow() {
+    let a = UInt2::new(0, 1);
+    assert_eq!(a.firstbitlow(), UInt2::new(u32::MAX, 0));
+}
+
+#[test]
+fn uint2_reversebits() {
+    let a = UInt2::new(0, 1);
+    assert_eq!(a.reversebits(), UInt2::new(0, 0x80000000));
+    assert_eq!(a.reversebits().reversebits(), a);
+}
+
+#[test]
+fn uint2_swizzle() {
+    let a = UInt2::new(1, 2);
+    let x = 1;
+    let y = 2;
+    assert_eq!(a.xx(), UInt2::new(x, x));
+    assert_eq!(a.xy(), UInt2::new(x, y));
+    assert_eq!(a.yx(), UInt2::new(y, x));
+    assert_eq!(a.yy(), UInt2::new(y, y));
+    assert_eq!(a.xxx(), UInt3::new(x, x, x));
+    assert_eq!(a.xxy(), UInt3::new(x, x, y));
+    assert_eq!(a.xyx(), UInt3::new(x, y, x));
+    assert_eq!(a.xyy(), UInt3::new(x, y, y));
+    assert_eq!(a.yxx(), UInt3::new(y, x, x));
+    assert_eq!(a.yxy(), UInt3::new(y, x, y));
+    assert_eq!(a.yyx(), UInt3::new(y, y, x));
+    assert_eq!(a.yyy(), UInt3::new(y, y, y));
+    assert_eq!(a.xxxx(), UInt4::new(x, x, x, x));
+    assert_eq!(a.xxxy(), UInt4::new(x, x, x, y));
+    assert_eq!(a.xxyx(), UInt4::new(x, x, y, x));
+    assert_eq!(a.xxyy(), UInt4::new(x, x, y, y));
+    assert_eq!(a.xyxx(), UInt4::new(x, y, x, x));
+    assert_eq!(a.xyxy(), UInt4::new(x, y, x, y));
+    assert_eq!(a.xyyx(), UInt4::new(x, y, y, x));
+    assert_eq!(a.xyyy(), UInt4::new(x, y, y, y));
+    assert_eq!(a.yxxx(), UInt4::new(y, x, x, x));
+    assert_eq!(a.yxxy(), UInt4::new(y, x, x, y));
+    assert_eq!(a.yxyx(), UInt4::new(y, x, y, x));
+    assert_eq!(a.yxyy(), UInt4::new(y, x, y, y));
+    assert_eq!(a.yyxx(), UInt4::new(y, y, x, x));
+    assert_eq!(a.yyxy(), UInt4::new(y, y, x, y));
+    assert_eq!(a.yyyx(), UInt4::new(y, y, y, x));
+    assert_eq!(a.yyyy(), UInt4::new(y, y, y, y));
+}
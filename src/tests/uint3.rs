@@ -0,0 +1,379 @@
+use crate::math::UInt2;
+use crate::math::UInt3;
+use crate::math::UInt4;
+
+#[test]
+fn uint3_from() {
+    let result = UInt3::new(7, 12, 5);
+    assert_eq!(result.x, 7);
+    assert_eq!(result.y, 12);
+    assert_eq!(result.z, 5);
+    assert_eq!(UInt3::from(3), UInt3::new(3, 3, 3));
+}
+
+#[test]
+fn uint3_ops_add() {
+    let a = UInt3::new(7, 12, 5);
+    let b = UInt3::new(2, 5, 3);
+    assert_eq!(a + b, UInt3::new(9, 17, 8));
+    assert_eq!(a + 2, UInt3::new(9, 14, 7));
+    let mut result = a;
+    result += b;
+    assert_eq!(result, UInt3::new(9, 17, 8));
+    result += 2;
+    assert_eq!(result, UInt3::new(11, 19, 10));
+}
+
+#[test]
+fn uint3_ops_sub() {
+    let a = UInt3::new(7, 12, 5);
+    let b = UInt3::new(2, 5, 3);
+    assert_eq!(a - b, UInt3::new(5, 7, 2));
+    assert_eq!(a - 2, UInt3::new(5, 10, 3));
+    let mut result = a;
+    result -= b;
+    assert_eq!(result, UInt3::new(5, 7, 2));
+    result -= 2;
+    assert_eq!(result, UInt3::new(3, 5, 0));
+}
+
+#[test]
+fn uint3_ops_mul() {
+    let a = UInt3::new(7, 12, 5);
+    let b = UInt3::new(2, 5, 3);
+    assert_eq!(a * b, UInt3::new(14, 60, 15));
+    assert_eq!(a * 2, UInt3::new(14, 24, 10));
+    let mut result = a;
+    result *= b;
+    assert_eq!(result, UInt3::new(14, 60, 15));
+    result *= 2;
+    assert_eq!(result, UInt3::new(28, 120, 30));
+}
+
+#[test]
+fn uint3_ops_div() {
+    let a = UInt3::new(7, 12, 5);
+    let b = UInt3::new(2, 5, 3);
+    assert_eq!(a / b, UInt3::new(3, 2, 1));
+    assert_eq!(a / 2, UInt3::new(3, 6, 2));
+    let mut result = a;
+    result /= b;
+    assert_eq!(result, UInt3::new(3, 2, 1));
+    result /= 2;
+    assert_eq!(result, UInt3::new(1, 1, 0));
+}
+
+#[test]
+fn uint3_ops_rem() {
+    let a = UInt3::new(7, 12, 5);
+    let b = UInt3::new(2, 5, 3);
+    assert_eq!(a % b, UInt3::new(1, 2, 2));
+    assert_eq!(a % 2, UInt3::new(1, 0, 1));
+    let mut result = a;
+    result %= b;
+    assert_eq!(result, UInt3::new(1, 2, 2));
+    result %= 2;
+    assert_eq!(result, UInt3::new(1, 0, 0));
+}
+
+#[test]
+fn uint3_ops_wrapping() {
+    let a = UInt3::from(u32::MAX);
+    assert_eq!(a + 1, UInt3::from(0));
+    assert_eq!(UInt3::from(0) - 1, a);
+    assert_eq!(a * 2, UInt3::from(u32::MAX - 1));
+    assert_eq!(-UInt3::from(1), a);
+}
+
+#[test]
+fn uint3_ops_div_zero() {
+    let a = UInt3::new(7, 12, 5);
+    assert_eq!(a / 0, UInt3::from(u32::MAX));
+    assert_eq!(a % 0, UInt3::from(u32::MAX));
+}
+
+#[test]
+fn uint3_ops_bitand() {
+    let a = UInt3::new(7, 12, 5);
+    let b = UInt3::new(2, 5, 3);
+    assert_eq!(a & b, UInt3::new(2, 4, 1));
+    assert_eq!(a & 3, UInt3::new(3, 0, 1));
+    let mut result = a;
+    result &= b;
+    assert_eq!(result, UInt3::new(2, 4, 1));
+    result &= 3;
+    assert_eq!(result, UInt3::new(2, 0, 1));
+}
+
+#[test]
+fn uint3_ops_bitor() {
+    let a = UInt3::new(7, 12, 5);
+    let b = UInt3::new(2, 5, 3);
+    assert_eq!(a | b, UInt3::new(7, 13, 7));
+    assert_eq!(a | 3, UInt3::new(7, 15, 7));
+    let mut result = a;
+    result |= b;
+    assert_eq!(result, UInt3::new(7, 13, 7));
+    result |= 3;
+    assert_eq!(result, UInt3::new(7, 15, 7));
+}
+
+#[test]
+fn uint3_ops_bitxor() {
+    let a = UInt3::new(7, 12, 5);
+    let b = UInt3::new(2, 5, 3);
+    assert_eq!(a ^ b, UInt3::new(5, 9, 6));
+    assert_eq!(a ^ 3, UInt3::new(4, 15, 6));
+    let mut result = a;
+    result ^= b;
+    assert_eq!(result, UInt3::new(5, 9, 6));
+    result ^= 3;
+    assert_eq!(result, UInt3::new(6, 10, 5));
+}
+
+#[test]
+fn uint3_ops_shl() {
+    let a = UInt3::new(7, 12, 5);
+    let b = UInt3::new(2, 5, 3);
+    assert_eq!(a << b, UInt3::new(28, 384, 40));
+    assert_eq!(a << 3, UInt3::new(56, 96, 40));
+    let mut result = a;
+    result <<= b;
+    assert_eq!(result, UInt3::new(28, 384, 40));
+    result <<= 3;
+    assert_eq!(result, UInt3::new(224, 3072, 320));
+}
+
+#[test]
+fn uint3_ops_shr() {
+    let a = UInt3::new(7, 12, 5);
+    let b = UInt3::new(2, 5, 3);
+    assert_eq!(a >> b, UInt3::new(1, 0, 0));
+    assert_eq!(a >> 3, UInt3::new(0, 1, 0));
+    let mut result = a;
+    result >>= b;
+    assert_eq!(result, UInt3::new(1, 0, 0));
+    result >>= 3;
+    assert_eq!(result, UInt3::new(0, 0, 0));
+}
+
+#[test]
+fn uint3_ops_shift_masked() {
+    let a = UInt3::from(1);
+    assert_eq!(a << 33, UInt3::from(2));
+    assert_eq!(UInt3::from(8) >> 35, UInt3::from(1));
+}
+
+#[test]
+fn uint3_ops_not() {
+    let result = !UInt3::new(7, 12, 5);
+    assert_eq!(result, UInt3::new(4294967288, 4294967283, 4294967290));
+}
+
+#[test]
+fn uint3_equality() {
+    let a = UInt3::new(7, 12, 5);
+    let b = UInt3::new(2, 5, 3);
+    assert_ne!(a, b);
+    assert_eq!(a, UInt3::new(7, 12, 5));
+}
+
+#[test]
+fn uint3_all() {
+    assert!(UInt3::new(7, 12, 5).all());
+    assert!(!UInt3::new(0, 12, 5).all());
+    assert!(!UInt3::from(0).all());
+}
+
+#[test]
+fn uint3_any() {
+    assert!(UInt3::new(7, 12, 5).any());
+    assert!(UInt3::new(0, 0, 1).any());
+    assert!(!UInt3::from(0).any());
+}
+
+#[test]
+fn uint3_clamp() {
+    let a = UInt3::new(7, 12, 5);
+    assert_eq!(a.clamp(6, 9), UInt3::new(7, 9, 6));
+}
+
+#[test]
+fn uint3_dot() {
+    let a = UInt3::new(7, 12, 5);
+    let b = UInt3::new(2, 5, 3);
+    assert_eq!(a.dot(&b), 89);
+}
+
+#[test]
+fn uint3_mad() {
+    let a = UInt3::new(7, 12, 5);
+    let b = UInt3::new(2, 5, 3);
+    let c = UInt3::from(1);
+    assert_eq!(a.mad(&b, &c), UInt3::new(15, 61, 16));
+}
+
+#[test]
+fn uint3_max() {
+    let a = UInt3::new(7, 12, 5);
+    let b = UInt3::new(8, 8, 8);
+    assert_eq!(a.max(&b), UInt3::new(8, 12, 8));
+}
+
+#[test]
+fn uint3_min() {
+    let a = UInt3::new(7, 12, 5);
+    let b = UInt3::new(8, 8, 8);
+    assert_eq!(a.min(&b), UInt3::new(7, 8, 5));
+}
+
+#[test]
+fn uint3_countbits() {
+    let a = UInt3::new(0, 1, 0x80000000);
+    assert_eq!(a.countbits(), UInt3::new(0, 1, 1));
+    assert_eq!(UInt3::from(u32::MAX).countbits(), UInt3::from(32));
+}
+
+#[test]
+fn uint3_firstbithigh() {
+    let a = UInt3::new(0, 1, 0x80000000);
+    assert_eq!(a.firstbithigh(), UInt3::new(u32::MAX, 0, 31));
+}
+
+#[test]
+fn uint3_firstbitlow() {
+    let a = UInt3::new(0, 1, 0x80000000);
+    assert_eq!(a.firstbitlow(), UInt3::new(u32::MAX, 0, 31));
+}
+
+#[test]
+fn uint3_reversebits() {
+    let a = UInt3::new(0, 1, 0x80000000);
+    assert_eq!(a.reversebits(), UInt3::new(0, 0x80000000, 1));
+    assert_eq!(a.reversebits().reversebits(), a);
+}
+
+#[test]
+fn uint3_swizzle() {
+    let a = UInt3::new(1, 2, 3);
+    let x = 1;
+    let y = 2;
+    let z = 3;
+    assert_eq!(a.xx(), UInt2::new(x, x));
+    assert_eq!(a.xy(), UInt2::new(x, y));
+    assert_eq!(a.xz(), UInt2::new(x, z));
+    assert_eq!(a.yx(), UInt2::new(y, x));
+    assert_eq!(a.yy(), UInt2::new(y, y));
+    assert_eq!(a.yz(), UInt2::new(y, z));
+    assert_eq!(a.zx(), UInt2::new(z, x));
+    assert_eq!(a.zy(), UInt2::new(z, y));
+    assert_eq!(a.zz(), UInt2::new(z, z));
+    assert_eq!(a.xxx(), UInt3::new(x, x, x));
+    assert_eq!(a.xxy(), UInt3::new(x, x, y));
+    assert_eq!(a.xxz(), UInt3::new(x, x, z));
+    assert_eq!(a.xyx(), UInt3::new(x, y, x));
+    assert_eq!(a.xyy(), UInt3::new(x, y, y));
+    assert_eq!(a.xyz(), UInt3::new(x, y, z));
+    assert_eq!(a.xzx(), UInt3::new(x, z, x));
+    assert_eq!(a.xzy(), UInt3::new(x, z, y));
+    assert_eq!(a.xzz(), UInt3::new(x, z, z));
+    assert_eq!(a.yxx(), UInt3::new(y, x, x));
+    assert_eq!(a.yxy(), UInt3::new(y, x, y));
+    assert_eq!(a.yxz(), UInt3::new(y, x, z));
+    assert_eq!(a.yyx(), UInt3::new(y, y, x));
+    assert_eq!(a.yyy(), UInt3::new(y, y, y));
+    assert_eq!(a.yyz(), UInt3::new(y, y, z));
+    assert_eq!(a.yzx(), UInt3::new(y, z, x));
+    assert_eq!(a.yzy(), UInt3::new(y, z, y));
+    assert_eq!(a.yzz(), UInt3::new(y, z, z));
+    assert_eq!(a.zxx(), UInt3::new(z, x, x));
+    assert_eq!(a.zxy(), UInt3::new(z, x, y));
+    assert_eq!(a.zxz(), UInt3::new(z, x, z));
+    assert_eq!(a.zyx(), UInt3::new(z, y, x));
+    assert_eq!(a.zyy(), UInt3::new(z, y, y));
+    assert_eq!(a.zyz(), UInt3::new(z, y, z));
+    assert_eq!(a.zzx(), UInt3::new(z, z, x));
+    assert_eq!(a.zzy(), UInt3::new(z, z, y));
+    assert_eq!(a.zzz(), UInt3::new(z, z, z));
+    assert_eq!(a.xxxx(), UInt4::new(x, x, x, x));
+    assert_eq!(a.xxxy(), UInt4::new(x, x, x, y));
+    assert_eq!(a.xxxz(), UInt4::new(x, x, x, z));
+    assert_eq!(a.xxyx(), UInt4::new(x, x, y, x));
+    assert_eq!(a.xxyy(), UInt4::new(x, x, y, y));
+    assert_eq!(a.xxyz(), UInt4::new(x, x, y, z));
+    assert_eq!(a.xxzx(), UInt4::new(x, x, z, x));
+    assert_eq!(a.xxzy(), UInt4::new(x, x, z, y));
+    assert_eq!(a.xxzz(), UInt4::new(x, x, z, z));
+    assert_eq!(a.xyxx(), UInt4::new(x, y, x, x));
+    assert_eq!(a.xyxy(), UInt4::new(x, y, x, y));
+    assert_eq!(a.xyxz(), UInt4::new(x, y, x, z));
+    assert_eq!(a.xyyx(), UInt4::new(x, y, y, x));
+    assert_eq!(a.xyyy(), UInt4::new(x, y, y, y));
+    assert_eq!(a.xyyz(), UInt4::new(x, y, y, z));
+    assert_eq!(a.xyzx(), UInt4::new(x, y, z, x));
+    assert_eq!(a.xyzy(), UInt4::new(x, y, z, y));
+    assert_eq!(a.xyzz(), UInt4::new(x, y, z, z));
+    assert_eq!(a.xzxx(), UInt4::new(x, z, x, x));
+    assert_eq!(a.xzxy(), UInt4::new(x, z, x, y));
+    assert_eq!(a.xzxz(), UInt4::new(x, z, x, z));
+    assert_eq!(a.xzyx(), UInt4::new(x, z, y, x));
+    assert_eq!(a.xzyy(), UInt4::new(x, z, y, y));
+    assert_eq!(a.xzyz(), UInt4::new(x, z, y, z));
+    assert_eq!(a.xzzx(), UInt4::new(x, z, z, x));
+    assert_eq!(a.xzzy(), UInt4::new(x, z, z, y));
+    assert_eq!(a.xzzz(), UInt4::new(x, z, z, z));
+    assert_eq!(a.yxxx(), UInt4::new(y, x, x, x));
+    assert_eq!(a.yxxy(), UInt4::new(y, x, x, y));
+    assert_eq!(a.yxxz(), UInt4::new(y, x, x, z));
+    assert_eq!(a.yxyx(), UInt4::new(y, x, y, x));
+    assert_eq!(a.yxyy(), UInt4::new(y, x, y, y));
+    assert_eq!(a.yxyz(), UInt4::new(y, x, y, z));
+    assert_eq!(a.yxzx(), UInt4::new(y, x, z, x));
+    assert_eq!(a.yxzy(), UInt4::new(y, x, z, y));
+    assert_eq!(a.yxzz(), UInt4::new(y, x, z, z));
+    assert_eq!(a.yyxx(), UInt4::new(y, y, x, x));
+    assert_eq!(a.yyxy(), UInt4::new(y, y, x, y));
+    assert_eq!(a.yyxz(), UInt4::new(y, y, x, z));
+    assert_eq!(a.yyyx(), UInt4::new(y, y, y, x));
+    assert_eq!(a.yyyy(), UInt4::new(y, y, y, y));
+    assert_eq!(a.yyyz(), UInt4::new(y, y, y, z));
+    assert_eq!(a.yyzx(), UInt4::new(y, y, z, x));
+    assert_eq!(a.yyzy(), UInt4::new(y, y, z, y));
+    assert_eq!(a.yyzz(), UInt4::new(y, y, z, z));
+    assert_eq!(a.yzxx(), UInt4::new(y, z, x, x));
+    assert_eq!(a.yzxy(), UInt4::new(y, z, x, y));
+    assert_eq!(a.yzxz(), UInt4::new(y, z, x, z));
+    assert_eq!(a.yzyx(), UInt4::new(y, z, y, x));
+    assert_eq!(a.yzyy(), UInt4::new(y, z, y, y));
+    assert_eq!(a.yzyz(), UInt4::new(y, z, y, z));
+    assert_eq!(a.yzzx(), UInt4::new(y, z, z, x));
+    assert_eq!(a.yzzy(), UInt4::new(y, z, z, y));
+    assert_eq!(a.yzzz(), UInt4::new(y, z, z, z));
+    assert_eq!(a.zxxx(), UInt4::new(z, x, x, x));
+    assert_eq!(a.zxxy(), UInt4::new(z, x, x, y));
+    assert_eq!(a.zxxz(), UInt4::new(z, x, x, z));
+    assert_eq!(a.zxyx(), UInt4::new(z, x, y, x));
+    assert_eq!(a.zxyy(), UInt4::new(z, x, y, y));
+    assert_eq!(a.zxyz(), UInt4::new(z, x, y, z));
+    assert_eq!(a.zxzx(), UInt4::new(z, x, z, x));
+    assert_eq!(a.zxzy(), UInt4::new(z, x, z, y));
+    assert_eq!(a.zxzz(), UInt4::new(z, x, z, z));
+    assert_eq!(a.zyxx(), UInt4::new(z, y, x, x));
+    assert_eq!(a.zyxy(), UInt4::new(z, y, x, y));
+    assert_eq!(a.zyxz(), UInt4::new(z, y, x, z));
+    assert_eq!(a.zyyx(), UInt4::new(z, y, y, x));
+    assert_eq!(a.zyyy(), UInt4::new(z, y, y, y));
+    assert_eq!(a.zyyz(), UInt4::new(z, y, y, z));
+    assert_eq!(a.zyzx(), UInt4::new(z, y, z, x));
+    assert_eq!(a.zyzy(), UInt4::new(z, y, z, y));
+    assert_eq!(a.zyzz(), UInt4::new(z, y, z, z));
+    assert_eq!(a.zzxx(), UInt4::new(z, z, x, x));
+    assert_eq!(a.zzxy(), UInt4::new(z, z, x, y));
+    assert_eq!(a.zzxz(), UInt4::new(z, z, x, z));
+    assert_eq!(a.zzyx(), UInt4::new(z, z, y, x));
+    assert_eq!(a.zzyy(), UInt4::new(z, z, y, y));
+    assert_eq!(a.zzyz(), UInt4::new(z, z, y, z));
+    assert_eq!(a.zzzx(), UInt4::new(z, z, z, x));
+    assert_eq!(a.zzzy(), UInt4::new(z, z, z, y));
+    assert_eq!(a.zzzz(), UInt4::new(z, z, z, z));
+}
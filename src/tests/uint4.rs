@@ -0,0 +1,603 @@
+use crate::math::UInt2;
+use crate::math::UInt3;
+use crate::math::UInt4;
+
+#[test]
+fn uint4_from() {
+    let result = UInt4::new(7, 12, 5, 9);
+    assert_eq!(result.x, 7);
+    assert_eq!(result.y, 12);
+    assert_eq!(result.z, 5);
+    assert_eq!(result.w, 9);
+    assert_eq!(UInt4::from(3), UInt4::new(3, 3, 3, 3));
+}
+
+#[test]
+fn uint4_ops_add() {
+    let a = UInt4::new(7, 12, 5, 9);
+    let b = UInt4::new(2, 5, 3, 4);
+    assert_eq!(a + b, UInt4::new(9, 17, 8, 13));
+    assert_eq!(a + 2, UInt4::new(9, 14, 7, 11));
+    let mut result = a;
+    result += b;
+    assert_eq!(result, UInt4::new(9, 17, 8, 13));
+    result += 2;
+    assert_eq!(result, UInt4::new(11, 19, 10, 15));
+}
+
+#[test]
+fn uint4_ops_sub() {
+    let a = UInt4::new(7, 12, 5, 9);
+    let b = UInt4::new(2, 5, 3, 4);
+    assert_eq!(a - b, UInt4::new(5, 7, 2, 5));
+    assert_eq!(a - 2, UInt4::new(5, 10, 3, 7));
+    let mut result = a;
+    result -= b;
+    assert_eq!(result, UInt4::new(5, 7, 2, 5));
+    result -= 2;
+    assert_eq!(result, UInt4::new(3, 5, 0, 3));
+}
+
+#[test]
+fn uint4_ops_mul() {
+    let a = UInt4::new(7, 12, 5, 9);
+    let b = UInt4::new(2, 5, 3, 4);
+    assert_eq!(a * b, UInt4::new(14, 60, 15, 36));
+    assert_eq!(a * 2, UInt4::new(14, 24, 10, 18));
+    let mut result = a;
+    result *= b;
+    assert_eq!(result, UInt4::new(14, 60, 15, 36));
+    result *= 2;
+    assert_eq!(result, UInt4::new(28, 120, 30, 72));
+}
+
+#[test]
+fn uint4_ops_div() {
+    let a = UInt4::new(7, 12, 5, 9);
+    let b = UInt4::new(2, 5, 3, 4);
+    assert_eq!(a / b, UInt4::new(3, 2, 1, 2));
+    assert_eq!(a / 2, UInt4::new(3, 6, 2, 4));
+    let mut result = a;
+    result /= b;
+    assert_eq!(result, UInt4::new(3, 2, 1, 2));
+    result /= 2;
+    assert_eq!(result, UInt4::new(1, 1, 0, 1));
+}
+
+#[test]
+fn uint4_ops_rem() {
+    let a = UInt4::new(7, 12, 5, 9);
+    let b = UInt4::new(2, 5, 3, 4);
+    assert_eq!(a % b, UInt4::new(1, 2, 2, 1));
+    assert_eq!(a % 2, UInt4::new(1, 0, 1, 1));
+    let mut result = a;
+    result %= b;
+    assert_eq!(result, UInt4::new(1, 2, 2, 1));
+    result %= 2;
+    assert_eq!(result, UInt4::new(1, 0, 0, 1));
+}
+
+#[test]
+fn uint4_ops_wrapping() {
+    let a = UInt4::from(u32::MAX);
+    assert_eq!(a + 1, UInt4::from(0));
+    assert_eq!(UInt4::from(0) - 1, a);
+    assert_eq!(a * 2, UInt4::from(u32::MAX - 1));
+    assert_eq!(-UInt4::from(1), a);
+}
+
+#[test]
+fn uint4_ops_div_zero() {
+    let a = UInt4::new(7, 12, 5, 9);
+    assert_eq!(a / 0, UInt4::from(u32::MAX));
+    assert_eq!(a % 0, UInt4::from(u32::MAX));
+}
+
+#[test]
+fn uint4_ops_bitand() {
+    let a = UInt4::new(7, 12, 5, 9);
+    let b = UInt4::new(2, 5, 3, 4);
+    assert_eq!(a & b, UInt4::new(2, 4, 1, 0));
+    assert_eq!(a & 3, UInt4::new(3, 0, 1, 1));
+    let mut result = a;
+    result &= b;
+    assert_eq!(result, UInt4::new(2, 4, 1, 0));
+    result &= 3;
+    assert_eq!(result, UInt4::new(2, 0, 1, 0));
+}
+
+#[test]
+fn uint4_ops_bitor() {
+    let a = UInt4::new(7, 12, 5, 9);
+    let b = UInt4::new(2, 5, 3, 4);
+    assert_eq!(a | b, UInt4::new(7, 13, 7, 13));
+    assert_eq!(a | 3, UInt4::new(7, 15, 7, 11));
+    let mut result = a;
+    result |= b;
+    assert_eq!(result, UInt4::new(7, 13, 7, 13));
+    result |= 3;
+    assert_eq!(result, UInt4::new(7, 15, 7, 15));
+}
+
+#[test]
+fn uint4_ops_bitxor() {
+    let a = UInt4::new(7, 12, 5, 9);
+    let b = UInt4::new(2, 5, 3, 4);
+    assert_eq!(a ^ b, UInt4::new(5, 9, 6, 13));
+    assert_eq!(a ^ 3, UInt4::new(4, 15, 6, 10));
+    let mut result = a;
+    result ^= b;
+    assert_eq!(result, UInt4::new(5, 9, 6, 13));
+    result ^= 3;
+    assert_eq!(result, UInt4::new(6, 10, 5, 14));
+}
+
+#[test]
+fn uint4_ops_shl() {
+    let a = UInt4::new(7, 12, 5, 9);
+    let b = UInt4::new(2, 5, 3, 4);
+    assert_eq!(a << b, UInt4::new(28, 384, 40, 144));
+    assert_eq!(a << 3, UInt4::new(56, 96, 40, 72));
+    let mut result = a;
+    result <<= b;
+    assert_eq!(result, UInt4::new(28, 384, 40, 144));
+    result <<= 3;
+    assert_eq!(result, UInt4::new(224, 3072, 320, 1152));
+}
+
+#[test]
+fn uint4_ops_shr() {
+    let a = UInt4::new(7, 12, 5, 9);
+    let b = UInt4::new(2, 5, 3, 4);
+    assert_eq!(a >> b, UInt4::new(1, 0, 0, 0));
+    assert_eq!(a >> 3, UInt4::new(0, 1, 0, 1));
+    let mut result = a;
+    result >>= b;
+    assert_eq!(result, UInt4::new(1, 0, 0, 0));
+    result >>= 3;
+    assert_eq!(result, UInt4::new(0, 0, 0, 0));
+}
+
+#[test]
+fn uint4_ops_shift_masked() {
+    let a = UInt4::from(1);
+    assert_eq!(a << 33, UInt4::from(2));
+    assert_eq!(UInt4::from(8) >> 35, UInt4::from(1));
+}
+
+#[test]
+fn uint4_ops_not() {
+    let result = !UInt4::new(7, 12, 5, 9);
+    assert_eq!(
+        result,
+        UInt4::new(4294967288, 4294967283, 4294967290, 4294967286)
+    );
+}
+
+#[test]
+fn uint4_equality() {
+    let a = UInt4::new(7, 12, 5, 9);
+    let b = UInt4::new(2, 5, 3, 4);
+    assert_ne!(a, b);
+    assert_eq!(a, UInt4::new(7, 12, 5, 9));
+}
+
+#[test]
+fn uint4_all() {
+    assert!(UInt4::new(7, 12, 5, 9).all());
+    assert!(!UInt4::new(0, 12, 5, 9).all());
+    assert!(!UInt4::from(0).all());
+}
+
+#[test]
+fn uint4_any() {
+    assert!(UInt4::new(7, 12, 5, 9).any());
+    assert!(UInt4::new(0, 0, 0, 1).any());
+    assert!(!UInt4::from(0).any());
+}
+
+#[test]
+fn uint4_clamp() {
+    let a = UInt4::new(7, 12, 5, 9);
+    assert_eq!(a.clamp(6, 9), UInt4::new(7, 9, 6, 9));
+}
+
+#[test]
+fn uint4_dot() {
+    let a = UInt4::new(7, 12, 5, 9);
+    let b = UInt4::new(2, 5, 3, 4);
+    assert_eq!(a.dot(&b), 125);
+}
+
+#[test]
+fn uint4_mad() {
+    let a = UInt4::new(7, 12, 5, 9);
+    let b = UInt4::new(2, 5, 3, 4);
+    let c = UInt4::from(1);
+    assert_eq!(a.mad(&b, &c), UInt4::new(15, 61, 16, 37));
+}
+
+#[test]
+fn uint4_max() {
+    let a = UInt4::new(7, 12, 5, 9);
+    let b = UInt4::new(8, 8, 8, 8);
+    assert_eq!(a.max(&b), UInt4::new(8, 12, 8, 9));
+}
+
+#[test]
+fn uint4_min() {
+    let a = UInt4::new(7, 12, 5, 9);
+    let b = UInt4::new(8, 8, 8, 8);
+    assert_eq!(a.min(&b), UInt4::new(7, 8, 5, 8));
+}
+
+#[test]
+fn uint4_countbits() {
+    let a = UInt4::new(0, 1, 0x80000000, 0xf0);
+    assert_eq!(a.countbits(), UInt4::new(0, 1, 1, 4));
+    assert_eq!(UInt4::from(u32::MAX).countbits(), UInt4::from(32));
+}
+
+#[test]
+fn uint4_firstbithigh() {
+    let a = UInt4::new(0, 1, 0x80000000, 0xf0);
+    assert_eq!(a.firstbithigh(), UInt4::new(u32::MAX, 0, 31, 7));
+}
+
+#[test]
+fn uint4_firstbitlow() {
+    let a = UInt4::new(0, 1, 0x80000000, 0xf0);
+    assert_eq!(a.firstbitlow(), UInt4::new(u32::MAX, 0, 31, 4));
+}
+
+#[test]
+fn uint4_reversebits() {
+    let a = UInt4::new(0, 1, 0x80000000, 0xf0);
+    assert_eq!(a.reversebits(), UInt4::new(0, 0x80000000, 1, 0xf000000));
+    assert_eq!(a.reversebits().reversebits(), a);
+}
+
+#[test]
+fn uint4_swizzle() {
+    let a = UInt4::new(1, 2, 3, 4);
+    let x = 1;
+    let y = 2;
+    let z = 3;
+    let w = 4;
+    assert_eq!(a.xx(), UInt2::new(x, x));
+    assert_eq!(a.xy(), UInt2::new(x, y));
+    assert_eq!(a.xz(), UInt2::new(x, z));
+    assert_eq!(a.xw(), UInt2::new(x, w));
+    assert_eq!(a.yx(), UInt2::new(y, x));
+    assert_eq!(a.yy(), UInt2::new(y, y));
+    assert_eq!(a.yz(), UInt2::new(y, z));
+    assert_eq!(a.yw(), UInt2::new(y, w));
+    assert_eq!(a.zx(), UInt2::new(z, x));
+    assert_eq!(a.zy(), UInt2::new(z, y));
+    assert_eq!(a.zz(), UInt2::new(z, z));
+    assert_eq!(a.zw(), UInt2::new(z, w));
+    assert_eq!(a.wx(), UInt2::new(w, x));
+    assert_eq!(a.wy(), UInt2::new(w, y));
+    assert_eq!(a.wz(), UInt2::new(w, z));
+    assert_eq!(a.ww(), UInt2::new(w, w));
+    assert_eq!(a.xxx(), UInt3::new(x, x, x));
+    assert_eq!(a.xxy(), UInt3::new(x, x, y));
+    assert_eq!(a.xxz(), UInt3::new(x, x, z));
+    assert_eq!(a.xxw(), UInt3::new(x, x, w));
+    assert_eq!(a.xyx(), UInt3::new(x, y, x));
+    assert_eq!(a.xyy(), UInt3::new(x, y, y));
+    assert_eq!(a.xyz(), UInt3::new(x, y, z));
+    assert_eq!(a.xyw(), UInt3::new(x, y, w));
+    assert_eq!(a.xzx(), UInt3::new(x, z, x));
+    assert_eq!(a.xzy(), UInt3::new(x, z, y));
+    assert_eq!(a.xzz(), UInt3::new(x, z, z));
+    assert_eq!(a.xzw(), UInt3::new(x, z, w));
+    assert_eq!(a.xwx(), UInt3::new(x, w, x));
+    assert_eq!(a.xwy(), UInt3::new(x, w, y));
+    assert_eq!(a.xwz(), UInt3::new(x, w, z));
+    assert_eq!(a.xww(), UInt3::new(x, w, w));
+    assert_eq!(a.yxx(), UInt3::new(y, x, x));
+    assert_eq!(a.yxy(), UInt3::new(y, x, y));
+    assert_eq!(a.yxz(), UInt3::new(y, x, z));
+    assert_eq!(a.yxw(), UInt3::new(y, x, w));
+    assert_eq!(a.yyx(), UInt3::new(y, y, x));
+    assert_eq!(a.yyy(), UInt3::new(y, y, y));
+    assert_eq!(a.yyz(), UInt3::new(y, y, z));
+    assert_eq!(a.yyw(), UInt3::new(y, y, w));
+    assert_eq!(a.yzx(), UInt3::new(y, z, x));
+    assert_eq!(a.yzy(), UInt3::new(y, z, y));
+    assert_eq!(a.yzz(), UInt3::new(y, z, z));
+    assert_eq!(a.yzw(), UInt3::new(y, z, w));
+    assert_eq!(a.ywx(), UInt3::new(y, w, x));
+    assert_eq!(a.ywy(), UInt3::new(y, w, y));
+    assert_eq!(a.ywz(), UInt3::new(y, w, z));
+    assert_eq!(a.yww(), UInt3::new(y, w, w));
+    assert_eq!(a.zxx(), UInt3::new(z, x, x));
+    assert_eq!(a.zxy(), UInt3::new(z, x, y));
+    assert_eq!(a.zxz(), UInt3::new(z, x, z));
+    assert_eq!(a.zxw(), UInt3::new(z, x, w));
+    assert_eq!(a.zyx(), UInt3::new(z, y, x));
+    assert_eq!(a.zyy(), UInt3::new(z, y, y));
+    assert_eq!(a.zyz(), UInt3::new(z, y, z));
+    assert_eq!(a.zyw(), UInt3::new(z, y, w));
+    assert_eq!(a.zzx(), UInt3::new(z, z, x));
+    assert_eq!(a.zzy(), UInt3::new(z, z, y));
+    assert_eq!(a.zzz(), UInt3::new(z, z, z));
+    assert_eq!(a.zzw(), UInt3::new(z, z, w));
+    assert_eq!(a.zwx(), UInt3::new(z, w, x));
+    assert_eq!(a.zwy(), UInt3::new(z, w, y));
+    assert_eq!(a.zwz(), UInt3::new(z, w, z));
+    assert_eq!(a.zww(), UInt3::new(z, w, w));
+    assert_eq!(a.wxx(), UInt3::new(w, x, x));
+    assert_eq!(a.wxy(), UInt3::new(w, x, y));
+    assert_eq!(a.wxz(), UInt3::new(w, x, z));
+    assert_eq!(a.wxw(), UInt3::new(w, x, w));
+    assert_eq!(a.wyx(), UInt3::new(w, y, x));
+    assert_eq!(a.wyy(), UInt3::new(w, y, y));
+    assert_eq!(a.wyz(), UInt3::new(w, y, z));
+    assert_eq!(a.wyw(), UInt3::new(w, y, w));
+    assert_eq!(a.wzx(), UInt3::new(w, z, x));
+    assert_eq!(a.wzy(), UInt3::new(w, z, y));
+    assert_eq!(a.wzz(), UInt3::new(w, z, z));
+    assert_eq!(a.wzw(), UInt3::new(w, z, w));
+    assert_eq!(a.wwx(), UInt3::new(w, w, x));
+    assert_eq!(a.wwy(), UInt3::new(w, w, y));
+    assert_eq!(a.wwz(), UInt3::new(w, w, z));
+    assert_eq!(a.www(), UInt3::new(w, w, w));
+    assert_eq!(a.xxxx(), UInt4::new(x, x, x, x));
+    assert_eq!(a.xxxy(), UInt4::new(x, x, x, y));
+    assert_eq!(a.xxxz(), UInt4::new(x, x, x, z));
+    assert_eq!(a.xxxw(), UInt4::new(x, x, x, w));
+    assert_eq!(a.xxyx(), UInt4::new(x, x, y, x));
+    assert_eq!(a.xxyy(), UInt4::new(x, x, y, y));
+    assert_eq!(a.xxyz(), UInt4::new(x, x, y, z));
+    assert_eq!(a.xxyw(), UInt4::new(x, x, y, w));
+    assert_eq!(a.xxzx(), UInt4::new(x, x, z, x));
+    assert_eq!(a.xxzy(), UInt4::new(x, x, z, y));
+    assert_eq!(a.xxzz(), UInt4::new(x, x, z, z));
+    assert_eq!(a.xxzw(), UInt4::new(x, x, z, w));
+    assert_eq!(a.xxwx(), UInt4::new(x, x, w, x));
+    assert_eq!(a.xxwy(), UInt4::new(x, x, w, y));
+    assert_eq!(a.xxwz(), UInt4::new(x, x, w, z));
+    assert_eq!(a.xxww(), UInt4::new(x, x, w, w));
+    assert_eq!(a.xyxx(), UInt4::new(x, y, x, x));
+    assert_eq!(a.xyxy(), UInt4::new(x, y, x, y));
+    assert_eq!(a.xyxz(), UInt4::new(x, y, x, z));
+    assert_eq!(a.xyxw(), UInt4::new(x, y, x, w));
+    assert_eq!(a.xyyx(), UInt4::new(x, y, y, x));
+    assert_eq!(a.xyyy(), UInt4::new(x, y, y, y));
+    assert_eq!(a.xyyz(), UInt4::new(x, y, y, z));
+    assert_eq!(a.xyyw(), UInt4::new(x, y, y, w));
+    assert_eq!(a.xyzx(), UInt4::new(x, y, z, x));
+    assert_eq!(a.xyzy(), UInt4::new(x, y, z, y));
+    assert_eq!(a.xyzz(), UInt4::new(x, y, z, z));
+    assert_eq!(a.xyzw(), UInt4::new(x, y, z, w));
+    assert_eq!(a.xywx(), UInt4::new(x, y, w, x));
+    assert_eq!(a.xywy(), UInt4::new(x, y, w, y));
+    assert_eq!(a.xywz(), UInt4::new(x, y, w, z));
+    assert_eq!(a.xyww(), UInt4::new(x, y, w, w));
+    assert_eq!(a.xzxx(), UInt4::new(x, z, x, x));
+    assert_eq!(a.xzxy(), UInt4::new(x, z, x, y));
+    assert_eq!(a.xzxz(), UInt4::new(x, z, x, z));
+    assert_eq!(a.xzxw(), UInt4::new(x, z, x, w));
+    assert_eq!(a.xzyx(), UInt4::new(x, z, y, x));
+    assert_eq!(a.xzyy(), UInt4::new(x, z, y, y));
+    assert_eq!(a.xzyz(), UInt4::new(x, z, y, z));
+    assert_eq!(a.xzyw(), UInt4::new(x, z, y, w));
+    assert_eq!(a.xzzx(), UInt4::new(x, z, z, x));
+    assert_eq!(a.xzzy(), UInt4::new(x, z, z, y));
+    assert_eq!(a.xzzz(), UInt4::new(x, z, z, z));
+    assert_eq!(a.xzzw(), UInt4::new(x, z, z, w));
+    assert_eq!(a.xzwx(), UInt4::new(x, z, w, x));
+    assert_eq!(a.xzwy(), UInt4::new(x, z, w, y));
+    assert_eq!(a.xzwz(), UInt4::new(x, z, w, z));
+    assert_eq!(a.xzww(), UInt4::new(x, z, w, w));
+    assert_eq!(a.xwxx(), UInt4::new(x, w, x, x));
+    assert_eq!(a.xwxy(), UInt4::new(x, w, x, y));
+    assert_eq!(a.xwxz(), UInt4::new(x, w, x, z));
+    assert_eq!(a.xwxw(), UInt4::new(x, w, x, w));
+    assert_eq!(a.xwyx(), UInt4::new(x, w, y, x));
+    assert_eq!(a.xwyy(), UInt4::new(x, w, y, y));
+    assert_eq!(a.xwyz(), UInt4::new(x, w, y, z));
+    assert_eq!(a.xwyw(), UInt4::new(x, w, y, w));
+    assert_eq!(a.xwzx(), UInt4::new(x, w, z, x));
+    assert_eq!(a.xwzy(), UInt4::new(x, w, z, y));
+    assert_eq!(a.xwzz(), UInt4::new(x, w, z, z));
+    assert_eq!(a.xwzw(), UInt4::new(x, w, z, w));
+    assert_eq!(a.xwwx(), UInt4::new(x, w, w, x));
+    assert_eq!(a.xwwy(), UInt4::new(x, w, w, y));
+    assert_eq!(a.xwwz(), UInt4::new(x, w, w, z));
+    assert_eq!(a.xwww(), UInt4::new(x, w, w, w));
+    assert_eq!(a.yxxx(), UInt4::new(y, x, x, x));
+    assert_eq!(a.yxxy(), UInt4::new(y, x, x, y));
+    assert_eq!(a.yxxz(), UInt4::new(y, x, x, z));
+    assert_eq!(a.yxxw(), UInt4::new(y, x, x, w));
+    assert_eq!(a.yxyx(), UInt4::new(y, x, y, x));
+    assert_eq!(a.yxyy(), UInt4::new(y, x, y, y));
+    assert_eq!(a.yxyz(), UInt4::new(y, x, y, z));
+    assert_eq!(a.yxyw(), UInt4::new(y, x, y, w));
+    assert_eq!(a.yxzx(), UInt4::new(y, x, z, x));
+    assert_eq!(a.yxzy(), UInt4::new(y, x, z, y));
+    assert_eq!(a.yxzz(), UInt4::new(y, x, z, z));
+    assert_eq!(a.yxzw(), UInt4::new(y, x, z, w));
+    assert_eq!(a.yxwx(), UInt4::new(y, x, w, x));
+    assert_eq!(a.yxwy(), UInt4::new(y, x, w, y));
+    assert_eq!(a.yxwz(), UInt4::new(y, x, w, z));
+    assert_eq!(a.yxww(), UInt4::new(y, x, w, w));
+    assert_eq!(a.yyxx(), UInt4::new(y, y, x, x));
+    assert_eq!(a.yyxy(), UInt4::new(y, y, x, y));
+    assert_eq!(a.yyxz(), UInt4::new(y, y, x, z));
+    assert_eq!(a.yyxw(), UInt4::new(y, y, x, w));
+    assert_eq!(a.yyyx(), UInt4::new(y, y, y, x));
+    assert_eq!(a.yyyy(), UInt4::new(y, y, y, y));
+    assert_eq!(a.yyyz(), UInt4::new(y, y, y, z));
+    assert_eq!(a.yyyw(), UInt4::new(y, y, y, w));
+    assert_eq!(a.yyzx(), UInt4::new(y, y, z, x));
+    assert_eq!(a.yyzy(), UInt4::new(y, y, z, y));
+    assert_eq!(a.yyzz(), UInt4::new(y, y, z, z));
+    assert_eq!(a.yyzw(), UInt4::new(y, y, z, w));
+    assert_eq!(a.yywx(), UInt4::new(y, y, w, x));
+    assert_eq!(a.yywy(), UInt4::new(y, y, w, y));
+    assert_eq!(a.yywz(), UInt4::new(y, y, w, z));
+    assert_eq!(a.yyww(), UInt4::new(y, y, w, w));
+    assert_eq!(a.yzxx(), UInt4::new(y, z, x, x));
+    assert_eq!(a.yzxy(), UInt4::new(y, z, x, y));
+    assert_eq!(a.yzxz(), UInt4::new(y, z, x, z));
+    assert_eq!(a.yzxw(), UInt4::new(y, z, x, w));
+    assert_eq!(a.yzyx(), UInt4::new(y, z, y, x));
+    assert_eq!(a.yzyy(), UInt4::new(y, z, y, y));
+    assert_eq!(a.yzyz(), UInt4::new(y, z, y, z));
+    assert_eq!(a.yzyw(), UInt4::new(y, z, y, w));
+    assert_eq!(a.yzzx(), UInt4::new(y, z, z, x));
+    assert_eq!(a.yzzy(), UInt4::new(y, z, z, y));
+    assert_eq!(a.yzzz(), UInt4::new(y, z, z, z));
+    assert_eq!(a.yzzw(), UInt4::new(y, z, z, w));
+    assert_eq!(a.yzwx(), UInt4::new(y, z, w, x));
+    assert_eq!(a.yzwy(), UInt4::new(y, z, w, y));
+    assert_eq!(a.yzwz(), UInt4::new(y, z, w, z));
+    assert_eq!(a.yzww(), UInt4::new(y, z, w, w));
+    assert_eq!(a.ywxx(), UInt4::new(y, w, x, x));
+    assert_eq!(a.ywxy(), UInt4::new(y, w, x, y));
+    assert_eq!(a.ywxz(), UInt4::new(y, w, x, z));
+    assert_eq!(a.ywxw(), UInt4::new(y, w, x, w));
+    assert_eq!(a.ywyx(), UInt4::new(y, w, y, x));
+    assert_eq!(a.ywyy(), UInt4::new(y, w, y, y));
+    assert_eq!(a.ywyz(), UInt4::new(y, w, y, z));
+    assert_eq!(a.ywyw(), UInt4::new(y, w, y, w));
+    assert_eq!(a.ywzx(), UInt4::new(y, w, z, x));
+    assert_eq!(a.ywzy(), UInt4::new(y, w, z, y));
+    assert_eq!(a.ywzz(), UInt4::new(y, w, z, z));
+    assert_eq!(a.ywzw(), UInt4::new(y, w, z, w));
+    assert_eq!(a.ywwx(), UInt4::new(y, w, w, x));
+    assert_eq!(a.ywwy(), UInt4::new(y, w, w, y));
+    assert_eq!(a.ywwz(), UInt4::new(y, w, w, z));
+    assert_eq!(a.ywww(), UInt4::new(y, w, w, w));
+    assert_eq!(a.zxxx(), UInt4::new(z, x, x, x));
+    assert_eq!(a.zxxy(), UInt4::new(z, x, x, y));
+    assert_eq!(a.zxxz(), UInt4::new(z, x, x, z));
+    assert_eq!(a.zxxw(), UInt4::new(z, x, x, w));
+    assert_eq!(a.zxyx(), UInt4::new(z, x, y, x));
+    assert_eq!(a.zxyy(), UInt4::new(z, x, y, y));
+    assert_eq!(a.zxyz(), UInt4::new(z, x, y, z));
+    assert_eq!(a.zxyw(), UInt4::new(z, x, y, w));
+    assert_eq!(a.zxzx(), UInt4::new(z, x, z, x));
+    assert_eq!(a.zxzy(), UInt4::new(z, x, z, y));
+    assert_eq!(a.zxzz(), UInt4::new(z, x, z, z));
+    assert_eq!(a.zxzw(), UInt4::new(z, x, z, w));
+    assert_eq!(a.zxwx(), UInt4::new(z, x, w, x));
+    assert_eq!(a.zxwy(), UInt4::new(z, x, w, y));
+    assert_eq!(a.zxwz(), UInt4::new(z, x, w, z));
+    assert_eq!(a.zxww(), UInt4::new(z, x, w, w));
+    assert_eq!(a.zyxx(), UInt4::new(z, y, x, x));
+    assert_eq!(a.zyxy(), UInt4::new(z, y, x, y));
+    assert_eq!(a.zyxz(), UInt4::new(z, y, x, z));
+    assert_eq!(a.zyxw(), UInt4::new(z, y, x, w));
+    assert_eq!(a.zyyx(), UInt4::new(z, y, y, x));
+    assert_eq!(a.zyyy(), UInt4::new(z, y, y, y));
+    assert_eq!(a.zyyz(), UInt4::new(z, y, y, z));
+    assert_eq!(a.zyyw(), UInt4::new(z, y, y, w));
+    assert_eq!(a.zyzx(), UInt4::new(z, y, z, x));
+    assert_eq!(a.zyzy(), UInt4::new(z, y, z, y));
+    assert_eq!(a.zyzz(), UInt4::new(z, y, z, z));
+    assert_eq!(a.zyzw(), UInt4::new(z, y, z, w));
+    assert_eq!(a.zywx(), UInt4::new(z, y, w, x));
+    assert_eq!(a.zywy(), UInt4::new(z, y, w, y));
+    assert_eq!(a.zywz(), UInt4::new(z, y, w, z));
+    assert_eq!(a.zyww(), UInt4::new(z, y, w, w));
+    assert_eq!(a.zzxx(), UInt4::new(z, z, x, x));
+    assert_eq!(a.zzxy(), UInt4::new(z, z, x, y));
+    assert_eq!(a.zzxz(), UInt4::new(z, z, x, z));
+    assert_eq!(a.zzxw(), UInt4::new(z, z, x, w));
+    assert_eq!(a.zzyx(), UInt4::new(z, z, y, x));
+    assert_eq!(a.zzyy(), UInt4::new(z, z, y, y));
+    assert_eq!(a.zzyz(), UInt4::new(z, z, y, z));
+    assert_eq!(a.zzyw(), UInt4::new(z, z, y, w));
+    assert_eq!(a.zzzx(), UInt4::new(z, z, z, x));
+    assert_eq!(a.zzzy(), UInt4::new(z, z, z, y));
+    assert_eq!(a.zzzz(), UInt4::new(z, z, z, z));
+    assert_eq!(a.zzzw(), UInt4::new(z, z, z, w));
+    assert_eq!(a.zzwx(), UInt4::new(z, z, w, x));
+    assert_eq!(a.zzwy(), UInt4::new(z, z, w, y));
+    assert_eq!(a.zzwz(), UInt4::new(z, z, w, z));
+    assert_eq!(a.zzww(), UInt4::new(z, z, w, w));
+    assert_eq!(a.zwxx(), UInt4::new(z, w, x, x));
+    assert_eq!(a.zwxy(), UInt4::new(z, w, x, y));
+    assert_eq!(a.zwxz(), UInt4::new(z, w, x, z));
+    assert_eq!(a.zwxw(), UInt4::new(z, w, x, w));
+    assert_eq!(a.zwyx(), UInt4::new(z, w, y, x));
+    assert_eq!(a.zwyy(), UInt4::new(z, w, y, y));
+    assert_eq!(a.zwyz(), UInt4::new(z, w, y, z));
+    assert_eq!(a.zwyw(), UInt4::new(z, w, y, w));
+    assert_eq!(a.zwzx(), UInt4::new(z, w, z, x));
+    assert_eq!(a.zwzy(), UInt4::new(z, w, z, y));
+    assert_eq!(a.zwzz(), UInt4::new(z, w, z, z));
+    assert_eq!(a.zwzw(), UInt4::new(z, w, z, w));
+    assert_eq!(a.zwwx(), UInt4::new(z, w, w, x));
+    assert_eq!(a.zwwy(), UInt4::new(z, w, w, y));
+    assert_eq!(a.zwwz(), UInt4::new(z, w, w, z));
+    assert_eq!(a.zwww(), UInt4::new(z, w, w, w));
+    assert_eq!(a.wxxx(), UInt4::new(w, x, x, x));
+    assert_eq!(a.wxxy(), UInt4::new(w, x, x, y));
+    assert_eq!(a.wxxz(), UInt4::new(w, x, x, z));
+    assert_eq!(a.wxxw(), UInt4::new(w, x, x, w));
+    assert_eq!(a.wxyx(), UInt4::new(w, x, y, x));
+    assert_eq!(a.wxyy(), UInt4::new(w, x, y, y));
+    assert_eq!(a.wxyz(), UInt4::new(w, x, y, z));
+    assert_eq!(a.wxyw(), UInt4::new(w, x, y, w));
+    assert_eq!(a.wxzx(), UInt4::new(w, x, z, x));
+    assert_eq!(a.wxzy(), UInt4::new(w, x, z, y));
+    assert_eq!(a.wxzz(), UInt4::new(w, x, z, z));
+    assert_eq!(a.wxzw(), UInt4::new(w, x, z, w));
+    assert_eq!(a.wxwx(), UInt4::new(w, x, w, x));
+    assert_eq!(a.wxwy(), UInt4::new(w, x, w, y));
+    assert_eq!(a.wxwz(), UInt4::new(w, x, w, z));
+    assert_eq!(a.wxww(), UInt4::new(w, x, w, w));
+    assert_eq!(a.wyxx(), UInt4::new(w, y, x, x));
+    assert_eq!(a.wyxy(), UInt4::new(w, y, x, y));
+    assert_eq!(a.wyxz(), UInt4::new(w, y, x, z));
+    assert_eq!(a.wyxw(), UInt4::new(w, y, x, w));
+    assert_eq!(a.wyyx(), UInt4::new(w, y, y, x));
+    assert_eq!(a.wyyy(), UInt4::new(w, y, y, y));
+    assert_eq!(a.wyyz(), UInt4::new(w, y, y, z));
+    assert_eq!(a.wyyw(), UInt4::new(w, y, y, w));
+    assert_eq!(a.wyzx(), UInt4::new(w, y, z, x));
+    assert_eq!(a.wyzy(), UInt4::new(w, y, z, y));
+    assert_eq!(a.wyzz(), UInt4::new(w, y, z, z));
+    assert_eq!(a.wyzw(), UInt4::new(w, y, z, w));
+    assert_eq!(a.wywx(), UInt4::new(w, y, w, x));
+    assert_eq!(a.wywy(), UInt4::new(w, y, w, y));
+    assert_eq!(a.wywz(), UInt4::new(w, y, w, z));
+    assert_eq!(a.wyww(), UInt4::new(w, y, w, w));
+    assert_eq!(a.wzxx(), UInt4::new(w, z, x, x));
+    assert_eq!(a.wzxy(), UInt4::new(w, z, x, y));
+    assert_eq!(a.wzxz(), UInt4::new(w, z, x, z));
+    assert_eq!(a.wzxw(), UInt4::new(w, z, x, w));
+    assert_eq!(a.wzyx(), UInt4::new(w, z, y, x));
+    assert_eq!(a.wzyy(), UInt4::new(w, z, y, y));
+    assert_eq!(a.wzyz(), UInt4::new(w, z, y, z));
+    assert_eq!(a.wzyw(), UInt4::new(w, z, y, w));
+    assert_eq!(a.wzzx(), UInt4::new(w, z, z, x));
+    assert_eq!(a.wzzy(), UInt4::new(w, z, z, y));
+    assert_eq!(a.wzzz(), UInt4::new(w, z, z, z));
+    assert_eq!(a.wzzw(), UInt4::new(w, z, z, w));
+    assert_eq!(a.wzwx(), UInt4::new(w, z, w, x));
+    assert_eq!(a.wzwy(), UInt4::new(w, z, w, y));
+    assert_eq!(a.wzwz(), UInt4::new(w, z, w, z));
+    assert_eq!(a.wzww(), UInt4::new(w, z, w, w));
+    assert_eq!(a.wwxx(), UInt4::new(w, w, x, x));
+    assert_eq!(a.wwxy(), UInt4::new(w, w, x, y));
+    assert_eq!(a.wwxz(), UInt4::new(w, w, x, z));
+    assert_eq!(a.wwxw(), UInt4::new(w, w, x, w));
+    assert_eq!(a.wwyx(), UInt4::new(w, w, y, x));
+    assert_eq!(a.wwyy(), UInt4::new(w, w, y, y));
+    assert_eq!(a.wwyz(), UInt4::new(w, w, y, z));
+    assert_eq!(a.wwyw(), UInt4::new(w, w, y, w));
+    assert_eq!(a.wwzx(), UInt4::new(w, w, z, x));
+    assert_eq!(a.wwzy(), UInt4::new(w, w, z, y));
+    assert_eq!(a.wwzz(), UInt4::new(w, w, z, z));
+    assert_eq!(a.wwzw(), UInt4::new(w, w, z, w));
+    assert_eq!(a.wwwx(), UInt4::new(w, w, w, x));
+    assert_eq!(a.wwwy(), UInt4::new(w, w, w, y));
+    assert_eq!(a.wwwz(), UInt4::new(w, w, w, z));
+    assert_eq!(a.wwww(), UInt4::new(w, w, w, w));
+}
@@ -0,0 +1,229 @@
+use crate::math::Float3;
+use crate::math::Int2;
+use crate::math::UInt4;
+use crate::math::Wave;
+
+#[test]
+fn wave_lanes() {
+    let result = Wave::run(32, u64::MAX, |wave| {
+        (wave.lane_index(), wave.lane_count(), wave.is_first_lane())
+    });
+    assert_eq!(result.len(), 32);
+    assert_eq!(result[0], Some((0, 32, true)));
+    assert_eq!(result[31], Some((31, 32, false)));
+
+    let result = Wave::run(64, 0b1100, |wave| wave.is_first_lane());
+    assert_eq!(result.len(), 64);
+    assert_eq!(result[..4], [None, None, Some(true), Some(false)]);
+    assert!(result[4..].iter().all(Option::is_none));
+}
+
+#[test]
+#[should_panic(expected = "the wave size must be 32 or 64")]
+fn wave_size() {
+    Wave::run(16, u64::MAX, |_| ());
+}
+
+#[test]
+fn wave_active_sum() {
+    let result = Wave::run(32, u64::MAX, |wave| wave.active_sum(wave.lane_index()));
+    assert!(result.iter().all(|sum| *sum == Some(496)));
+
+    // only the active lanes contribute.
+    let result = Wave::run(64, 0xf0, |wave| {
+        let lane = wave.lane_index() as f32;
+        wave.active_sum(Float3::new(lane, 1.0, -lane))
+    });
+    assert_eq!(result[4], Some(Float3::new(22.0, 4.0, -22.0)));
+    assert_eq!(result[0], None);
+}
+
+#[test]
+fn wave_active_reductions() {
+    let result = Wave::run(32, 0b1111, |wave| {
+        let value = Int2::new(wave.lane_index() as i32 + 1, -(wave.lane_index() as i32));
+        (
+            wave.active_product(value),
+            wave.active_min(value),
+            wave.active_max(value),
+        )
+    });
+    assert_eq!(
+        result[0],
+        Some((Int2::new(24, 0), Int2::new(1, -3), Int2::new(4, 0)))
+    );
+
+    let result = Wave::run(32, 0b111, |wave| {
+        let value = 1u32 << wave.lane_index() | 0x100;
+        (
+            wave.active_bit_and(value),
+            wave.active_bit_or(value),
+            wave.active_bit_xor(value),
+        )
+    });
+    assert_eq!(result[2], Some((0x100, 0x107, 0x107)));
+}
+
+#[test]
+fn wave_prefix() {
+    let result = Wave::run(32, 0b10110, |wave| {
+        let value = wave.lane_index() as i32;
+        (
+            wave.prefix_sum(value),
+            wave.prefix_product(value),
+            wave.prefix_count_bits(value != 2),
+        )
+    });
+    assert_eq!(result[1], Some((0, 1, 0)));
+    assert_eq!(result[2], Some((1, 1, 1)));
+    assert_eq!(result[4], Some((3, 2, 1)));
+}
+
+#[test]
+fn wave_ballot() {
+    let result = Wave::run(64, u64::MAX, |wave| {
+        let condition = wave.lane_index() % 3 == 0;
+        (
+            wave.active_ballot(condition),
+            wave.active_count_bits(condition),
+        )
+    });
+    assert_eq!(
+        result[7],
+        Some((UInt4::new(0x49249249, 0x92492492, 0, 0), 22))
+    );
+}
+
+#[test]
+fn wave_any_all() {
+    let result = Wave::run(32, 0xff, |wave| {
+        let lane = wave.lane_index();
+        (
+            wave.active_any_true(lane == 7),
+            wave.active_all_true(lane < 8),
+            wave.active_all_true(lane < 7),
+            wave.active_all_equal(lane / 8),
+            wave.active_all_equal(lane),
+        )
+    });
+    assert_eq!(result[0], Some((true, true, false, true, false)));
+}
+
+#[test]
+fn wave_read_lane() {
+    let result = Wave::run(32, 0xff00, |wave| {
+        let value = wave.lane_index() * 10;
+        (wave.read_lane_first(value), wave.read_lane_at(value, 12))
+    });
+    assert_eq!(result[15], Some((80, 120)));
+}
+
+#[test]
+fn wave_stream_compaction() {
+    // a typical use: every lane with an item finds its output slot without atomics.
+    let result = Wave::run(32, u64::MAX, |wave| {
+        let keep = wave.lane_index() % 4 == 1;
+        let slot = wave.prefix_count_bits(keep);
+        let total = wave.active_count_bits(keep);
+        keep.then_some((slot, total))
+    });
+    assert_eq!(result[1], Some(Some((0, 8))));
+    assert_eq!(result[29], Some(Some((7, 8))));
+    assert_eq!(result[2], Some(None));
+}
+
+#[test]
+fn wave_divergent_branch() {
+    // only the lanes taking a branch are active inside it and all lanes are active again after it.
+    let result = Wave::run(32, u64::MAX, |wave| {
+        let lane = wave.lane_index();
+        let below = wave.branch(lane < 16, |wave| wave.active_sum(1));
+        let above = wave.branch(lane >= 16, |wave| wave.active_sum(10));
+        let first = wave.is_first_lane();
+        (below.or(above).unwrap(), first, wave.active_sum(1))
+    });
+    assert_eq!(result[0], Some((16, true, 32)));
+    assert_eq!(result[16], Some((160, false, 32)));
+
+    let result = Wave::run(32, u64::MAX, |wave| {
+        let lane = wave.lane_index();
+        wave.branch(lane >= 8, |wave| {
+            (wave.is_first_lane(), wave.read_lane_first(lane))
+        })
+    });
+    assert_eq!(result[8], Some(Some((true, 8))));
+    assert_eq!(result[9], Some(Some((false, 8))));
+    assert_eq!(result[0], Some(None));
+}
+
+#[test]
+fn wave_divergent_helper() {
+    // both branches call the same function, but each only combines its own lanes.
+    fn sum(wave: &Wave, value: u32) -> u32 {
+        wave.active_sum(value)
+    }
+    let result = Wave::run(32, u64::MAX, |wave| {
+        let lane = wave.lane_index();
+        let below = wave.branch(lane < 16, |wave| sum(wave, 1));
+        let above = wave.branch(lane >= 16, |wave| sum(wave, 100));
+        below.or(above).unwrap()
+    });
+    assert_eq!(result[0], Some(16));
+    assert_eq!(result[31], Some(1600));
+}
+
+#[test]
+fn wave_divergent_loop() {
+    // the uniform call of every iteration sees all lanes, the branch only the even lanes.
+    let result = Wave::run(32, u64::MAX, |wave| {
+        let even = wave.lane_index() % 2 == 0;
+        let mut sums = Vec::new();
+        for _ in 0..2 {
+            sums.push(wave.active_sum(1));
+            wave.branch(even, |wave| sums.push(wave.active_sum(1)));
+        }
+        sums
+    });
+    assert_eq!(result[0], Some(vec![32, 16, 32, 16]));
+    assert_eq!(result[1], Some(vec![32, 32]));
+
+    // lane i runs i + 1 iterations, so the active lanes shrink with every iteration.
+    let result = Wave::run(32, 0b1111, |wave| {
+        let mut counts = Vec::new();
+        for i in 0..4 {
+            wave.branch(i <= wave.lane_index(), |wave| {
+                counts.push(wave.active_count_bits(true))
+            });
+        }
+        counts
+    });
+    assert_eq!(result[0], Some(vec![4]));
+    assert_eq!(result[3], Some(vec![4, 3, 2, 1]));
+}
+
+#[test]
+#[should_panic(expected = "use Wave::branch for divergent control flow")]
+fn wave_divergent_without_branch() {
+    // the odd lanes skip the second call, so their next call does not match the one of the even lanes.
+    Wave::run(32, u64::MAX, |wave| {
+        for _ in 0..2 {
+            wave.active_sum(1);
+            if wave.lane_index() % 2 == 0 {
+                wave.active_sum(1);
+            }
+        }
+    });
+}
+
+#[test]
+fn wave_early_return() {
+    // lanes that returned no longer take part in the wave intrinsics.
+    let result = Wave::run(32, u64::MAX, |wave| {
+        if wave.lane_index() % 2 == 0 {
+            return None;
+        }
+        Some(wave.active_ballot(true))
+    });
+    assert_eq!(result[1], Some(Some(UInt4::new(0xaaaaaaaa, 0, 0, 0))));
+    assert_eq!(result[0], Some(None));
+}